@@ -1,3 +1,6 @@
 
 # raydium_amm_v3
 solana program dump -u m CAMMCzo5YL8w4VFF8KVHrK22GGUsp5VTaW7grrKgrWqK ./tests-rs/src/helpers/dumps/clmm.so
+
+# amm, build it in week-2 first
+cp ../week-2/target/deploy/amm.so ./tests-rs/src/helpers/dumps/amm.so
@@ -0,0 +1,7 @@
+pub mod math;
+pub mod state;
+pub mod types;
+
+use anchor_lang::prelude::*;
+
+declare_id!("CpuYGzAZWKWBHXUoBSfEg3qnvRd8pMcRa9XV29Xoj3KU");
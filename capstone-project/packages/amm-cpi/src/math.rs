@@ -0,0 +1,37 @@
+use crate::state::PoolConfig;
+
+pub fn calc_amount_out(
+    amount_in: u64,
+    is_mint_in_x: bool,
+    total_mint_x: u64,
+    total_mint_y: u64,
+) -> u64 {
+    let total_mint_y = total_mint_y as u128;
+    let total_mint_x = total_mint_x as u128;
+    let amount_in = amount_in as u128;
+
+    let k = total_mint_x * total_mint_y;
+    let amount_out = if is_mint_in_x {
+        let amount_in_full = total_mint_x + amount_in;
+
+        if amount_in_full == 0 {
+            0
+        } else {
+            total_mint_y - k / amount_in_full
+        }
+    } else {
+        let amount_in_full = total_mint_y + amount_in;
+
+        if amount_in_full == 0 {
+            0
+        } else {
+            total_mint_x - k / amount_in_full
+        }
+    };
+
+    amount_out as u64
+}
+
+pub fn calc_fee(amount_out: u64, pool_config: &PoolConfig) -> u64 {
+    (amount_out as u128 * pool_config.fee_bps as u128 / 10_000_u128) as u64
+}
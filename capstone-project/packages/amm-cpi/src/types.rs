@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize)]
+pub struct CreatePoolData {
+    pub discriminator: [u8; 8],
+    pub id: u64,
+    pub mint_x: Pubkey,
+    pub mint_y: Pubkey,
+    pub fee_bps: u16,
+}
+
+#[derive(AnchorSerialize)]
+pub struct ProvideLiquidityData {
+    pub discriminator: [u8; 8],
+    pub id: u64,
+    pub mint_x_amount: u64,
+    pub mint_y_amount: u64,
+}
@@ -13,6 +13,9 @@ pub enum AuthError {
 
     #[msg("It's too late to accept owner role!")]
     TransferOwnerDeadline,
+
+    #[msg("There is no rotation to cancel!")]
+    NoRotationToCancel,
 }
 
 #[error_code]
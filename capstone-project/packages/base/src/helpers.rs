@@ -77,6 +77,25 @@ where
     Ok(Rent::get()?.minimum_balance(account.to_account_info().data_len()))
 }
 
+/// closes the account if it's initialized by the program, otherwise does nothing \
+/// it lets close optional PDAs (e.g. buffers) along with required ones
+pub fn close_account_if_exists<'a>(
+    account: &AccountInfo<'a>,
+    destination: &AccountInfo<'a>,
+    program_id: &Pubkey,
+) -> Result<()> {
+    if account.owner != program_id || account.data_is_empty() {
+        return Ok(());
+    }
+
+    let lamports = account.lamports();
+    **account.try_borrow_mut_lamports()? = 0;
+    **destination.try_borrow_mut_lamports()? += lamports;
+
+    account.assign(&system_program::ID);
+    Ok(account.realloc(0, false)?)
+}
+
 pub fn transfer_sol_from_user<'a>(
     amount: u64,
     from: &Signer<'a>,
@@ -1,2 +1,4 @@
 pub mod error;
 pub mod helpers;
+pub mod migration;
+pub mod pause;
@@ -0,0 +1,82 @@
+use {
+    crate::helpers::{get_space, transfer_sol_from_user},
+    anchor_lang::{prelude::*, Discriminator},
+};
+
+/// accounts which layout can be upgraded in place
+pub trait Versioned {
+    /// the latest layout version, 0 is used by accounts created before versioning
+    const VERSION: u8;
+
+    fn get_version(&self) -> u8;
+
+    fn set_version(&mut self, version: u8);
+
+    /// sets defaults of the fields introduced after the version, they are zeroed before the call
+    fn upgrade_from(&mut self, _version: u8) {}
+}
+
+/// reallocs the account to the latest layout and upgrades its version, new fields are filled \
+/// with zeros and then set by Versioned::upgrade_from \
+/// new fields must be appended to the end of the struct \
+/// returns false if the account is already up to date
+pub fn migrate_account<'a, T>(
+    account: &AccountInfo<'a>,
+    payer: &Signer<'a>,
+    system_program: &Program<'a, System>,
+    program_id: &Pubkey,
+) -> Result<bool>
+where
+    T: AccountSerialize + AccountDeserialize + Discriminator + Space + Versioned,
+{
+    if account.owner != program_id {
+        Err(ErrorCode::AccountOwnedByWrongProgram)?;
+    }
+
+    if !account.try_borrow_data()?.starts_with(T::DISCRIMINATOR) {
+        Err(ErrorCode::AccountDiscriminatorMismatch)?;
+    }
+
+    let space = get_space(T::INIT_SPACE);
+
+    if account.data_len() < space {
+        grow_account(account, space, payer, system_program)?;
+    }
+
+    let mut data = account.try_borrow_mut_data()?;
+    let mut state = T::try_deserialize(&mut &data[..])?;
+
+    let version = state.get_version();
+    if version >= T::VERSION {
+        return Ok(false);
+    }
+
+    state.upgrade_from(version);
+    state.set_version(T::VERSION);
+    state.try_serialize(&mut &mut data[..])?;
+
+    Ok(true)
+}
+
+/// reallocs the account to the specified space, new bytes are zeroed \
+/// payer tops up the rent exemption
+pub fn grow_account<'a>(
+    account: &AccountInfo<'a>,
+    space: usize,
+    payer: &Signer<'a>,
+    system_program: &Program<'a, System>,
+) -> Result<()> {
+    let rent_exempt_pre = account.lamports();
+    let rent_exempt = Rent::get()?.minimum_balance(space);
+
+    if rent_exempt > rent_exempt_pre {
+        transfer_sol_from_user(
+            rent_exempt - rent_exempt_pre,
+            payer,
+            account,
+            system_program,
+        )?;
+    }
+
+    Ok(account.realloc(space, true)?)
+}
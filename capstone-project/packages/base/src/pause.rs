@@ -0,0 +1,23 @@
+//! bit flags of operations which can be paused independently via `paused_ops` config field
+
+pub const PAUSE_CREATE: u8 = 1 << 0;
+pub const PAUSE_ACTIVATE: u8 = 1 << 1;
+pub const PAUSE_WRITE: u8 = 1 << 2;
+pub const PAUSE_ROTATE: u8 = 1 << 3;
+pub const PAUSE_SWAP: u8 = 1 << 4;
+pub const PAUSE_SWAP_AND_ACTIVATE: u8 = 1 << 5;
+pub const PAUSE_UNWRAP: u8 = 1 << 6;
+
+pub const PAUSE_NONE: u8 = 0;
+pub const PAUSE_ALL: u8 = PAUSE_CREATE
+    | PAUSE_ACTIVATE
+    | PAUSE_WRITE
+    | PAUSE_ROTATE
+    | PAUSE_SWAP
+    | PAUSE_SWAP_AND_ACTIVATE
+    | PAUSE_UNWRAP;
+
+/// checks if any of the operations specified by `op` mask is paused
+pub fn is_paused(paused_ops: u8, op: u8) -> bool {
+    paused_ops & op != 0
+}
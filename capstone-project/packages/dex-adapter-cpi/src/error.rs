@@ -23,7 +23,7 @@ pub enum CustomError {
     #[msg("Contract is paused")]
     ContractPaused,
 
-    #[msg("Route must contain at least 2 tokens")]
+    #[msg("Route hops amount is out of range")]
     InvalidRouteLength,
 
     #[msg("Amount must be greater than 0")]
@@ -37,4 +37,34 @@ pub enum CustomError {
 
     #[msg("No output tokens received from swap")]
     NoOutputTokens,
+
+    #[msg("Swap accounts don't match the saved route")]
+    RouteMismatch,
+
+    #[msg("Output token isn't accepted as activation fee")]
+    UnacceptedFeeAsset,
+
+    #[msg("Pool has insufficient liquidity")]
+    InsufficientLiquidity,
+
+    #[msg("Fee exceeds maximum allowed")]
+    FeeTooHigh,
+
+    #[msg("Exceeded available asset amount")]
+    ExceededAvailableAssetAmount,
+
+    #[msg("DEX program isn't specified in config")]
+    DexNotConfigured,
+
+    #[msg("Operation isn't supported by the route DEX")]
+    UnsupportedDexKind,
+
+    #[msg("Route output token doesn't match the last mint")]
+    RouteOutputMismatch,
+
+    #[msg("Protocol fee token account isn't provided")]
+    FeeAccountNotProvided,
+
+    #[msg("Route hop output token must differ from its input token")]
+    SameHopTokens,
 }
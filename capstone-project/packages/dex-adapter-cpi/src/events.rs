@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct AdminRotationCancelled {
+    pub admin: Pubkey,
+    pub new_admin: Pubkey,
+}
+
+#[event]
+pub struct FeesWithdrawn {
+    pub asset: Pubkey,
+    pub amount: u64,
+    pub recipient: Pubkey,
+}
+
+#[event]
+pub struct AccountMigrated {
+    pub address: Pubkey,
+    pub version: u8,
+}
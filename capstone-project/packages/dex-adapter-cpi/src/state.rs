@@ -1,9 +1,18 @@
-use {crate::types::RouteItem, anchor_lang::prelude::*};
+use {
+    crate::types::{FeeSide, RouteItem},
+    anchor_lang::prelude::*,
+    base::{
+        migration::Versioned,
+        pause::{PAUSE_NONE, PAUSE_SWAP, PAUSE_SWAP_AND_ACTIVATE, PAUSE_UNWRAP},
+    },
+};
 
 pub const SECONDS_PER_DAY: u32 = 24 * 3_600;
 pub const ROTATION_TIMEOUT: u32 = SECONDS_PER_DAY;
 pub const TOKEN_IN_WHITELIST_MAX_LEN: usize = 16;
 pub const ROUTE_MAX_LEN: usize = 4;
+pub const BPS_MAX: u16 = 10_000;
+pub const FEE_BPS_MAX: u16 = 1_000;
 
 pub const CLOCK_TIME_MIN: u64 = 1750000000;
 pub const MAINNET_ADMIN: Pubkey =
@@ -29,10 +38,87 @@ pub struct DaBump {
 pub struct DaConfig {
     /// can update the config and execute priveled instructions
     pub admin: Pubkey,
+    /// clmm program
     pub dex: Pubkey,
     pub registry: Option<Pubkey>,
-    pub is_paused: bool,
+    /// bit mask of paused operations, see base::pause
+    pub paused_ops: u8,
     pub rotation_timeout: u32,
+    /// protocol fee taken from swap input or output amount, see fee_side
+    pub fee_bps: u16,
+    /// constant product amm program
+    pub amm: Option<Pubkey>,
+    pub fee_side: FeeSide,
+    pub version: u8,
+}
+
+impl Versioned for DaConfig {
+    const VERSION: u8 = 1;
+
+    fn get_version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+
+    fn upgrade_from(&mut self, version: u8) {
+        if version < 1 {
+            // legacy is_paused flag blocked all swaps
+            if self.paused_ops != PAUSE_NONE {
+                self.paused_ops = PAUSE_SWAP | PAUSE_SWAP_AND_ACTIVATE | PAUSE_UNWRAP;
+            }
+        }
+    }
+}
+
+impl DaConfig {
+    /// route fee overrides the config one
+    pub fn get_fee_bps(&self, route: &Route) -> u16 {
+        route.fee_bps.unwrap_or(self.fee_bps)
+    }
+
+    /// route fee side overrides the config one
+    pub fn get_fee_side(&self, route: &Route) -> FeeSide {
+        route.fee_side.unwrap_or(self.fee_side)
+    }
+
+    pub fn get_fee_amount(&self, route: &Route, amount: u64) -> u64 {
+        (amount as u128 * self.get_fee_bps(route) as u128 / BPS_MAX as u128) as u64
+    }
+
+    /// returns the fee to receive on top of net amount, so net amount remains after the fee
+    pub fn get_fee_amount_on_top(&self, route: &Route, net_amount: u64) -> u64 {
+        let gross_amount = (net_amount as u128 * BPS_MAX as u128)
+            .div_ceil((BPS_MAX - self.get_fee_bps(route)) as u128);
+
+        (gross_amount - net_amount as u128) as u64
+    }
+
+    /// fee taken from input amount before the swap, it's zero for output fee side
+    pub fn get_input_fee_amount(&self, route: &Route, amount_in: u64) -> u64 {
+        match self.get_fee_side(route) {
+            FeeSide::Input => self.get_fee_amount(route, amount_in),
+            FeeSide::Output => 0,
+        }
+    }
+
+    /// fee taken from output amount after the swap, it's zero for input fee side
+    pub fn get_output_fee_amount(&self, route: &Route, amount_out: u64) -> u64 {
+        match self.get_fee_side(route) {
+            FeeSide::Input => 0,
+            FeeSide::Output => self.get_fee_amount(route, amount_out),
+        }
+    }
+
+    /// fee received on top of exact output amount, it's zero for input fee side
+    pub fn get_output_fee_amount_on_top(&self, route: &Route, net_amount_out: u64) -> u64 {
+        match self.get_fee_side(route) {
+            FeeSide::Input => 0,
+            FeeSide::Output => self.get_fee_amount_on_top(route, net_amount_out),
+        }
+    }
 }
 
 /// to transfer ownership from one address to another in 2 steps (for security reasons)
@@ -49,4 +135,21 @@ pub struct RotationState {
 pub struct Route {
     #[max_len(ROUTE_MAX_LEN)]
     pub value: Vec<RouteItem>,
+    pub fee_bps: Option<u16>,
+    pub fee_side: Option<FeeSide>,
+    pub version: u8,
+}
+
+/// baseline route items had no dex and pool_id, such routes are rewritten by the migration \
+/// instead of being reallocated, see dex_adapter::migrate
+impl Versioned for Route {
+    const VERSION: u8 = 1;
+
+    fn get_version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
 }
@@ -7,8 +7,89 @@ pub struct SwapRouterBaseInData {
     pub amount_out_minimum: u64,
 }
 
+#[derive(AnchorSerialize)]
+pub struct SwapRouterBaseOutData {
+    pub discriminator: [u8; 8],
+    pub amount_out: u64,
+    pub amount_in_maximum: u64,
+}
+
+#[derive(AnchorSerialize)]
+pub struct AmmSwapData {
+    pub discriminator: [u8; 8],
+    pub id: u64,
+    pub amount_in: u64,
+    pub mint_in: Pubkey,
+}
+
+/// config fields to update, unspecified ones are kept, at least one must be specified
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, PartialEq, Debug)]
+pub struct UpdateConfigArgs {
+    /// new admin must confirm the rotation
+    pub admin: Option<Pubkey>,
+    pub dex: Option<Pubkey>,
+    pub registry: Option<Pubkey>,
+    pub paused_ops: Option<u8>,
+    pub rotation_timeout: Option<u32>,
+    pub fee_bps: Option<u16>,
+    pub amm: Option<Pubkey>,
+    pub fee_side: Option<FeeSide>,
+}
+
+/// token the protocol fee is taken in
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FeeSide {
+    /// fee is taken from input amount before the swap
+    Input,
+    /// fee is taken from output amount after the swap
+    Output,
+}
+
+/// program executing the route hop
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DexKind {
+    /// concentrated liquidity pools, config.dex
+    Clmm,
+    /// constant product pools, config.amm
+    Amm,
+}
+
+impl DexKind {
+    /// number of remaining accounts required for a single hop
+    pub const fn hop_accounts_len(&self) -> usize {
+        match self {
+            Self::Clmm => 7,
+            Self::Amm => 9,
+        }
+    }
+}
+
 #[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Debug)]
 pub struct RouteItem {
+    /// amm config index, used by Clmm hops
     pub amm_index: u16,
     pub token_out: Pubkey,
+    pub dex: DexKind,
+    /// pool id, used by Amm hops
+    pub pool_id: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Debug)]
+pub struct HopQuote {
+    pub amount_in: u64,
+    pub amount_out: u64,
+}
+
+/// expected result of a swap along the saved route
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Debug)]
+pub struct RouteQuote {
+    pub amount_in: u64,
+    /// protocol fee taken from amount_in before the first hop or from the last hop output
+    pub fee_amount: u64,
+    pub fee_side: FeeSide,
+    /// output amount after the protocol fee
+    pub amount_out: u64,
+    pub hops: Vec<HopQuote>,
+    /// output reduction caused by pool reserves change, fees aren't included
+    pub price_impact_bps: u16,
 }
@@ -0,0 +1,55 @@
+use {
+    crate::state::{
+        Bump, Config, FeeWhitelist, PromoCode, RotationState, UserAccount, UserCounter,
+        UserDelegate, UserId, WriteBuffer,
+    },
+    anchor_lang::prelude::*,
+};
+
+pub use crate::helpers::decode_user_index_page;
+
+/// decodes registry account from raw account data, discriminator is checked
+pub fn decode_account<T: AccountDeserialize>(data: &[u8]) -> Result<T> {
+    T::try_deserialize(&mut &data[..])
+}
+
+pub fn decode_bump(data: &[u8]) -> Result<Bump> {
+    decode_account(data)
+}
+
+pub fn decode_config(data: &[u8]) -> Result<Config> {
+    decode_account(data)
+}
+
+pub fn decode_user_counter(data: &[u8]) -> Result<UserCounter> {
+    decode_account(data)
+}
+
+pub fn decode_fee_whitelist(data: &[u8]) -> Result<FeeWhitelist> {
+    decode_account(data)
+}
+
+pub fn decode_promo_code(data: &[u8]) -> Result<PromoCode> {
+    decode_account(data)
+}
+
+/// admin and user rotation states share the layout
+pub fn decode_rotation_state(data: &[u8]) -> Result<RotationState> {
+    decode_account(data)
+}
+
+pub fn decode_user_id(data: &[u8]) -> Result<UserId> {
+    decode_account(data)
+}
+
+pub fn decode_user_account(data: &[u8]) -> Result<UserAccount> {
+    decode_account(data)
+}
+
+pub fn decode_user_delegate(data: &[u8]) -> Result<UserDelegate> {
+    decode_account(data)
+}
+
+pub fn decode_write_buffer(data: &[u8]) -> Result<WriteBuffer> {
+    decode_account(data)
+}
@@ -0,0 +1,770 @@
+use {
+    crate::{
+        client::pda::{
+            get_admin_rotation_state_address, get_bump_address, get_config_address,
+            get_fee_whitelist_address, get_promo_code_address, get_user_account_address,
+            get_user_counter_address, get_user_delegate_address, get_user_id_address,
+            get_user_index_page_address, get_user_rotation_state_address,
+            get_user_write_buffer_address,
+        },
+        state::UserIndexPage,
+        types::{AssetItem, DataHeader, Range, UpdateConfigArgs},
+    },
+    anchor_lang::{
+        prelude::*,
+        solana_program::{hash::hash, instruction::Instruction},
+        system_program,
+    },
+    anchor_spl::associated_token::{self, get_associated_token_address_with_program_id},
+};
+
+/// args are serialized as a tuple which matches anchor instruction layout
+fn build_instruction(
+    name: &str,
+    accounts: Vec<AccountMeta>,
+    args: impl AnchorSerialize,
+) -> Result<Instruction> {
+    let mut data = hash(format!("global:{name}").as_bytes()).to_bytes()[..8].to_vec();
+    args.serialize(&mut data)
+        .map_err(|_| error!(ErrorCode::InstructionDidNotSerialize))?;
+
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts,
+        data,
+    })
+}
+
+/// anchor expects the program address in place of omitted optional account
+fn optional_account(address: Option<Pubkey>) -> AccountMeta {
+    match address {
+        Some(x) => AccountMeta::new(x, false),
+        None => AccountMeta::new_readonly(crate::ID, false),
+    }
+}
+
+fn get_ata(owner: &Pubkey, mint: &Pubkey, token_program: &Pubkey) -> Pubkey {
+    get_associated_token_address_with_program_id(owner, mint, token_program)
+}
+
+// admin
+//
+pub fn init(
+    sender: Pubkey,
+    token_program: Pubkey,
+    revenue_mint: Pubkey,
+    rotation_timeout: Option<u32>,
+    account_registration_fee: Option<AssetItem>,
+    account_data_size_range: Option<Range>,
+    activation_period: Option<u32>,
+) -> Result<Instruction> {
+    let config = get_config_address();
+
+    build_instruction(
+        "init",
+        vec![
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(token_program, false),
+            AccountMeta::new_readonly(associated_token::ID, false),
+            AccountMeta::new(sender, true),
+            AccountMeta::new(get_bump_address(), false),
+            AccountMeta::new(config, false),
+            AccountMeta::new(get_user_counter_address(), false),
+            AccountMeta::new(get_admin_rotation_state_address(), false),
+            AccountMeta::new(get_fee_whitelist_address(), false),
+            AccountMeta::new_readonly(revenue_mint, false),
+            AccountMeta::new(get_ata(&config, &revenue_mint, &token_program), false),
+        ],
+        (
+            rotation_timeout,
+            account_registration_fee,
+            account_data_size_range,
+            activation_period,
+        ),
+    )
+}
+
+pub fn update_config(sender: Pubkey, args: UpdateConfigArgs) -> Result<Instruction> {
+    build_instruction(
+        "update_config",
+        vec![
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new(sender, true),
+            AccountMeta::new_readonly(get_bump_address(), false),
+            AccountMeta::new(get_config_address(), false),
+            AccountMeta::new(get_admin_rotation_state_address(), false),
+            AccountMeta::new(get_fee_whitelist_address(), false),
+        ],
+        (args,),
+    )
+}
+
+pub fn confirm_admin_rotation(sender: Pubkey) -> Result<Instruction> {
+    build_instruction(
+        "confirm_admin_rotation",
+        vec![
+            AccountMeta::new_readonly(sender, true),
+            AccountMeta::new_readonly(get_bump_address(), false),
+            AccountMeta::new(get_config_address(), false),
+            AccountMeta::new(get_admin_rotation_state_address(), false),
+        ],
+        (),
+    )
+}
+
+pub fn cancel_admin_rotation(sender: Pubkey) -> Result<Instruction> {
+    build_instruction(
+        "cancel_admin_rotation",
+        vec![
+            AccountMeta::new_readonly(sender, true),
+            AccountMeta::new_readonly(get_bump_address(), false),
+            AccountMeta::new_readonly(get_config_address(), false),
+            AccountMeta::new(get_admin_rotation_state_address(), false),
+        ],
+        (),
+    )
+}
+
+pub fn withdraw_revenue(
+    sender: Pubkey,
+    recipient: Pubkey,
+    token_program: Pubkey,
+    revenue_mint: Pubkey,
+    amount: Option<u64>,
+) -> Result<Instruction> {
+    let config = get_config_address();
+
+    build_instruction(
+        "withdraw_revenue",
+        vec![
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(token_program, false),
+            AccountMeta::new_readonly(associated_token::ID, false),
+            AccountMeta::new(sender, true),
+            AccountMeta::new_readonly(recipient, false),
+            AccountMeta::new_readonly(get_bump_address(), false),
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new_readonly(revenue_mint, false),
+            AccountMeta::new(get_ata(&recipient, &revenue_mint, &token_program), false),
+            AccountMeta::new(get_ata(&config, &revenue_mint, &token_program), false),
+        ],
+        (amount,),
+    )
+}
+
+pub fn withdraw_revenue_with_sol(
+    sender: Pubkey,
+    recipient: Pubkey,
+    amount: Option<u64>,
+) -> Result<Instruction> {
+    build_instruction(
+        "withdraw_revenue_with_sol",
+        vec![
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new(sender, true),
+            AccountMeta::new(recipient, false),
+            AccountMeta::new_readonly(get_bump_address(), false),
+            AccountMeta::new(get_config_address(), false),
+        ],
+        (amount,),
+    )
+}
+
+pub fn suspend_account(sender: Pubkey, user: Pubkey) -> Result<Instruction> {
+    build_instruction(
+        "suspend_account",
+        suspend_account_metas(sender, &user),
+        (user,),
+    )
+}
+
+pub fn unsuspend_account(sender: Pubkey, user: Pubkey) -> Result<Instruction> {
+    build_instruction(
+        "unsuspend_account",
+        suspend_account_metas(sender, &user),
+        (user,),
+    )
+}
+
+fn suspend_account_metas(sender: Pubkey, user: &Pubkey) -> Vec<AccountMeta> {
+    vec![
+        AccountMeta::new_readonly(sender, true),
+        AccountMeta::new_readonly(get_bump_address(), false),
+        AccountMeta::new_readonly(get_config_address(), false),
+        AccountMeta::new(get_user_id_address(user), false),
+    ]
+}
+
+/// rent_recipient is UserId::get_rent_recipient(user)
+pub fn force_close_account(
+    sender: Pubkey,
+    user: Pubkey,
+    id: u32,
+    rent_recipient: Pubkey,
+) -> Result<Instruction> {
+    build_instruction(
+        "force_close_account",
+        vec![
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(sender, true),
+            AccountMeta::new(rent_recipient, false),
+            AccountMeta::new(user, false),
+            AccountMeta::new_readonly(get_bump_address(), false),
+            AccountMeta::new_readonly(get_config_address(), false),
+            AccountMeta::new(get_user_id_address(&user), false),
+            AccountMeta::new(get_user_account_address(id), false),
+            AccountMeta::new(get_user_rotation_state_address(id), false),
+            AccountMeta::new(get_user_write_buffer_address(id), false),
+        ],
+        (user,),
+    )
+}
+
+pub fn create_promo_code(
+    sender: Pubkey,
+    code: &str,
+    discount_bps: u16,
+    referrer: Option<Pubkey>,
+    referrer_share_bps: u16,
+    usage_limit: Option<u32>,
+    expiration_date: Option<u64>,
+) -> Result<Instruction> {
+    build_instruction(
+        "create_promo_code",
+        promo_code_metas(sender, code)?,
+        (
+            code.to_string(),
+            discount_bps,
+            referrer,
+            referrer_share_bps,
+            usage_limit,
+            expiration_date,
+        ),
+    )
+}
+
+pub fn close_promo_code(sender: Pubkey, code: &str) -> Result<Instruction> {
+    build_instruction(
+        "close_promo_code",
+        promo_code_metas(sender, code)?,
+        (code.to_string(),),
+    )
+}
+
+fn promo_code_metas(sender: Pubkey, code: &str) -> Result<Vec<AccountMeta>> {
+    Ok(vec![
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new(sender, true),
+        AccountMeta::new_readonly(get_bump_address(), false),
+        AccountMeta::new_readonly(get_config_address(), false),
+        AccountMeta::new(get_promo_code_address(code)?, false),
+    ])
+}
+
+/// users are account owners, up to BATCH_SIZE_MAX
+pub fn batch_activate_accounts(sender: Pubkey, users: &[Pubkey]) -> Result<Instruction> {
+    build_instruction(
+        "batch_activate_accounts",
+        batch_update_accounts_metas(sender, users),
+        (),
+    )
+}
+
+/// users are account owners, up to BATCH_SIZE_MAX
+pub fn batch_suspend_accounts(sender: Pubkey, users: &[Pubkey]) -> Result<Instruction> {
+    build_instruction(
+        "batch_suspend_accounts",
+        batch_update_accounts_metas(sender, users),
+        (),
+    )
+}
+
+/// users are (owner, UserId::id, UserId::get_rent_recipient(owner)), up to BATCH_SIZE_MAX
+pub fn batch_close_accounts(
+    sender: Pubkey,
+    users: &[(Pubkey, u32, Pubkey)],
+) -> Result<Instruction> {
+    let mut accounts = batch_update_accounts_metas(sender, &[]);
+
+    for (user, id, rent_recipient) in users {
+        accounts.extend([
+            AccountMeta::new(*user, false),
+            AccountMeta::new(get_user_id_address(user), false),
+            AccountMeta::new(get_user_account_address(*id), false),
+            AccountMeta::new(get_user_rotation_state_address(*id), false),
+            AccountMeta::new(get_user_write_buffer_address(*id), false),
+            AccountMeta::new(*rent_recipient, false),
+        ]);
+    }
+
+    build_instruction("batch_close_accounts", accounts, ())
+}
+
+fn batch_update_accounts_metas(sender: Pubkey, users: &[Pubkey]) -> Vec<AccountMeta> {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(sender, true),
+        AccountMeta::new_readonly(get_bump_address(), false),
+        AccountMeta::new_readonly(get_config_address(), false),
+    ];
+
+    for user in users {
+        accounts.extend([
+            AccountMeta::new_readonly(*user, false),
+            AccountMeta::new(get_user_id_address(user), false),
+        ]);
+    }
+
+    accounts
+}
+
+/// users are owners of UserId accounts to migrate \
+/// user_ids are ids of user RotationState accounts to migrate
+pub fn migrate(sender: Pubkey, users: &[Pubkey], user_ids: &[u32]) -> Result<Instruction> {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new(sender, true),
+        AccountMeta::new(get_bump_address(), false),
+        AccountMeta::new(get_config_address(), false),
+        AccountMeta::new(get_admin_rotation_state_address(), false),
+        AccountMeta::new(get_fee_whitelist_address(), false),
+    ];
+
+    accounts.extend(
+        users
+            .iter()
+            .map(|x| AccountMeta::new(get_user_id_address(x), false)),
+    );
+    accounts.extend(
+        user_ids
+            .iter()
+            .map(|x| AccountMeta::new(get_user_rotation_state_address(*x), false)),
+    );
+
+    build_instruction("migrate", accounts, ())
+}
+
+// user
+//
+/// id is expected UserId::id, i.e. UserCounter::last_user_id + 1 \
+/// the payer distinct from the sender is a sponsor
+pub fn create_account(
+    sender: Pubkey,
+    payer: Pubkey,
+    id: u32,
+    max_data_size: u32,
+    is_rent_reclaimable: bool,
+) -> Result<Instruction> {
+    build_instruction(
+        "create_account",
+        vec![
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(sender, true),
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(get_bump_address(), false),
+            AccountMeta::new_readonly(get_config_address(), false),
+            AccountMeta::new(get_user_counter_address(), false),
+            AccountMeta::new(get_user_id_address(&sender), false),
+            AccountMeta::new(get_user_account_address(id), false),
+            AccountMeta::new(get_user_rotation_state_address(id), false),
+            AccountMeta::new(
+                get_user_index_page_address(UserIndexPage::get_page(id)),
+                false,
+            ),
+        ],
+        (max_data_size, is_rent_reclaimable),
+    )
+}
+
+/// rent_recipient is UserId::get_rent_recipient(sender)
+pub fn close_account(sender: Pubkey, id: u32, rent_recipient: Pubkey) -> Result<Instruction> {
+    build_instruction(
+        "close_account",
+        vec![
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new(sender, true),
+            AccountMeta::new(rent_recipient, false),
+            AccountMeta::new(get_user_id_address(&sender), false),
+            AccountMeta::new(get_user_account_address(id), false),
+            AccountMeta::new(get_user_rotation_state_address(id), false),
+            AccountMeta::new(get_user_write_buffer_address(id), false),
+        ],
+        (),
+    )
+}
+
+pub fn reopen_account(sender: Pubkey, id: u32, max_data_size: u32) -> Result<Instruction> {
+    build_instruction(
+        "reopen_account",
+        vec![
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new(sender, true),
+            AccountMeta::new_readonly(get_bump_address(), false),
+            AccountMeta::new_readonly(get_config_address(), false),
+            AccountMeta::new(get_user_id_address(&sender), false),
+            AccountMeta::new(get_user_account_address(id), false),
+            AccountMeta::new(get_user_rotation_state_address(id), false),
+        ],
+        (max_data_size,),
+    )
+}
+
+/// rent_payer is UserId::get_rent_recipient, the sponsor must sign if it reclaims rent
+pub fn resize_account(
+    sender: Pubkey,
+    id: u32,
+    max_data_size: u32,
+    rent_payer: Pubkey,
+) -> Result<Instruction> {
+    build_instruction(
+        "resize_account",
+        vec![
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new(sender, true),
+            AccountMeta::new(rent_payer, true),
+            AccountMeta::new_readonly(get_bump_address(), false),
+            AccountMeta::new_readonly(get_config_address(), false),
+            AccountMeta::new_readonly(get_user_id_address(&sender), false),
+            AccountMeta::new(get_user_account_address(id), false),
+        ],
+        (max_data_size,),
+    )
+}
+
+pub fn migrate_user_account(sender: Pubkey, id: u32) -> Result<Instruction> {
+    build_instruction(
+        "migrate_user_account",
+        vec![
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new(sender, true),
+            AccountMeta::new_readonly(get_user_id_address(&sender), false),
+            AccountMeta::new(get_user_account_address(id), false),
+        ],
+        (),
+    )
+}
+
+/// referrer is PromoCode::referrer if promo code is specified
+pub fn activate_account(
+    sender: Pubkey,
+    user: Pubkey,
+    token_program: Pubkey,
+    revenue_mint: Pubkey,
+    promo_code: Option<&str>,
+    referrer: Option<Pubkey>,
+) -> Result<Instruction> {
+    build_instruction(
+        "activate_account",
+        activate_account_metas(
+            sender,
+            &user,
+            token_program,
+            revenue_mint,
+            promo_code,
+            referrer,
+        )?,
+        (user,),
+    )
+}
+
+/// referrer is PromoCode::referrer if promo code is specified
+pub fn renew_activation(
+    sender: Pubkey,
+    user: Pubkey,
+    token_program: Pubkey,
+    revenue_mint: Pubkey,
+    promo_code: Option<&str>,
+    referrer: Option<Pubkey>,
+) -> Result<Instruction> {
+    build_instruction(
+        "renew_activation",
+        activate_account_metas(
+            sender,
+            &user,
+            token_program,
+            revenue_mint,
+            promo_code,
+            referrer,
+        )?,
+        (user,),
+    )
+}
+
+fn activate_account_metas(
+    sender: Pubkey,
+    user: &Pubkey,
+    token_program: Pubkey,
+    revenue_mint: Pubkey,
+    promo_code: Option<&str>,
+    referrer: Option<Pubkey>,
+) -> Result<Vec<AccountMeta>> {
+    let config = get_config_address();
+
+    Ok(vec![
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(token_program, false),
+        AccountMeta::new_readonly(associated_token::ID, false),
+        AccountMeta::new(sender, true),
+        AccountMeta::new_readonly(get_bump_address(), false),
+        AccountMeta::new_readonly(config, false),
+        AccountMeta::new_readonly(get_fee_whitelist_address(), false),
+        AccountMeta::new(get_user_id_address(user), false),
+        optional_account(promo_code.map(get_promo_code_address).transpose()?),
+        AccountMeta::new_readonly(revenue_mint, false),
+        AccountMeta::new(get_ata(&sender, &revenue_mint, &token_program), false),
+        AccountMeta::new(get_ata(&config, &revenue_mint, &token_program), false),
+        optional_account(referrer.map(|x| get_ata(&x, &revenue_mint, &token_program))),
+    ])
+}
+
+/// referrer is PromoCode::referrer if promo code is specified
+pub fn activate_account_with_sol(
+    sender: Pubkey,
+    user: Pubkey,
+    promo_code: Option<&str>,
+    referrer: Option<Pubkey>,
+) -> Result<Instruction> {
+    build_instruction(
+        "activate_account_with_sol",
+        activate_account_with_sol_metas(sender, &user, promo_code, referrer)?,
+        (user,),
+    )
+}
+
+/// referrer is PromoCode::referrer if promo code is specified
+pub fn renew_activation_with_sol(
+    sender: Pubkey,
+    user: Pubkey,
+    promo_code: Option<&str>,
+    referrer: Option<Pubkey>,
+) -> Result<Instruction> {
+    build_instruction(
+        "renew_activation_with_sol",
+        activate_account_with_sol_metas(sender, &user, promo_code, referrer)?,
+        (user,),
+    )
+}
+
+fn activate_account_with_sol_metas(
+    sender: Pubkey,
+    user: &Pubkey,
+    promo_code: Option<&str>,
+    referrer: Option<Pubkey>,
+) -> Result<Vec<AccountMeta>> {
+    Ok(vec![
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new(sender, true),
+        AccountMeta::new_readonly(get_bump_address(), false),
+        AccountMeta::new(get_config_address(), false),
+        AccountMeta::new_readonly(get_fee_whitelist_address(), false),
+        AccountMeta::new(get_user_id_address(user), false),
+        optional_account(promo_code.map(get_promo_code_address).transpose()?),
+        optional_account(referrer),
+    ])
+}
+
+/// the sender is the owner or its delegate, id is UserId::id of the owner
+pub fn write_data(
+    sender: Pubkey,
+    user: Pubkey,
+    id: u32,
+    data: &str,
+    nonce: u64,
+    expected_nonce: Option<u64>,
+    header: DataHeader,
+) -> Result<Instruction> {
+    build_instruction(
+        "write_data",
+        write_data_metas(sender, &user, id),
+        (user, data.to_string(), nonce, expected_nonce, header),
+    )
+}
+
+/// the sender is the owner or its delegate, id is UserId::id of the owner
+pub fn write_bytes(
+    sender: Pubkey,
+    user: Pubkey,
+    id: u32,
+    data: &[u8],
+    nonce: u64,
+    expected_nonce: Option<u64>,
+    header: DataHeader,
+) -> Result<Instruction> {
+    build_instruction(
+        "write_bytes",
+        write_data_metas(sender, &user, id),
+        (user, data.to_vec(), nonce, expected_nonce, header),
+    )
+}
+
+fn write_data_metas(sender: Pubkey, user: &Pubkey, id: u32) -> Vec<AccountMeta> {
+    let user_delegate = if &sender != user {
+        AccountMeta::new_readonly(get_user_delegate_address(id, &sender), false)
+    } else {
+        AccountMeta::new_readonly(crate::ID, false)
+    };
+
+    vec![
+        AccountMeta::new_readonly(sender, true),
+        AccountMeta::new_readonly(get_bump_address(), false),
+        AccountMeta::new_readonly(get_config_address(), false),
+        AccountMeta::new_readonly(get_user_id_address(user), false),
+        AccountMeta::new(get_user_account_address(id), false),
+        user_delegate,
+    ]
+}
+
+pub fn grant_delegate(
+    sender: Pubkey,
+    id: u32,
+    delegate: Pubkey,
+    expiration_date: Option<u64>,
+) -> Result<Instruction> {
+    build_instruction(
+        "grant_delegate",
+        user_delegate_metas(sender, id, &delegate),
+        (delegate, expiration_date),
+    )
+}
+
+pub fn revoke_delegate(sender: Pubkey, id: u32, delegate: Pubkey) -> Result<Instruction> {
+    build_instruction(
+        "revoke_delegate",
+        user_delegate_metas(sender, id, &delegate),
+        (delegate,),
+    )
+}
+
+fn user_delegate_metas(sender: Pubkey, id: u32, delegate: &Pubkey) -> Vec<AccountMeta> {
+    vec![
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new(sender, true),
+        AccountMeta::new_readonly(get_user_id_address(&sender), false),
+        AccountMeta::new(get_user_delegate_address(id, delegate), false),
+    ]
+}
+
+pub fn begin_data_write(sender: Pubkey, id: u32, data_size: u32) -> Result<Instruction> {
+    build_instruction(
+        "begin_data_write",
+        vec![
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new(sender, true),
+            AccountMeta::new_readonly(get_bump_address(), false),
+            AccountMeta::new_readonly(get_config_address(), false),
+            AccountMeta::new_readonly(get_user_id_address(&sender), false),
+            AccountMeta::new_readonly(get_user_account_address(id), false),
+            AccountMeta::new(get_user_write_buffer_address(id), false),
+        ],
+        (data_size,),
+    )
+}
+
+pub fn append_data_chunk(
+    sender: Pubkey,
+    id: u32,
+    offset: u32,
+    chunk: &[u8],
+) -> Result<Instruction> {
+    build_instruction(
+        "append_data_chunk",
+        vec![
+            AccountMeta::new_readonly(sender, true),
+            AccountMeta::new_readonly(get_bump_address(), false),
+            AccountMeta::new_readonly(get_config_address(), false),
+            AccountMeta::new_readonly(get_user_id_address(&sender), false),
+            AccountMeta::new(get_user_write_buffer_address(id), false),
+        ],
+        (offset, chunk.to_vec()),
+    )
+}
+
+pub fn commit_data_write(
+    sender: Pubkey,
+    id: u32,
+    nonce: u64,
+    expected_nonce: Option<u64>,
+    header: DataHeader,
+) -> Result<Instruction> {
+    build_instruction(
+        "commit_data_write",
+        vec![
+            AccountMeta::new(sender, true),
+            AccountMeta::new_readonly(get_bump_address(), false),
+            AccountMeta::new_readonly(get_config_address(), false),
+            AccountMeta::new_readonly(get_user_id_address(&sender), false),
+            AccountMeta::new(get_user_account_address(id), false),
+            AccountMeta::new(get_user_write_buffer_address(id), false),
+        ],
+        (nonce, expected_nonce, header),
+    )
+}
+
+pub fn abort_data_write(sender: Pubkey, id: u32) -> Result<Instruction> {
+    build_instruction(
+        "abort_data_write",
+        vec![
+            AccountMeta::new(sender, true),
+            AccountMeta::new_readonly(get_user_id_address(&sender), false),
+            AccountMeta::new(get_user_write_buffer_address(id), false),
+        ],
+        (),
+    )
+}
+
+pub fn request_account_rotation(
+    sender: Pubkey,
+    id: u32,
+    new_owner: Pubkey,
+    clear_data: bool,
+) -> Result<Instruction> {
+    build_instruction(
+        "request_account_rotation",
+        vec![
+            AccountMeta::new_readonly(sender, true),
+            AccountMeta::new_readonly(get_bump_address(), false),
+            AccountMeta::new_readonly(get_config_address(), false),
+            AccountMeta::new_readonly(get_user_id_address(&sender), false),
+            AccountMeta::new(get_user_rotation_state_address(id), false),
+        ],
+        (new_owner, clear_data),
+    )
+}
+
+/// the sender is the new owner, prev_owner and id are taken from the rotation request
+pub fn confirm_account_rotation(
+    sender: Pubkey,
+    prev_owner: Pubkey,
+    id: u32,
+) -> Result<Instruction> {
+    build_instruction(
+        "confirm_account_rotation",
+        vec![
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new(sender, true),
+            AccountMeta::new_readonly(get_bump_address(), false),
+            AccountMeta::new_readonly(get_config_address(), false),
+            AccountMeta::new(get_user_id_address(&prev_owner), false),
+            AccountMeta::new(get_user_id_address(&sender), false),
+            AccountMeta::new(get_user_rotation_state_address(id), false),
+            AccountMeta::new(get_user_account_address(id), false),
+            AccountMeta::new_readonly(get_user_write_buffer_address(id), false),
+            AccountMeta::new(
+                get_user_index_page_address(UserIndexPage::get_page(id)),
+                false,
+            ),
+        ],
+        (),
+    )
+}
+
+pub fn cancel_account_rotation(sender: Pubkey, id: u32) -> Result<Instruction> {
+    build_instruction(
+        "cancel_account_rotation",
+        vec![
+            AccountMeta::new_readonly(sender, true),
+            AccountMeta::new_readonly(get_user_id_address(&sender), false),
+            AccountMeta::new(get_user_rotation_state_address(id), false),
+        ],
+        (),
+    )
+}
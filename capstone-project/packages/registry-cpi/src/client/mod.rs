@@ -0,0 +1,3 @@
+pub mod decode;
+pub mod instruction;
+pub mod pda;
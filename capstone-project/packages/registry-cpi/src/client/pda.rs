@@ -0,0 +1,70 @@
+use {
+    crate::state::{
+        PromoCode, SEED_ADMIN_ROTATION_STATE, SEED_BUMP, SEED_CONFIG, SEED_FEE_WHITELIST,
+        SEED_PROMO_CODE, SEED_USER_ACCOUNT, SEED_USER_COUNTER, SEED_USER_DELEGATE, SEED_USER_ID,
+        SEED_USER_ROTATION_STATE, SEED_USER_WRITE_BUFFER,
+    },
+    anchor_lang::prelude::*,
+};
+
+pub use crate::helpers::get_user_index_page_address;
+
+fn find_address(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &crate::ID).0
+}
+
+pub fn get_bump_address() -> Pubkey {
+    find_address(&[SEED_BUMP.as_bytes()])
+}
+
+pub fn get_config_address() -> Pubkey {
+    find_address(&[SEED_CONFIG.as_bytes()])
+}
+
+pub fn get_user_counter_address() -> Pubkey {
+    find_address(&[SEED_USER_COUNTER.as_bytes()])
+}
+
+pub fn get_admin_rotation_state_address() -> Pubkey {
+    find_address(&[SEED_ADMIN_ROTATION_STATE.as_bytes()])
+}
+
+pub fn get_fee_whitelist_address() -> Pubkey {
+    find_address(&[SEED_FEE_WHITELIST.as_bytes()])
+}
+
+/// fails if the code length is out of range as it can't be used as a seed
+pub fn get_promo_code_address(code: &str) -> Result<Pubkey> {
+    Ok(find_address(&[
+        SEED_PROMO_CODE.as_bytes(),
+        PromoCode::get_seed(code)?,
+    ]))
+}
+
+/// the only user PDA derived from the owner address, others are derived from UserId::id
+pub fn get_user_id_address(user: &Pubkey) -> Pubkey {
+    find_address(&[SEED_USER_ID.as_bytes(), user.as_ref()])
+}
+
+pub fn get_user_account_address(id: u32) -> Pubkey {
+    find_address(&[SEED_USER_ACCOUNT.as_bytes(), id.to_le_bytes().as_ref()])
+}
+
+pub fn get_user_rotation_state_address(id: u32) -> Pubkey {
+    find_address(&[
+        SEED_USER_ROTATION_STATE.as_bytes(),
+        id.to_le_bytes().as_ref(),
+    ])
+}
+
+pub fn get_user_write_buffer_address(id: u32) -> Pubkey {
+    find_address(&[SEED_USER_WRITE_BUFFER.as_bytes(), id.to_le_bytes().as_ref()])
+}
+
+pub fn get_user_delegate_address(id: u32, delegate: &Pubkey) -> Pubkey {
+    find_address(&[
+        SEED_USER_DELEGATE.as_bytes(),
+        id.to_le_bytes().as_ref(),
+        delegate.as_ref(),
+    ])
+}
@@ -45,6 +45,84 @@ pub enum CustomError {
 
     #[msg("Nonce must be unique!")]
     BadNonce,
+
+    #[msg("Stored nonce doesn't match expected one!")]
+    NonceMismatch,
+
+    #[msg("Chunk offset doesn't match written data length!")]
+    BadChunkOffset,
+
+    #[msg("Data write isn't completed!")]
+    IncompleteDataWrite,
+
+    #[msg("Data isn't valid UTF-8!")]
+    InvalidDataEncoding,
+
+    #[msg("Data header version isn't supported!")]
+    UnsupportedHeaderVersion,
+
+    #[msg("Data header content length doesn't match data length!")]
+    ContentLengthMismatch,
+
+    #[msg("Data header checksum doesn't match data!")]
+    ChecksumMismatch,
+
+    #[msg("Stored data doesn't fit in new data size!")]
+    DataDoesNotFit,
+
+    #[msg("Fee whitelist is too long!")]
+    FeeWhitelistIsTooLong,
+
+    #[msg("Fee asset list has duplications!")]
+    FeeAssetDuplication,
+
+    #[msg("Account activation is expired!")]
+    ActivationIsExpired,
+
+    #[msg("Account owner can't be the delegate!")]
+    SelfDelegation,
+
+    #[msg("Delegate expiration date must be in the future!")]
+    BadDelegateExpirationDate,
+
+    #[msg("Delegate permission is expired or revoked!")]
+    DelegateIsNotValid,
+
+    #[msg("Basis points value is out of range!")]
+    BpsIsOutOfRange,
+
+    #[msg("Referrer share requires referrer!")]
+    NoReferrer,
+
+    #[msg("Promo code is expired!")]
+    PromoCodeIsExpired,
+
+    #[msg("Promo code usage limit is reached!")]
+    PromoCodeUsageLimitIsReached,
+
+    #[msg("Wrong referrer account!")]
+    WrongReferrerAccount,
+
+    #[msg("Promo code length is out of range!")]
+    InvalidPromoCodeLength,
+
+    #[msg("Account can't be suspended twice!")]
+    SuspendAccountTwice,
+
+    #[msg("Account is suspended!")]
+    AccountIsSuspended,
+
+    #[msg("Account isn't suspended!")]
+    AccountIsNotSuspended,
+
+    #[msg("Account is already migrated!")]
+    NothingToMigrate,
+
+    #[msg("Batch size is out of range!")]
+    BatchSizeIsOutOfRange,
+
+    #[msg("Wrong remaining accounts!")]
+    WrongRemainingAccounts,
 }
 
 impl From<CustomError> for ProgError {
@@ -0,0 +1,177 @@
+use {
+    crate::{state::Config, types::AssetItem},
+    anchor_lang::prelude::*,
+};
+
+#[event]
+pub struct AccountCreated {
+    pub id: u32,
+    pub owner: Pubkey,
+    pub max_data_size: u32,
+    pub sponsor: Option<Pubkey>,
+}
+
+#[event]
+pub struct AccountClosed {
+    pub id: u32,
+    pub owner: Pubkey,
+}
+
+#[event]
+pub struct AccountReopened {
+    pub id: u32,
+    pub owner: Pubkey,
+    pub max_data_size: u32,
+}
+
+#[event]
+pub struct AccountSuspended {
+    pub id: u32,
+    pub owner: Pubkey,
+}
+
+#[event]
+pub struct AccountUnsuspended {
+    pub id: u32,
+    pub owner: Pubkey,
+}
+
+/// rent is refunded to the owner or the sponsor
+#[event]
+pub struct AccountForceClosed {
+    pub id: u32,
+    pub owner: Pubkey,
+}
+
+#[event]
+pub struct AccountResized {
+    pub id: u32,
+    pub owner: Pubkey,
+    pub max_data_size: u32,
+}
+
+/// emitted both on activation and its renewal
+#[event]
+pub struct AccountActivated {
+    pub id: u32,
+    pub owner: Pubkey,
+    /// fee payer, it can differ from the owner
+    pub payer: Pubkey,
+    /// NATIVE_SOL_ASSET for payments in SOL
+    pub fee: AssetItem,
+    pub activated_until: u64,
+    pub is_renewal: bool,
+}
+
+/// emitted both on single transaction and chunked writes
+#[event]
+pub struct DataWritten {
+    pub id: u32,
+    /// the owner or its delegate
+    pub writer: Pubkey,
+    pub nonce: u64,
+    pub length: u32,
+}
+
+#[event]
+pub struct DelegateGranted {
+    pub id: u32,
+    pub owner: Pubkey,
+    pub delegate: Pubkey,
+    pub expiration_date: Option<u64>,
+}
+
+#[event]
+pub struct DelegateRevoked {
+    pub id: u32,
+    pub owner: Pubkey,
+    pub delegate: Pubkey,
+}
+
+#[event]
+pub struct AccountRotationRequested {
+    pub id: u32,
+    pub owner: Pubkey,
+    pub new_owner: Pubkey,
+    pub expiration_date: u64,
+    pub clear_data: bool,
+}
+
+#[event]
+pub struct AccountRotationConfirmed {
+    pub id: u32,
+    pub prev_owner: Pubkey,
+    pub new_owner: Pubkey,
+    pub clear_data: bool,
+}
+
+#[event]
+pub struct AccountRotationCancelled {
+    pub id: u32,
+    pub owner: Pubkey,
+    pub new_owner: Pubkey,
+}
+
+#[event]
+pub struct AdminRotationRequested {
+    pub admin: Pubkey,
+    pub new_admin: Pubkey,
+    pub expiration_date: u64,
+}
+
+#[event]
+pub struct AdminRotationConfirmed {
+    pub prev_admin: Pubkey,
+    pub new_admin: Pubkey,
+}
+
+#[event]
+pub struct AdminRotationCancelled {
+    pub admin: Pubkey,
+    pub new_admin: Pubkey,
+}
+
+/// contains the whole config after the update
+#[event]
+pub struct ConfigUpdated {
+    pub config: Config,
+    pub fee_whitelist: Vec<AssetItem>,
+}
+
+#[event]
+pub struct PromoCodeCreated {
+    pub code: String,
+    pub discount_bps: u16,
+    pub referrer: Option<Pubkey>,
+    pub referrer_share_bps: u16,
+    pub usage_limit: Option<u32>,
+    pub expiration_date: Option<u64>,
+}
+
+#[event]
+pub struct PromoCodeClosed {
+    pub code: String,
+    pub usage_count: u32,
+}
+
+/// emitted along with AccountActivated, its fee contains app amount only
+#[event]
+pub struct PromoCodeUsed {
+    pub code: String,
+    pub id: u32,
+    pub referrer: Option<Pubkey>,
+    pub referrer_amount: u64,
+}
+
+#[event]
+pub struct RevenueWithdrawn {
+    pub asset: Pubkey,
+    pub amount: u64,
+    pub recipient: Pubkey,
+}
+
+#[event]
+pub struct AccountMigrated {
+    pub address: Pubkey,
+    pub version: u8,
+}
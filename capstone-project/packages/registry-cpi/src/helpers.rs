@@ -0,0 +1,19 @@
+use {
+    crate::state::{UserIndexPage, SEED_USER_INDEX_PAGE},
+    anchor_lang::prelude::*,
+};
+
+/// derives UserIndexPage address, use UserIndexPage::get_page to find a page by user_id \
+/// all users can be enumerated iterating pages from 0 to UserIndexPage::get_page(last_user_id)
+pub fn get_user_index_page_address(page: u32) -> Pubkey {
+    Pubkey::find_program_address(
+        &[SEED_USER_INDEX_PAGE.as_bytes(), page.to_le_bytes().as_ref()],
+        &crate::ID,
+    )
+    .0
+}
+
+/// decodes UserIndexPage from raw account data, discriminator is checked
+pub fn decode_user_index_page(data: &[u8]) -> Result<UserIndexPage> {
+    UserIndexPage::try_deserialize(&mut &data[..])
+}
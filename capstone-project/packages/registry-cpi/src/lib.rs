@@ -1,4 +1,8 @@
+#[cfg(feature = "client")]
+pub mod client;
 pub mod error;
+pub mod events;
+pub mod helpers;
 pub mod state;
 pub mod types;
 
@@ -1,6 +1,14 @@
 use {
-    crate::types::{AssetItem, Range},
+    crate::{
+        error::CustomError,
+        types::{AssetItem, DataHeader, Range},
+    },
     anchor_lang::prelude::*,
+    base::{
+        helpers::DISCRIMINATOR_SPACE,
+        migration::Versioned,
+        pause::{PAUSE_ALL, PAUSE_NONE},
+    },
 };
 
 pub const SECONDS_PER_DAY: u32 = 24 * 3_600;
@@ -11,21 +19,36 @@ pub const MAINNET_ADMIN: Pubkey =
     Pubkey::from_str_const("AH9JvTDAiQy2zAuFfzteNyUrW5DYoTsTLoeNjXrxTTSt");
 
 pub const ROTATION_TIMEOUT: u32 = SECONDS_PER_DAY;
+pub const ACTIVATION_PERIOD: u32 = SECONDS_PER_YEAR;
 pub const ACCOUNT_REGISTRATION_FEE_AMOUNT: u64 = 10_000_000; // 10 $
 pub const ACCOUNT_REGISTRATION_FEE_ASSET: Pubkey =
     Pubkey::from_str_const("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v"); // mainnet USDC
 pub const ACCOUNT_DATA_SIZE_MIN: u32 = 100;
 /// "Account data size realloc limited to 10240 in inner instructions"
 pub const ACCOUNT_DATA_SIZE_MAX: u32 = 10_000;
+pub const FEE_WHITELIST_MAX_LEN: usize = 8;
+/// native SOL doesn't have a mint, system program address is used as its asset
+pub const NATIVE_SOL_ASSET: Pubkey = Pubkey::from_str_const("11111111111111111111111111111111");
+pub const USER_INDEX_PAGE_SIZE: usize = 64;
+/// PDA seed length limit
+pub const PROMO_CODE_MAX_LEN: usize = 32;
+pub const BPS_MAX: u16 = 10_000;
+/// max number of users processed by a single batch instruction
+pub const BATCH_SIZE_MAX: usize = 16;
 
 pub const SEED_BUMP: &str = "bump";
 pub const SEED_CONFIG: &str = "config";
 pub const SEED_USER_COUNTER: &str = "user_counter";
 pub const SEED_ADMIN_ROTATION_STATE: &str = "admin_rotation_state";
+pub const SEED_FEE_WHITELIST: &str = "fee_whitelist";
+pub const SEED_USER_INDEX_PAGE: &str = "user_index_page";
+pub const SEED_PROMO_CODE: &str = "promo_code";
 
 pub const SEED_USER_ID: &str = "user_id";
 pub const SEED_USER_ACCOUNT: &str = "user_account";
 pub const SEED_USER_ROTATION_STATE: &str = "user_rotation_state";
+pub const SEED_USER_WRITE_BUFFER: &str = "user_write_buffer";
+pub const SEED_USER_DELEGATE: &str = "user_delegate";
 
 /// to store bumps for all app accounts
 #[account]
@@ -34,6 +57,19 @@ pub struct Bump {
     pub config: u8,
     pub user_counter: u8,
     pub rotation_state: u8,
+    pub version: u8,
+}
+
+impl Versioned for Bump {
+    const VERSION: u8 = 1;
+
+    fn get_version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
 }
 
 #[account]
@@ -41,10 +77,103 @@ pub struct Bump {
 pub struct Config {
     /// can update the config and execute priveled instructions
     pub admin: Pubkey,
-    pub is_paused: bool,
+    /// bit mask of paused operations, see base::pause
+    pub paused_ops: u8,
     pub rotation_timeout: u32,
     pub registration_fee: AssetItem,
     pub data_size_range: Range,
+    /// activation is extended by this period for each fee payment
+    pub activation_period: u32,
+    pub version: u8,
+}
+
+impl Versioned for Config {
+    const VERSION: u8 = 1;
+
+    fn get_version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+
+    fn upgrade_from(&mut self, version: u8) {
+        if version < 1 {
+            // zero period would make any activation expired immediately
+            self.activation_period = ACTIVATION_PERIOD;
+
+            // legacy is_paused flag blocked all operations
+            if self.paused_ops != PAUSE_NONE {
+                self.paused_ops = PAUSE_ALL;
+            }
+        }
+    }
+}
+
+/// assets accepted for account activation in addition to Config.registration_fee
+#[account]
+#[derive(InitSpace, Default, PartialEq, Debug)]
+pub struct FeeWhitelist {
+    #[max_len(FEE_WHITELIST_MAX_LEN)]
+    pub value: Vec<AssetItem>,
+}
+
+impl FeeWhitelist {
+    /// returns fee amount if the asset is accepted
+    pub fn get_fee_amount(&self, registration_fee: &AssetItem, asset: &Pubkey) -> Option<u64> {
+        std::iter::once(registration_fee)
+            .chain(self.value.iter())
+            .find(|x| &x.asset == asset)
+            .map(|x| x.amount)
+    }
+}
+
+/// get by code: String \
+/// discount on activation fee created by admin, a part of discounted fee can be paid to referrer
+#[account]
+#[derive(InitSpace, PartialEq, Debug)]
+pub struct PromoCode {
+    #[max_len(PROMO_CODE_MAX_LEN)]
+    pub code: String,
+    pub discount_bps: u16,
+    pub referrer: Option<Pubkey>,
+    /// share of discounted fee paid to referrer
+    pub referrer_share_bps: u16,
+    pub usage_limit: Option<u32>,
+    pub usage_count: u32,
+    pub expiration_date: Option<u64>,
+}
+
+impl PromoCode {
+    /// code is validated before address derivation as its length is limited by PDA seed length
+    pub fn get_seed(code: &str) -> Result<&[u8]> {
+        if code.is_empty() || code.len() > PROMO_CODE_MAX_LEN {
+            Err(CustomError::InvalidPromoCodeLength)?;
+        }
+
+        Ok(code.as_bytes())
+    }
+
+    /// registers the usage and splits discounted fee into (app_amount, referrer_amount)
+    pub fn apply(&mut self, fee_amount: u64, clock_time: u64) -> Result<(u64, u64)> {
+        if self.expiration_date.is_some_and(|x| clock_time >= x) {
+            Err(CustomError::PromoCodeIsExpired)?;
+        }
+
+        if self.usage_limit.is_some_and(|x| self.usage_count >= x) {
+            Err(CustomError::PromoCodeUsageLimitIsReached)?;
+        }
+
+        self.usage_count += 1;
+
+        let discount = fee_amount as u128 * self.discount_bps as u128 / BPS_MAX as u128;
+        let discounted_fee = fee_amount - discount as u64;
+        let referrer_amount =
+            (discounted_fee as u128 * self.referrer_share_bps as u128 / BPS_MAX as u128) as u64;
+
+        Ok((discounted_fee - referrer_amount, referrer_amount))
+    }
 }
 
 /// for indexing
@@ -54,6 +183,56 @@ pub struct UserCounter {
     pub last_user_id: u32,
 }
 
+/// get by page: u32 \
+/// reverse lookup (user_id -> owner), user_id = page * USER_INDEX_PAGE_SIZE + position + 1
+#[account]
+#[derive(InitSpace, Default, PartialEq, Debug)]
+pub struct UserIndexPage {
+    #[max_len(USER_INDEX_PAGE_SIZE)]
+    pub owners: Vec<Pubkey>,
+}
+
+impl UserIndexPage {
+    pub fn get_page(user_id: u32) -> u32 {
+        user_id.saturating_sub(1) / USER_INDEX_PAGE_SIZE as u32
+    }
+
+    pub fn get_position(user_id: u32) -> usize {
+        user_id.saturating_sub(1) as usize % USER_INDEX_PAGE_SIZE
+    }
+
+    /// returns None for unknown id or for id registered before the index was introduced
+    pub fn get_owner(&self, user_id: u32) -> Option<Pubkey> {
+        self.owners
+            .get(Self::get_position(user_id))
+            .filter(|x| x != &&Pubkey::default())
+            .copied()
+    }
+
+    /// ids registered before the index was introduced are filled with default address
+    pub fn set_owner(&mut self, user_id: u32, owner: Pubkey) {
+        let position = Self::get_position(user_id);
+
+        if self.owners.len() <= position {
+            self.owners.resize(position + 1, Pubkey::default());
+        }
+
+        self.owners[position] = owner;
+    }
+
+    /// returns (user_id, owner) pairs stored on the page
+    pub fn get_users(&self, page: u32) -> Vec<(u32, Pubkey)> {
+        let first_user_id = page * USER_INDEX_PAGE_SIZE as u32 + 1;
+
+        self.owners
+            .iter()
+            .enumerate()
+            .filter(|(_, owner)| owner != &&Pubkey::default())
+            .map(|(position, owner)| (first_user_id + position as u32, *owner))
+            .collect()
+    }
+}
+
 /// to transfer ownership from one address to another in 2 steps (for security reasons) \
 /// used both for app admin and user accounts
 #[account]
@@ -62,6 +241,21 @@ pub struct RotationState {
     pub owner: Pubkey,
     pub new_owner: Option<Pubkey>,
     pub expiration_date: u64,
+    /// user data is cleared on confirmation to not expose it to the new owner
+    pub clear_data: bool,
+    pub version: u8,
+}
+
+impl Versioned for RotationState {
+    const VERSION: u8 = 1;
+
+    fn get_version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
 }
 
 /// get by user: Pubkey
@@ -73,6 +267,73 @@ pub struct UserId {
     pub is_activated: bool,
     pub account_bump: u8,
     pub rotation_state_bump: u8,
+    /// activation expiration date
+    pub activated_until: u64,
+    /// blocks data writes and rotations, set by admin
+    pub is_suspended: bool,
+    pub version: u8,
+    /// paid rent on account creation instead of the owner
+    pub sponsor: Option<Pubkey>,
+    /// rent is refunded to the sponsor on account closing
+    pub is_rent_reclaimable: bool,
+}
+
+impl Versioned for UserId {
+    const VERSION: u8 = 2;
+
+    fn get_version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+
+    fn upgrade_from(&mut self, version: u8) {
+        // accounts activated before expiration was introduced were activated permanently
+        if version < 1 && self.is_activated {
+            self.activated_until = u64::MAX;
+        }
+    }
+}
+
+impl UserId {
+    pub fn is_activation_expired(&self, clock_time: u64) -> bool {
+        clock_time >= self.activated_until
+    }
+
+    /// returns address receiving rent on account closing
+    pub fn get_rent_recipient(&self, owner: Pubkey) -> Pubkey {
+        match self.sponsor {
+            Some(sponsor) if self.is_rent_reclaimable => sponsor,
+            _ => owner,
+        }
+    }
+
+    /// extends activation from expiration date or from now if it's already expired
+    pub fn extend_activation(&mut self, clock_time: u64, activation_period: u32) {
+        self.activated_until = self
+            .activated_until
+            .max(clock_time)
+            .saturating_add(activation_period as u64);
+    }
+}
+
+/// get by user_id: u32, delegate: Pubkey \
+/// allows the delegate to write data on behalf of the owner
+#[account]
+#[derive(InitSpace, PartialEq, Debug)]
+pub struct UserDelegate {
+    /// account owner granted the permission, it's reset by account rotation
+    pub owner: Pubkey,
+    /// permission is valid until this date if it's specified
+    pub expiration_date: Option<u64>,
+}
+
+impl UserDelegate {
+    pub fn is_valid(&self, owner: &Pubkey, clock_time: u64) -> bool {
+        &self.owner == owner && self.expiration_date.is_none_or(|x| clock_time < x)
+    }
 }
 
 /// get by user_id: u32
@@ -80,18 +341,64 @@ pub struct UserId {
 #[derive(PartialEq, Debug)]
 pub struct UserAccount {
     /// encrypted user data
-    pub data: String,
+    pub data: Vec<u8>,
     /// encryption nonce
     pub nonce: u64,
     /// allocated storage capacity
     pub max_size: u32,
+    /// format of the data
+    pub header: DataHeader,
 }
 
 impl UserAccount {
     pub fn get_space(max_size: u32) -> usize {
         8 +   // discriminator
-        4 + max_size as usize + // data (String: 4 bytes length + content)
+        4 + max_size as usize + // data (Vec<u8>: 4 bytes length + content)
         8 +   // nonce (u64)
-        4 // max_size (u32)
+        4 +   // max_size (u32)
+        DataHeader::INIT_SPACE // header
+    }
+
+    /// reads max_size from raw account data, header isn't required to be present \
+    /// String data of legacy accounts has the same layout as Vec<u8>
+    pub fn read_max_size(account_data: &[u8]) -> Option<u32> {
+        let data_len_offset = DISCRIMINATOR_SPACE;
+        let data_len = u32::from_le_bytes(
+            account_data
+                .get(data_len_offset..data_len_offset + 4)?
+                .try_into()
+                .ok()?,
+        );
+        let max_size_offset = data_len_offset + 4 + data_len as usize + 8;
+
+        Some(u32::from_le_bytes(
+            account_data
+                .get(max_size_offset..max_size_offset + 4)?
+                .try_into()
+                .ok()?,
+        ))
+    }
+}
+
+/// get by user_id: u32 \
+/// stages data exceeding a single transaction until it's committed to UserAccount
+#[account]
+#[derive(PartialEq, Debug)]
+pub struct WriteBuffer {
+    /// encrypted user data received so far
+    pub data: Vec<u8>,
+    /// expected data length
+    pub size: u32,
+}
+
+impl WriteBuffer {
+    pub fn get_space(size: u32) -> usize {
+        8 +   // discriminator
+        4 + size as usize + // data (Vec<u8>: 4 bytes length + content)
+        4 // size (u32)
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.data.len() == self.size as usize
     }
 }
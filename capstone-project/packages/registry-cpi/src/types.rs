@@ -1,4 +1,10 @@
-use anchor_lang::prelude::*;
+use {
+    crate::error::CustomError,
+    anchor_lang::{prelude::*, solana_program::hash::hash},
+};
+
+/// the only header layout supported by write instructions, 0 is reserved for untagged data
+pub const DATA_HEADER_VERSION: u8 = 1;
 
 #[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Debug)]
 pub struct AssetItem {
@@ -11,3 +17,58 @@ pub struct Range {
     pub min: u32,
     pub max: u32,
 }
+
+/// config fields to update, unspecified ones are kept, at least one must be specified
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, PartialEq, Debug)]
+pub struct UpdateConfigArgs {
+    /// new admin must confirm the rotation
+    pub admin: Option<Pubkey>,
+    pub paused_ops: Option<u8>,
+    pub rotation_timeout: Option<u32>,
+    pub registration_fee_amount: Option<u64>,
+    pub data_size_range: Option<Range>,
+    pub fee_whitelist: Option<Vec<AssetItem>>,
+    pub activation_period: Option<u32>,
+}
+
+/// describes UserAccount.data so client apps sharing the registry can decode it \
+/// schema and encryption scheme ids are defined by clients, the program only stores them
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, Default, PartialEq, Debug)]
+pub struct DataHeader {
+    pub version: u8,
+    pub schema_id: u16,
+    pub encryption_scheme_id: u16,
+    /// data length in bytes
+    pub content_length: u32,
+    /// sha256 of data
+    pub checksum: [u8; 32],
+}
+
+impl DataHeader {
+    pub fn new(schema_id: u16, encryption_scheme_id: u16, data: &[u8]) -> Self {
+        Self {
+            version: DATA_HEADER_VERSION,
+            schema_id,
+            encryption_scheme_id,
+            content_length: data.len() as u32,
+            checksum: hash(data).to_bytes(),
+        }
+    }
+
+    /// checks if the header matches the data
+    pub fn validate(&self, data: &[u8]) -> Result<()> {
+        if self.version != DATA_HEADER_VERSION {
+            Err(CustomError::UnsupportedHeaderVersion)?;
+        }
+
+        if self.content_length as usize != data.len() {
+            Err(CustomError::ContentLengthMismatch)?;
+        }
+
+        if self.checksum != hash(data).to_bytes() {
+            Err(CustomError::ChecksumMismatch)?;
+        }
+
+        Ok(())
+    }
+}
@@ -3,6 +3,7 @@ pub mod create_operation_account;
 pub mod create_pool;
 pub mod open_position_with_token22_nft;
 pub mod swap_router_base_in;
+pub mod swap_router_base_out;
 pub mod swap_v2;
 
 pub use create_amm_config::*;
@@ -10,4 +11,5 @@ pub use create_operation_account::*;
 pub use create_pool::*;
 pub use open_position_with_token22_nft::*;
 pub use swap_router_base_in::*;
+pub use swap_router_base_out::*;
 pub use swap_v2::*;
@@ -0,0 +1,113 @@
+use {
+    crate::{
+        error::ErrorCode,
+        instructions::swap_v2::{calculate_amount_in, exact_internal_v2, SwapSingleV2},
+        state::*,
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::{
+        memo::Memo,
+        token::Token,
+        token_interface::{Mint, Token2022, TokenAccount},
+    },
+    raydium_clmm_cpi::states::AmmConfig,
+};
+
+#[derive(Accounts)]
+pub struct SwapRouterBaseOut<'info> {
+    /// The user performing the swap
+    pub payer: Signer<'info>,
+
+    /// The token account that pays input tokens for the swap
+    #[account(mut)]
+    pub input_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// The mint of input token
+    #[account(mut)]
+    pub input_token_mint: InterfaceAccount<'info, Mint>,
+
+    /// SPL program for token transfers
+    pub token_program: Program<'info, Token>,
+    /// SPL program 2022 for token transfers
+    pub token_program_2022: Program<'info, Token2022>,
+
+    /// Memo program
+    pub memo_program: Program<'info, Memo>,
+}
+
+/// hop accounts are the same as in swap_router_base_in \
+/// required input amounts are calculated walking the route in reverse, then hops are executed in direct order
+pub fn swap_router_base_out<'a, 'b, 'c: 'info, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, SwapRouterBaseOut<'info>>,
+    amount_out: u64,
+    amount_in_maximum: u64,
+) -> Result<()> {
+    require!(
+        !ctx.remaining_accounts.is_empty() && ctx.remaining_accounts.len() % 7 == 0,
+        ErrorCode::AccountLack
+    );
+
+    let mut input_token_account = Box::new(ctx.accounts.input_token_account.clone());
+    let mut input_token_mint = Box::new(ctx.accounts.input_token_mint.clone());
+    let mut hops: Vec<SwapSingleV2> = vec![];
+
+    for chunk in ctx.remaining_accounts.chunks_exact(7) {
+        let amm_config = Box::new(Account::<AmmConfig>::try_from(&chunk[0])?);
+        let pool_state_loader = AccountLoader::<PoolState>::try_from(&chunk[1])?;
+        let output_token_account = Box::new(InterfaceAccount::<TokenAccount>::try_from(&chunk[2])?);
+        let input_vault = Box::new(InterfaceAccount::<TokenAccount>::try_from(&chunk[3])?);
+        let output_vault = Box::new(InterfaceAccount::<TokenAccount>::try_from(&chunk[4])?);
+        let output_token_mint = Box::new(InterfaceAccount::<Mint>::try_from(&chunk[5])?);
+        let observation_state = AccountLoader::<ObservationState>::try_from(&chunk[6])?;
+
+        {
+            let pool_state = pool_state_loader.load()?;
+            // check observation account is owned by the pool
+            require_keys_eq!(pool_state.observation_key, observation_state.key());
+            // check ammConfig account is associate with the pool
+            require_keys_eq!(pool_state.amm_config, amm_config.key());
+        }
+
+        hops.push(SwapSingleV2 {
+            payer: ctx.accounts.payer.clone(),
+            amm_config,
+            input_token_account,
+            pool_state: pool_state_loader,
+            output_token_account: output_token_account.clone(),
+            input_vault,
+            output_vault,
+            input_vault_mint: input_token_mint,
+            output_vault_mint: output_token_mint.clone(),
+            observation_state,
+            token_program: ctx.accounts.token_program.clone(),
+            token_program_2022: ctx.accounts.token_program_2022.clone(),
+            memo_program: ctx.accounts.memo_program.clone(),
+        });
+
+        // output token is the next hop input token
+        input_token_account = output_token_account;
+        input_token_mint = output_token_mint;
+    }
+
+    // output of each hop is input of the next one
+    let mut hop_amounts_out = vec![0; hops.len()];
+    let mut amount_in = amount_out;
+
+    for (i, hop) in hops.iter().enumerate().rev() {
+        hop_amounts_out[i] = amount_in;
+        amount_in = calculate_amount_in(
+            amount_in,
+            hop.input_vault.amount,
+            hop.output_vault.amount,
+            hop.amm_config.trade_fee_rate,
+        )?;
+    }
+
+    require_gte!(amount_in_maximum, amount_in, ErrorCode::TooMuchInputPaid);
+
+    for (hop, hop_amount_out) in hops.iter_mut().zip(hop_amounts_out) {
+        exact_internal_v2(hop, ctx.remaining_accounts, hop_amount_out, 0, false)?;
+    }
+
+    Ok(())
+}
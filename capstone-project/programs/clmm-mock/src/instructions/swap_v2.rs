@@ -10,12 +10,9 @@ use {
         token::Token,
         token_interface::{Mint, Token2022, TokenAccount},
     },
-    raydium_clmm_cpi::states::AmmConfig,
+    raydium_clmm_cpi::states::{AmmConfig, FEE_RATE_DENOMINATOR_VALUE},
 };
 
-pub const FEE_BPS: u64 = 20;
-pub const BPS_DIVIDER: u64 = 10_000;
-
 #[derive(Accounts)]
 pub struct SwapSingleV2<'info> {
     /// The user performing the swap
@@ -114,6 +111,7 @@ pub fn exact_internal_v2<'c: 'info, 'info>(
 ) -> Result<u64> {
     let SwapSingleV2 {
         payer,
+        amm_config,
         pool_state,
         input_token_account,
         output_token_account,
@@ -134,14 +132,16 @@ pub fn exact_internal_v2<'c: 'info, 'info>(
     let (amount_in, amount_out) = if is_base_input {
         // Exact input swap - calculate output using constant product formula
         let amount_in = amount_specified;
-        let amount_out = calculate_amount_out(amount_in, reserve_0, reserve_1)?;
+        let amount_out =
+            calculate_amount_out(amount_in, reserve_0, reserve_1, amm_config.trade_fee_rate)?;
         amount_to_return = amount_out;
 
         (amount_in, amount_out)
     } else {
         // Exact output swap - calculate input using constant product formula
         let amount_out = amount_specified;
-        let amount_in = calculate_amount_in(amount_out, reserve_0, reserve_1)?;
+        let amount_in =
+            calculate_amount_in(amount_out, reserve_0, reserve_1, amm_config.trade_fee_rate)?;
         amount_to_return = amount_in;
 
         (amount_in, amount_out)
@@ -173,7 +173,13 @@ pub fn exact_internal_v2<'c: 'info, 'info>(
 }
 
 // Helper function to calculate output amount using constant product formula
-fn calculate_amount_out(amount_in: u64, reserve_in: u64, reserve_out: u64) -> Result<u64> {
+// trade_fee_rate is denominated in hundredths of a bip (10^-6)
+fn calculate_amount_out(
+    amount_in: u64,
+    reserve_in: u64,
+    reserve_out: u64,
+    trade_fee_rate: u32,
+) -> Result<u64> {
     require!(amount_in > 0, ErrorCode::TooSmallInputOrOutputAmount);
     require!(
         reserve_in > 0 && reserve_out > 0,
@@ -181,15 +187,22 @@ fn calculate_amount_out(amount_in: u64, reserve_in: u64, reserve_out: u64) -> Re
     );
 
     // Apply fee
-    let amount_in_with_fee = (amount_in as u128) * (BPS_DIVIDER - FEE_BPS) as u128;
+    let amount_in_with_fee =
+        (amount_in as u128) * (FEE_RATE_DENOMINATOR_VALUE - trade_fee_rate) as u128;
     let numerator = amount_in_with_fee * (reserve_out as u128);
-    let denominator = (reserve_in as u128) * BPS_DIVIDER as u128 + amount_in_with_fee;
+    let denominator =
+        (reserve_in as u128) * FEE_RATE_DENOMINATOR_VALUE as u128 + amount_in_with_fee;
 
     Ok((numerator / denominator) as u64)
 }
 
 // Helper function to calculate input amount using constant product formula
-fn calculate_amount_in(amount_out: u64, reserve_in: u64, reserve_out: u64) -> Result<u64> {
+pub fn calculate_amount_in(
+    amount_out: u64,
+    reserve_in: u64,
+    reserve_out: u64,
+    trade_fee_rate: u32,
+) -> Result<u64> {
     require!(amount_out > 0, ErrorCode::TooSmallInputOrOutputAmount);
     require!(
         reserve_in > 0 && reserve_out > 0,
@@ -200,8 +213,10 @@ fn calculate_amount_in(amount_out: u64, reserve_in: u64, reserve_out: u64) -> Re
         ErrorCode::InsufficientLiquidityForDirection
     );
 
-    let numerator = (reserve_in as u128) * (amount_out as u128) * BPS_DIVIDER as u128;
-    let denominator = (reserve_out as u128 - amount_out as u128) * (BPS_DIVIDER - FEE_BPS) as u128;
+    let numerator =
+        (reserve_in as u128) * (amount_out as u128) * FEE_RATE_DENOMINATOR_VALUE as u128;
+    let denominator = (reserve_out as u128 - amount_out as u128)
+        * (FEE_RATE_DENOMINATOR_VALUE - trade_fee_rate) as u128;
 
     Ok((numerator / denominator + 1) as u64) // Add 1 to round up
 }
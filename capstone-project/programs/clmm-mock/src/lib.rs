@@ -10,7 +10,8 @@ use {
     anchor_lang::prelude::*,
     instructions::{
         create_amm_config::*, create_operation_account::*, create_pool::*,
-        open_position_with_token22_nft::*, swap_router_base_in::*, swap_v2::*,
+        open_position_with_token22_nft::*, swap_router_base_in::*, swap_router_base_out::*,
+        swap_v2::*,
     },
     raydium_clmm_cpi::states::FEE_RATE_DENOMINATOR_VALUE,
 };
@@ -108,4 +109,13 @@ pub mod clmm_mock {
     ) -> Result<()> {
         instructions::swap_router_base_in(ctx, amount_in, amount_out_minimum)
     }
+
+    /// multihop swap receiving exact output amount
+    pub fn swap_router_base_out<'a, 'b, 'c: 'info, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, SwapRouterBaseOut<'info>>,
+        amount_out: u64,
+        amount_in_maximum: u64,
+    ) -> Result<()> {
+        instructions::swap_router_base_out(ctx, amount_out, amount_in_maximum)
+    }
 }
@@ -0,0 +1,158 @@
+use {
+    super::Hop,
+    amm_cpi::{
+        math::{calc_amount_out, calc_fee},
+        state::{PoolBalance, PoolConfig, SEED_BALANCE, SEED_CONFIG},
+    },
+    anchor_lang::{prelude::*, solana_program},
+    anchor_spl::{associated_token::AssociatedToken, token_interface::TokenInterface},
+    base::helpers::get_discriminator,
+    dex_adapter_cpi::{
+        error::CustomError,
+        state::BPS_MAX,
+        types::{AmmSwapData, RouteItem},
+    },
+};
+
+/// hop accounts are amm program and the pool accounts of amm swap: \
+/// amm_program, pool_config, pool_balance, mint_x, mint_y, \
+/// trader_mint_x_ata, trader_mint_y_ata, liquidity_pool_mint_x_ata, liquidity_pool_mint_y_ata
+pub fn validate_hop(
+    amm_program_id: &Pubkey,
+    route_item: &RouteItem,
+    accounts: &[AccountInfo],
+) -> Result<()> {
+    let pool_id = route_item.pool_id.to_le_bytes();
+    let (pool_config, _) =
+        Pubkey::find_program_address(&[SEED_CONFIG.as_bytes(), &pool_id], amm_program_id);
+    let (pool_balance, _) =
+        Pubkey::find_program_address(&[SEED_BALANCE.as_bytes(), &pool_id], amm_program_id);
+
+    if accounts[0].key() != *amm_program_id
+        || accounts[1].key() != pool_config
+        || accounts[2].key() != pool_balance
+    {
+        Err(CustomError::RouteMismatch)?;
+    }
+
+    // mints must be passed in the pool order, the hop output must be one of them
+    let pool_config = PoolConfig::try_deserialize(&mut &accounts[1].try_borrow_data()?[..])?;
+    if accounts[3].key() != pool_config.mint_x
+        || accounts[4].key() != pool_config.mint_y
+        || (route_item.token_out != pool_config.mint_x
+            && route_item.token_out != pool_config.mint_y)
+    {
+        Err(CustomError::RouteMismatch)?;
+    }
+
+    Ok(())
+}
+
+/// returns output mint and output token account
+pub fn get_output_accounts<'a>(hop: &Hop<'a>) -> (AccountInfo<'a>, AccountInfo<'a>) {
+    if hop.accounts[3].key() == hop.item.token_out {
+        (hop.accounts[3].clone(), hop.accounts[5].clone())
+    } else {
+        (hop.accounts[4].clone(), hop.accounts[6].clone())
+    }
+}
+
+/// amm swap has no slippage protection, it's checked by the caller
+pub fn execute_swap<'a>(
+    amount_in: u64,
+    mint_in: &Pubkey,
+    system_program: &Program<'a, System>,
+    token_program: &Interface<'a, TokenInterface>,
+    associated_token_program: &Program<'a, AssociatedToken>,
+    sender: &Signer<'a>,
+    hop: &Hop<'a>,
+) -> Result<()> {
+    let chunk = hop.accounts;
+
+    let account_infos = [
+        &[
+            system_program.to_account_info(),
+            token_program.to_account_info(),
+            associated_token_program.to_account_info(),
+            sender.to_account_info(),
+        ],
+        chunk,
+    ]
+    .concat();
+
+    // build accounts for CPI call to amm
+    let accounts = vec![
+        AccountMeta::new_readonly(system_program.key(), false), // system_program
+        AccountMeta::new_readonly(token_program.key(), false),  // token_program
+        AccountMeta::new_readonly(associated_token_program.key(), false), // associated_token_program
+        AccountMeta::new(sender.key(), true),                             // trader (signer)
+        AccountMeta::new_readonly(chunk[1].key(), false),                 // pool_config (readonly)
+        AccountMeta::new(chunk[2].key(), false),                          // pool_balance (writable)
+        AccountMeta::new_readonly(chunk[3].key(), false),                 // mint_x (readonly)
+        AccountMeta::new_readonly(chunk[4].key(), false),                 // mint_y (readonly)
+        AccountMeta::new(chunk[5].key(), false), // trader_mint_x_ata (writable)
+        AccountMeta::new(chunk[6].key(), false), // trader_mint_y_ata (writable)
+        AccountMeta::new(chunk[7].key(), false), // liquidity_pool_mint_x_ata (writable)
+        AccountMeta::new(chunk[8].key(), false), // liquidity_pool_mint_y_ata (writable)
+    ];
+
+    let data = AmmSwapData {
+        discriminator: get_discriminator("swap"),
+        id: hop.item.pool_id,
+        amount_in,
+        mint_in: *mint_in,
+    }
+    .try_to_vec()?;
+
+    let instruction = solana_program::instruction::Instruction {
+        program_id: chunk[0].key(),
+        accounts,
+        data,
+    };
+
+    // execute CPI call with user as signer
+    Ok(solana_program::program::invoke(
+        &instruction,
+        &account_infos,
+    )?)
+}
+
+/// constant product math of amm, the fee is taken from output amount
+pub fn quote_hop(hop: &Hop, amount_in: u64, token_in: &Pubkey) -> Result<(u64, u16)> {
+    let pool_config = Account::<PoolConfig>::try_from(&hop.accounts[1])?;
+    let pool_balance = Account::<PoolBalance>::try_from(&hop.accounts[2])?;
+
+    let is_mint_in_x = *token_in == pool_config.mint_x;
+    let (reserve_in, reserve_out) = if is_mint_in_x {
+        (pool_balance.mint_x_amount, pool_balance.mint_y_amount)
+    } else {
+        (pool_balance.mint_y_amount, pool_balance.mint_x_amount)
+    };
+
+    if !is_mint_in_x && *token_in != pool_config.mint_y {
+        Err(CustomError::RouteMismatch)?;
+    }
+
+    if reserve_in == 0 || reserve_out == 0 {
+        Err(CustomError::InsufficientLiquidity)?;
+    }
+
+    let amount_out = calc_amount_out(
+        amount_in,
+        is_mint_in_x,
+        pool_balance.mint_x_amount,
+        pool_balance.mint_y_amount,
+    );
+    let fee = calc_fee(amount_out, &pool_config);
+
+    // amm rejects swaps with zero fee
+    if fee == 0 {
+        Err(CustomError::InsufficientLiquidity)?;
+    }
+
+    // spot price output is amount_in * reserve_out / reserve_in
+    let price_impact_bps =
+        amount_in as u128 * BPS_MAX as u128 / (reserve_in as u128 + amount_in as u128);
+
+    Ok((amount_out - fee, price_impact_bps as u16))
+}
@@ -0,0 +1,176 @@
+use {
+    super::Hop,
+    anchor_lang::{prelude::*, solana_program},
+    anchor_spl::token_interface::{TokenAccount, TokenInterface},
+    base::helpers::get_discriminator,
+    dex_adapter_cpi::{
+        error::CustomError,
+        state::BPS_MAX,
+        types::{RouteItem, SwapRouterBaseInData, SwapRouterBaseOutData},
+    },
+    raydium_clmm_cpi::states::{AmmConfig, PoolState, AMM_CONFIG_SEED, FEE_RATE_DENOMINATOR_VALUE},
+};
+
+/// hop accounts are the same as for clmm_mock swap_router_base_in: \
+/// amm_config, pool_state, output_token_account, input_vault, output_vault, output_mint, observation_state
+pub fn validate_hop(
+    dex_program_id: &Pubkey,
+    route_item: &RouteItem,
+    accounts: &[AccountInfo],
+) -> Result<()> {
+    let (amm_config, _) = Pubkey::find_program_address(
+        &[
+            AMM_CONFIG_SEED.as_bytes(),
+            &route_item.amm_index.to_be_bytes(),
+        ],
+        dex_program_id,
+    );
+
+    if accounts[0].key() != amm_config || accounts[5].key() != route_item.token_out {
+        Err(CustomError::RouteMismatch)?;
+    }
+
+    Ok(())
+}
+
+/// returns output mint and output token account
+pub fn get_output_accounts<'a>(hop: &Hop<'a>) -> (AccountInfo<'a>, AccountInfo<'a>) {
+    (hop.accounts[5].clone(), hop.accounts[2].clone())
+}
+
+/// amount is input amount if is_base_input is true, otherwise it's output amount \
+/// other_amount_threshold is min output amount or max input amount respectively
+#[allow(clippy::too_many_arguments)]
+pub fn execute_swap<'a>(
+    amount: u64,
+    other_amount_threshold: u64,
+    is_base_input: bool,
+    dex_program_id: &Pubkey,
+    token_program: &Interface<'a, TokenInterface>,
+    token_program_2022: &UncheckedAccount<'a>,
+    memo_program: &UncheckedAccount<'a>,
+    sender: &Signer<'a>,
+    input_token_mint: &AccountInfo<'a>,
+    input_token_sender_ata: &AccountInfo<'a>,
+    hops: &[Hop<'a>],
+) -> Result<()> {
+    let mut account_infos = vec![
+        sender.to_account_info(),
+        input_token_sender_ata.to_account_info(),
+        input_token_mint.to_account_info(),
+        token_program.to_account_info(),
+        token_program_2022.to_account_info(),
+        memo_program.to_account_info(),
+    ];
+
+    // build accounts for CPI call to clmm_mock
+    let mut accounts = vec![
+        AccountMeta::new(sender.key(), true), // payer (signer)
+        AccountMeta::new(input_token_sender_ata.key(), false), // input_token_account (writable)
+        AccountMeta::new(input_token_mint.key(), false), // input_token_mint (writable)
+        AccountMeta::new_readonly(token_program.key(), false), // token_program
+        AccountMeta::new_readonly(token_program_2022.key(), false), // token_program_2022
+        AccountMeta::new_readonly(memo_program.key(), false), // memo_program
+    ];
+
+    // process hop accounts in groups of 7
+    for hop in hops {
+        let chunk = hop.accounts;
+
+        account_infos.extend_from_slice(chunk);
+        accounts.extend(vec![
+            AccountMeta::new_readonly(chunk[0].key(), false), // amm_config (readonly)
+            AccountMeta::new(chunk[1].key(), false),          // pool_state (writable)
+            AccountMeta::new(chunk[2].key(), false),          // output_token_account (writable)
+            AccountMeta::new(chunk[3].key(), false),          // input_vault (writable)
+            AccountMeta::new(chunk[4].key(), false),          // output_vault (writable)
+            AccountMeta::new_readonly(chunk[5].key(), false), // output_mint (readonly)
+            AccountMeta::new(chunk[6].key(), false),          // observation_state (writable)
+        ]);
+    }
+
+    // prepare instruction data
+    let data = if is_base_input {
+        SwapRouterBaseInData {
+            discriminator: get_discriminator("swap_router_base_in"),
+            amount_in: amount,
+            amount_out_minimum: other_amount_threshold,
+        }
+        .try_to_vec()?
+    } else {
+        SwapRouterBaseOutData {
+            discriminator: get_discriminator("swap_router_base_out"),
+            amount_out: amount,
+            amount_in_maximum: other_amount_threshold,
+        }
+        .try_to_vec()?
+    };
+
+    let instruction = solana_program::instruction::Instruction {
+        program_id: *dex_program_id,
+        accounts,
+        data,
+    };
+
+    // execute CPI call with user as signer
+    Ok(solana_program::program::invoke(
+        &instruction,
+        &account_infos,
+    )?)
+}
+
+pub fn quote_hop(hop: &Hop, amount_in: u64, token_in: &Pubkey) -> Result<(u64, u16)> {
+    let chunk = hop.accounts;
+    let pool_state_loader = AccountLoader::<PoolState>::try_from(&chunk[1])?;
+    let input_vault = InterfaceAccount::<TokenAccount>::try_from(&chunk[3])?;
+    let output_vault = InterfaceAccount::<TokenAccount>::try_from(&chunk[4])?;
+
+    {
+        let pool_state = pool_state_loader.load()?;
+        let pool_vaults = [pool_state.token_vault_0, pool_state.token_vault_1];
+
+        // reserves must be read from the vaults of the pool along the route
+        if pool_state.amm_config != chunk[0].key()
+            || !pool_vaults.contains(&input_vault.key())
+            || !pool_vaults.contains(&output_vault.key())
+            || input_vault.key() == output_vault.key()
+            || input_vault.mint != *token_in
+            || output_vault.mint != hop.item.token_out
+        {
+            Err(CustomError::RouteMismatch)?;
+        }
+    }
+
+    // amm_config address is checked by validate_hop
+    let amm_config = AmmConfig::try_deserialize(&mut &chunk[0].try_borrow_data()?[..])?;
+
+    calculate_hop_quote(
+        amount_in,
+        input_vault.amount,
+        output_vault.amount,
+        amm_config.trade_fee_rate,
+    )
+}
+
+/// constant product math of clmm_mock, returns output amount and price impact \
+/// trade_fee_rate is denominated in hundredths of a bip (10^-6)
+fn calculate_hop_quote(
+    amount_in: u64,
+    reserve_in: u64,
+    reserve_out: u64,
+    trade_fee_rate: u32,
+) -> Result<(u64, u16)> {
+    if reserve_in == 0 || reserve_out == 0 {
+        Err(CustomError::InsufficientLiquidity)?;
+    }
+
+    let amount_in_with_fee =
+        amount_in as u128 * (FEE_RATE_DENOMINATOR_VALUE - trade_fee_rate) as u128;
+    let denominator = reserve_in as u128 * FEE_RATE_DENOMINATOR_VALUE as u128 + amount_in_with_fee;
+
+    let amount_out = amount_in_with_fee * reserve_out as u128 / denominator;
+    // spot price output is amount_in_with_fee * reserve_out / (reserve_in * FEE_RATE_DENOMINATOR_VALUE)
+    let price_impact_bps = amount_in_with_fee * BPS_MAX as u128 / denominator;
+
+    Ok((amount_out as u64, price_impact_bps as u16))
+}
@@ -0,0 +1,326 @@
+pub mod amm;
+pub mod clmm;
+
+use {
+    crate::helpers::transfer_protocol_fee,
+    anchor_lang::prelude::*,
+    anchor_spl::{
+        associated_token::AssociatedToken,
+        token_interface::{Mint, TokenAccount, TokenInterface},
+    },
+    dex_adapter_cpi::{
+        error::CustomError,
+        state::{DaConfig, Route},
+        types::{DexKind, RouteItem},
+    },
+};
+
+/// route item with its remaining accounts
+pub struct Hop<'a> {
+    pub item: RouteItem,
+    pub accounts: &'a [AccountInfo<'a>],
+}
+
+/// splits remaining accounts into hops, each hop must match the saved route \
+/// hop accounts layout depends on the DEX kind, see clmm and amm modules
+pub fn get_route_hops<'a>(
+    config: &DaConfig,
+    route: &[RouteItem],
+    remaining_accounts: &'a [AccountInfo<'a>],
+) -> Result<Vec<Hop<'a>>> {
+    let mut hops: Vec<Hop> = vec![];
+    let mut accounts_left = remaining_accounts;
+
+    for item in route {
+        let hop_accounts_len = item.dex.hop_accounts_len();
+
+        if accounts_left.len() < hop_accounts_len {
+            Err(CustomError::InvalidRemainingAccounts)?;
+        }
+
+        let (accounts, rest) = accounts_left.split_at(hop_accounts_len);
+
+        match item.dex {
+            DexKind::Clmm => clmm::validate_hop(&config.dex, item, accounts)?,
+            DexKind::Amm => amm::validate_hop(&get_amm_program_id(config)?, item, accounts)?,
+        }
+
+        hops.push(Hop {
+            item: item.clone(),
+            accounts,
+        });
+        accounts_left = rest;
+    }
+
+    // only admin-approved paths can be executed, extra hops aren't allowed
+    if !accounts_left.is_empty() {
+        Err(CustomError::RouteMismatch)?;
+    }
+
+    Ok(hops)
+}
+
+pub fn get_amm_program_id(config: &DaConfig) -> Result<Pubkey> {
+    config.amm.ok_or(CustomError::DexNotConfigured.into())
+}
+
+/// executes exact input swap along the route, returns output amount \
+/// consecutive clmm hops are executed by a single router call, output of each call is input of the next one
+#[allow(clippy::too_many_arguments)]
+pub fn execute_swap<'a>(
+    amount_in: u64,
+    amount_out_minimum: u64,
+    config: &DaConfig,
+    system_program: &Program<'a, System>,
+    token_program: &Interface<'a, TokenInterface>,
+    associated_token_program: &Program<'a, AssociatedToken>,
+    token_program_2022: &UncheckedAccount<'a>,
+    memo_program: &UncheckedAccount<'a>,
+    sender: &Signer<'a>,
+    input_token_mint: &InterfaceAccount<'a, Mint>,
+    input_token_sender_ata: &InterfaceAccount<'a, TokenAccount>,
+    route: &[RouteItem],
+    remaining_accounts: &'a [AccountInfo<'a>],
+) -> Result<u64> {
+    let hops = get_route_hops(config, route, remaining_accounts)?;
+    let segments: Vec<&[Hop]> = hops
+        .chunk_by(|a, b| a.item.dex == DexKind::Clmm && b.item.dex == DexKind::Clmm)
+        .collect();
+
+    let mut amount = amount_in;
+    let mut token_mint = input_token_mint.to_account_info();
+    let mut token_sender_ata = input_token_sender_ata.to_account_info();
+
+    for (i, segment) in segments.iter().enumerate() {
+        let last_hop = &segment[segment.len() - 1];
+        let (output_token_mint, output_token_sender_ata) = match last_hop.item.dex {
+            DexKind::Clmm => clmm::get_output_accounts(last_hop),
+            DexKind::Amm => amm::get_output_accounts(last_hop),
+        };
+        let output_amount_before = get_token_amount(&output_token_sender_ata)?;
+
+        match last_hop.item.dex {
+            DexKind::Clmm => clmm::execute_swap(
+                amount,
+                // intermediate amounts are checked by the final output amount
+                if i == segments.len() - 1 {
+                    amount_out_minimum
+                } else {
+                    0
+                },
+                true,
+                &config.dex,
+                token_program,
+                token_program_2022,
+                memo_program,
+                sender,
+                &token_mint,
+                &token_sender_ata,
+                segment,
+            )?,
+            DexKind::Amm => amm::execute_swap(
+                amount,
+                &token_mint.key(),
+                system_program,
+                token_program,
+                associated_token_program,
+                sender,
+                last_hop,
+            )?,
+        }
+
+        // output account is spent by the segment if it returns to its input token, e.g. A -> B -> A, \
+        // save_route rejects only hops with the same input and output tokens
+        amount = get_token_amount(&output_token_sender_ata)?
+            .checked_sub(output_amount_before)
+            .ok_or(CustomError::NoOutputTokens)?;
+        token_mint = output_token_mint;
+        token_sender_ata = output_token_sender_ata;
+    }
+
+    if amount < amount_out_minimum {
+        Err(CustomError::SlippageExceeded)?;
+    }
+
+    Ok(amount)
+}
+
+/// executes exact output swap along the route, only clmm router supports it
+#[allow(clippy::too_many_arguments)]
+pub fn execute_swap_base_out<'a>(
+    amount_out: u64,
+    amount_in_maximum: u64,
+    config: &DaConfig,
+    token_program: &Interface<'a, TokenInterface>,
+    token_program_2022: &UncheckedAccount<'a>,
+    memo_program: &UncheckedAccount<'a>,
+    sender: &Signer<'a>,
+    input_token_mint: &InterfaceAccount<'a, Mint>,
+    input_token_sender_ata: &InterfaceAccount<'a, TokenAccount>,
+    route: &[RouteItem],
+    remaining_accounts: &'a [AccountInfo<'a>],
+) -> Result<()> {
+    if route.iter().any(|x| x.dex != DexKind::Clmm) {
+        Err(CustomError::UnsupportedDexKind)?;
+    }
+
+    let hops = get_route_hops(config, route, remaining_accounts)?;
+
+    clmm::execute_swap(
+        amount_out,
+        amount_in_maximum,
+        false,
+        &config.dex,
+        token_program,
+        token_program_2022,
+        memo_program,
+        sender,
+        &input_token_mint.to_account_info(),
+        &input_token_sender_ata.to_account_info(),
+        &hops,
+    )
+}
+
+/// executes exact input swap along the route taking protocol fee from input or output amount \
+/// depending on fee side, returns output amount remaining after the fee
+#[allow(clippy::too_many_arguments)]
+pub fn execute_swap_with_fee<'a>(
+    amount_in: u64,
+    amount_out_minimum: u64,
+    config: &DaConfig,
+    route: &Route,
+    system_program: &Program<'a, System>,
+    token_program: &Interface<'a, TokenInterface>,
+    associated_token_program: &Program<'a, AssociatedToken>,
+    token_program_2022: &UncheckedAccount<'a>,
+    memo_program: &UncheckedAccount<'a>,
+    sender: &Signer<'a>,
+    input_token_mint: &InterfaceAccount<'a, Mint>,
+    input_token_sender_ata: &InterfaceAccount<'a, TokenAccount>,
+    input_token_app_ata: Option<&InterfaceAccount<'a, TokenAccount>>,
+    output_token_mint: &InterfaceAccount<'a, Mint>,
+    output_token_sender_ata: &InterfaceAccount<'a, TokenAccount>,
+    output_token_app_ata: Option<&InterfaceAccount<'a, TokenAccount>>,
+    remaining_accounts: &'a [AccountInfo<'a>],
+) -> Result<u64> {
+    let input_fee_amount = config.get_input_fee_amount(route, amount_in);
+    transfer_protocol_fee(
+        input_fee_amount,
+        token_program,
+        sender,
+        input_token_mint,
+        input_token_sender_ata,
+        input_token_app_ata,
+    )?;
+
+    let amount_out = execute_swap(
+        amount_in - input_fee_amount,
+        amount_out_minimum,
+        config,
+        system_program,
+        token_program,
+        associated_token_program,
+        token_program_2022,
+        memo_program,
+        sender,
+        input_token_mint,
+        input_token_sender_ata,
+        &route.value,
+        remaining_accounts,
+    )?;
+
+    // the rest after output fee must fit the limit
+    let output_fee_amount = config.get_output_fee_amount(route, amount_out);
+    if amount_out - output_fee_amount < amount_out_minimum {
+        Err(CustomError::SlippageExceeded)?;
+    }
+
+    transfer_protocol_fee(
+        output_fee_amount,
+        token_program,
+        sender,
+        output_token_mint,
+        output_token_sender_ata,
+        output_token_app_ata,
+    )?;
+
+    Ok(amount_out - output_fee_amount)
+}
+
+/// executes exact output swap along the route, output fee is received on top of the exact amount, \
+/// input fee is taken from actually spent input amount, the total must fit the limit
+#[allow(clippy::too_many_arguments)]
+pub fn execute_swap_base_out_with_fee<'a>(
+    amount_out: u64,
+    amount_in_maximum: u64,
+    config: &DaConfig,
+    route: &Route,
+    token_program: &Interface<'a, TokenInterface>,
+    token_program_2022: &UncheckedAccount<'a>,
+    memo_program: &UncheckedAccount<'a>,
+    sender: &Signer<'a>,
+    input_token_mint: &InterfaceAccount<'a, Mint>,
+    input_token_sender_ata: &mut InterfaceAccount<'a, TokenAccount>,
+    input_token_app_ata: Option<&InterfaceAccount<'a, TokenAccount>>,
+    output_token_mint: &InterfaceAccount<'a, Mint>,
+    output_token_sender_ata: &InterfaceAccount<'a, TokenAccount>,
+    output_token_app_ata: Option<&InterfaceAccount<'a, TokenAccount>>,
+    remaining_accounts: &'a [AccountInfo<'a>],
+) -> Result<()> {
+    let output_fee_amount = config.get_output_fee_amount_on_top(route, amount_out);
+    let input_token_amount_before = input_token_sender_ata.amount;
+
+    execute_swap_base_out(
+        amount_out + output_fee_amount,
+        amount_in_maximum,
+        config,
+        token_program,
+        token_program_2022,
+        memo_program,
+        sender,
+        input_token_mint,
+        input_token_sender_ata,
+        &route.value,
+        remaining_accounts,
+    )?;
+
+    input_token_sender_ata.reload()?;
+    let amount_in = input_token_amount_before - input_token_sender_ata.amount;
+    let input_fee_amount = config.get_input_fee_amount(route, amount_in);
+
+    if amount_in + input_fee_amount > amount_in_maximum {
+        Err(CustomError::SlippageExceeded)?;
+    }
+
+    transfer_protocol_fee(
+        input_fee_amount,
+        token_program,
+        sender,
+        input_token_mint,
+        input_token_sender_ata,
+        input_token_app_ata,
+    )?;
+
+    transfer_protocol_fee(
+        output_fee_amount,
+        token_program,
+        sender,
+        output_token_mint,
+        output_token_sender_ata,
+        output_token_app_ata,
+    )
+}
+
+/// simulates a single hop, returns output amount and price impact
+pub fn quote_hop(hop: &Hop, amount_in: u64, token_in: &Pubkey) -> Result<(u64, u16)> {
+    match hop.item.dex {
+        DexKind::Clmm => clmm::quote_hop(hop, amount_in, token_in),
+        DexKind::Amm => amm::quote_hop(hop, amount_in, token_in),
+    }
+}
+
+fn get_token_amount(token_account: &AccountInfo) -> Result<u64> {
+    let data = token_account.try_borrow_data()?;
+
+    Ok(TokenAccount::try_deserialize(&mut &data[..])?.amount)
+}
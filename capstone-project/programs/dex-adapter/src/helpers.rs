@@ -1,85 +1,39 @@
 use {
-    anchor_lang::{prelude::*, solana_program},
+    anchor_lang::prelude::*,
     anchor_spl::{
         associated_token::AssociatedToken,
         token_interface::{close_account, CloseAccount, Mint, TokenAccount, TokenInterface},
     },
-    base::helpers::get_discriminator,
-    dex_adapter_cpi::{error::CustomError, types::SwapRouterBaseInData},
+    base::helpers::transfer_token_from_user,
+    dex_adapter_cpi::error::CustomError,
 };
 
-#[allow(clippy::too_many_arguments)]
-pub fn execute_clmm_swap<'a>(
-    amount_in: u64,
-    amount_out_minimum: u64,
+/// protocol fee is accumulated in config ATA of input or output token depending on fee side \
+/// the ATA is required only if the fee isn't zero
+pub fn transfer_protocol_fee<'a>(
+    fee_amount: u64,
     token_program: &Interface<'a, TokenInterface>,
-    token_program_2022: &UncheckedAccount<'a>,
-    memo_program: &UncheckedAccount<'a>,
-    dex_program_id: &Pubkey,
     sender: &Signer<'a>,
-    input_token_mint: &InterfaceAccount<'a, Mint>,
-    input_token_sender_ata: &InterfaceAccount<'a, TokenAccount>,
-    remaining_accounts: &'a [AccountInfo<'a>],
+    token_mint: &InterfaceAccount<'a, Mint>,
+    token_sender_ata: &InterfaceAccount<'a, TokenAccount>,
+    token_app_ata: Option<&InterfaceAccount<'a, TokenAccount>>,
 ) -> Result<()> {
-    // validate that remaining accounts length is correct (multiple of 7)
-    if remaining_accounts.len() % 7 != 0 {
-        Err(CustomError::InvalidRemainingAccounts)?;
+    if fee_amount == 0 {
+        return Ok(());
     }
 
-    let account_infos = [
-        &[
-            sender.to_account_info(),
-            input_token_sender_ata.to_account_info(),
-            input_token_mint.to_account_info(),
-            token_program.to_account_info(),
-            token_program_2022.to_account_info(),
-            memo_program.to_account_info(),
-        ],
-        remaining_accounts,
-    ]
-    .concat();
-
-    // build accounts for CPI call to clmm_mock
-    let mut accounts = vec![
-        AccountMeta::new(sender.key(), true), // payer (signer)
-        AccountMeta::new(input_token_sender_ata.key(), false), // input_token_account (writable)
-        AccountMeta::new(input_token_mint.key(), false), // input_token_mint (writable)
-        AccountMeta::new_readonly(token_program.key(), false), // token_program
-        AccountMeta::new_readonly(token_program_2022.key(), false), // token_program_2022
-        AccountMeta::new_readonly(memo_program.key(), false), // memo_program
-    ];
-
-    // process remaining accounts in groups of 7
-    for chunk in remaining_accounts.chunks_exact(7) {
-        accounts.extend(vec![
-            AccountMeta::new_readonly(chunk[0].key(), false), // amm_config (readonly)
-            AccountMeta::new(chunk[1].key(), false),          // pool_state (writable)
-            AccountMeta::new(chunk[2].key(), false),          // output_token_account (writable)
-            AccountMeta::new(chunk[3].key(), false),          // input_vault (writable)
-            AccountMeta::new(chunk[4].key(), false),          // output_vault (writable)
-            AccountMeta::new_readonly(chunk[5].key(), false), // output_mint (readonly)
-            AccountMeta::new(chunk[6].key(), false),          // observation_state (writable)
-        ]);
-    }
-
-    // prepare instruction data
-    let instruction_data = SwapRouterBaseInData {
-        discriminator: get_discriminator("swap_router_base_in"),
-        amount_in,
-        amount_out_minimum,
-    };
-
-    let instruction = solana_program::instruction::Instruction {
-        program_id: *dex_program_id,
-        accounts,
-        data: instruction_data.try_to_vec()?,
+    let Some(token_app_ata) = token_app_ata else {
+        return Err(CustomError::FeeAccountNotProvided.into());
     };
 
-    // execute CPI call with user as signer
-    Ok(solana_program::program::invoke(
-        &instruction,
-        &account_infos,
-    )?)
+    transfer_token_from_user(
+        fee_amount,
+        token_mint,
+        token_sender_ata,
+        token_app_ata,
+        sender,
+        token_program,
+    )
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -92,6 +46,7 @@ pub fn activate_account_on_registry<'a>(
     sender: &Signer<'a>,
     registry_bump: &Account<'a, registry_cpi::state::Bump>,
     registry_config: &Account<'a, registry_cpi::state::Config>,
+    registry_fee_whitelist: &Account<'a, registry_cpi::state::FeeWhitelist>,
     registry_user_id: &Account<'a, registry_cpi::state::UserId>,
     output_token_mint: &InterfaceAccount<'a, Mint>,
     output_token_sender_ata: &InterfaceAccount<'a, TokenAccount>,
@@ -104,10 +59,13 @@ pub fn activate_account_on_registry<'a>(
         sender: sender.to_account_info(),
         bump: registry_bump.to_account_info(),
         config: registry_config.to_account_info(),
+        fee_whitelist: registry_fee_whitelist.to_account_info(),
         user_id: registry_user_id.to_account_info(),
+        promo_code: None,
         revenue_mint: output_token_mint.to_account_info(),
         revenue_sender_ata: output_token_sender_ata.to_account_info(),
         revenue_app_ata: revenue_app_ata.to_account_info(),
+        referrer_ata: None,
     };
 
     let cpi_ctx = CpiContext::new(registry_program.to_account_info(), cpi_accounts);
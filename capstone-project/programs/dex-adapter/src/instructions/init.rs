@@ -3,10 +3,15 @@ use {
     base::{
         error::AuthError,
         helpers::{get_clock_time, get_space},
+        migration::Versioned,
+        pause::PAUSE_NONE,
     },
-    dex_adapter_cpi::state::{
-        DaBump, DaConfig, RotationState, CLOCK_TIME_MIN, MAINNET_ADMIN, ROTATION_TIMEOUT,
-        SEED_ADMIN_ROTATION_STATE, SEED_BUMP, SEED_CONFIG,
+    dex_adapter_cpi::{
+        state::{
+            DaBump, DaConfig, RotationState, CLOCK_TIME_MIN, MAINNET_ADMIN, ROTATION_TIMEOUT,
+            SEED_ADMIN_ROTATION_STATE, SEED_BUMP, SEED_CONFIG,
+        },
+        types::FeeSide,
     },
 };
 
@@ -78,8 +83,12 @@ impl<'info> Init<'info> {
             admin: sender.key(),
             dex,
             registry,
-            is_paused: false,
+            paused_ops: PAUSE_NONE,
             rotation_timeout: rotation_timeout.unwrap_or(ROTATION_TIMEOUT),
+            fee_bps: 0,
+            amm: None,
+            fee_side: FeeSide::Input,
+            version: DaConfig::VERSION,
         });
 
         admin_rotation_state.set_inner(RotationState {
@@ -0,0 +1,132 @@
+use {
+    anchor_lang::prelude::*,
+    base::{
+        error::AuthError,
+        helpers::{get_space, DISCRIMINATOR_SPACE},
+        migration::{grow_account, migrate_account, Versioned},
+    },
+    dex_adapter_cpi::{
+        events::AccountMigrated,
+        state::{DaConfig, Route, SEED_CONFIG},
+        types::{DexKind, RouteItem},
+    },
+};
+
+/// baseline route item layout, all hops were executed by clmm
+#[derive(AnchorDeserialize)]
+struct LegacyRouteItem {
+    amm_index: u16,
+    token_out: Pubkey,
+}
+
+#[derive(Accounts)]
+pub struct Migrate<'info> {
+    pub system_program: Program<'info, System>,
+
+    #[account(mut)]
+    pub sender: Signer<'info>,
+
+    // data storage
+    //
+    /// CHECK: legacy layout can't be deserialized as DaConfig, it's checked in the handler
+    #[account(
+        mut,
+        seeds = [SEED_CONFIG.as_bytes()],
+        bump
+    )]
+    pub config: UncheckedAccount<'info>,
+}
+
+impl<'info> Migrate<'info> {
+    /// remaining_accounts: Route accounts to migrate
+    pub fn migrate(&mut self, remaining_accounts: &[AccountInfo<'info>]) -> Result<()> {
+        let Self {
+            system_program,
+            sender,
+            config,
+        } = self;
+
+        // check sender, admin is the first field in any DaConfig layout version
+        let admin = {
+            let data = config.try_borrow_data()?;
+
+            if !data.starts_with(DaConfig::DISCRIMINATOR) {
+                Err(ErrorCode::AccountDiscriminatorMismatch)?;
+            }
+
+            Pubkey::deserialize(&mut &data[DISCRIMINATOR_SPACE..])?
+        };
+
+        if sender.key() != admin {
+            Err(AuthError::Unauthorized)?;
+        }
+
+        let config = config.to_account_info();
+        if migrate_account::<DaConfig>(&config, sender, system_program, &crate::ID)? {
+            emit!(AccountMigrated {
+                address: config.key(),
+                version: DaConfig::VERSION,
+            });
+        }
+
+        for account in remaining_accounts {
+            if migrate_route(account, sender, system_program)? {
+                emit!(AccountMigrated {
+                    address: account.key(),
+                    version: Route::VERSION,
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// items of baseline routes can't be extended in place, so the route is deserialized \
+/// with the legacy layout and written again \
+/// routes have fixed space, smaller accounts are the baseline ones
+fn migrate_route<'a>(
+    account: &AccountInfo<'a>,
+    payer: &Signer<'a>,
+    system_program: &Program<'a, System>,
+) -> Result<bool> {
+    let space = get_space(Route::INIT_SPACE);
+
+    if account.data_len() >= space {
+        return migrate_account::<Route>(account, payer, system_program, &crate::ID);
+    }
+
+    if account.owner != &crate::ID {
+        Err(ErrorCode::AccountOwnedByWrongProgram)?;
+    }
+
+    let legacy_items = {
+        let data = account.try_borrow_data()?;
+
+        if !data.starts_with(Route::DISCRIMINATOR) {
+            Err(ErrorCode::AccountDiscriminatorMismatch)?;
+        }
+
+        Vec::<LegacyRouteItem>::deserialize(&mut &data[DISCRIMINATOR_SPACE..])?
+    };
+
+    let route = Route {
+        value: legacy_items
+            .into_iter()
+            .map(|x| RouteItem {
+                amm_index: x.amm_index,
+                token_out: x.token_out,
+                dex: DexKind::Clmm,
+                pool_id: 0,
+            })
+            .collect(),
+        fee_bps: None,
+        fee_side: None,
+        version: Route::VERSION,
+    };
+
+    grow_account(account, space, payer, system_program)?;
+    route.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])?;
+
+    Ok(true)
+}
@@ -1,15 +1,23 @@
+pub mod cancel_admin_rotation;
 pub mod confirm_admin_rotation;
 pub mod init;
+pub mod migrate;
+pub mod quote;
 pub mod save_route;
 pub mod swap;
 pub mod swap_and_activate;
 pub mod swap_and_unwrap_wsol;
 pub mod update_config;
+pub mod withdraw_fees;
 
+pub use cancel_admin_rotation::*;
 pub use confirm_admin_rotation::*;
 pub use init::*;
+pub use migrate::*;
+pub use quote::*;
 pub use save_route::*;
 pub use swap::*;
 pub use swap_and_activate::*;
 pub use swap_and_unwrap_wsol::*;
 pub use update_config::*;
+pub use withdraw_fees::*;
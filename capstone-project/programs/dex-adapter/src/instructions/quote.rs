@@ -0,0 +1,102 @@
+use {
+    crate::backend::{get_route_hops, quote_hop},
+    anchor_lang::prelude::*,
+    anchor_spl::token_interface::Mint,
+    dex_adapter_cpi::{
+        error::CustomError,
+        state::{DaBump, DaConfig, Route, BPS_MAX, SEED_BUMP, SEED_CONFIG, SEED_ROUTE},
+        types::{HopQuote, RouteQuote},
+    },
+};
+
+#[derive(Accounts)]
+pub struct Quote<'info> {
+    // data storage
+    //
+    #[account(
+        seeds = [SEED_BUMP.as_bytes()],
+        bump
+    )]
+    pub bump: Account<'info, DaBump>,
+
+    #[account(
+        seeds = [SEED_CONFIG.as_bytes()],
+        bump = bump.config,
+    )]
+    pub config: Account<'info, DaConfig>,
+
+    #[account(
+        seeds = [SEED_ROUTE.as_bytes(), &input_token_mint.key().to_bytes(), &output_token_mint.key().to_bytes()],
+        bump
+    )]
+    pub route: Account<'info, Route>,
+
+    // mint
+    //
+    pub input_token_mint: InterfaceAccount<'info, Mint>,
+
+    pub output_token_mint: InterfaceAccount<'info, Mint>,
+}
+
+impl<'info> Quote<'info> {
+    /// remaining accounts are the same as for swap
+    pub fn quote(
+        &self,
+        remaining_accounts: &'info [AccountInfo<'info>],
+        amount_in: u64,
+    ) -> Result<RouteQuote> {
+        let Self {
+            config,
+            route,
+            input_token_mint,
+            ..
+        } = self;
+
+        if amount_in == 0 {
+            Err(CustomError::InvalidAmount)?;
+        }
+
+        let route_hops = get_route_hops(config, &route.value, remaining_accounts)?;
+
+        // protocol fee is taken from input amount before the swap
+        let input_fee_amount = config.get_input_fee_amount(route, amount_in);
+
+        let mut hops: Vec<HopQuote> = vec![];
+        let mut hop_amount_in = amount_in - input_fee_amount;
+        let mut hop_token_in = input_token_mint.key();
+        // share of the spot price output that remains after all hops
+        let mut retained_bps = BPS_MAX;
+
+        for hop in &route_hops {
+            if hop_amount_in == 0 {
+                Err(CustomError::InvalidAmount)?;
+            }
+
+            let (hop_amount_out, price_impact_bps) = quote_hop(hop, hop_amount_in, &hop_token_in)?;
+
+            hops.push(HopQuote {
+                amount_in: hop_amount_in,
+                amount_out: hop_amount_out,
+            });
+
+            retained_bps =
+                (retained_bps as u32 * (BPS_MAX - price_impact_bps) as u32 / BPS_MAX as u32) as u16;
+
+            // output of each hop is input of the next one
+            hop_amount_in = hop_amount_out;
+            hop_token_in = hop.item.token_out;
+        }
+
+        // or from output amount after the swap
+        let output_fee_amount = config.get_output_fee_amount(route, hop_amount_in);
+
+        Ok(RouteQuote {
+            amount_in,
+            fee_amount: input_fee_amount + output_fee_amount,
+            fee_side: config.get_fee_side(route),
+            amount_out: hop_amount_in - output_fee_amount,
+            hops,
+            price_impact_bps: BPS_MAX - retained_bps,
+        })
+    }
+}
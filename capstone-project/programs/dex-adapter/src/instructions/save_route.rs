@@ -1,9 +1,12 @@
 use {
     anchor_lang::prelude::*,
-    base::{error::AuthError, helpers::get_space},
+    base::{error::AuthError, helpers::get_space, migration::Versioned},
     dex_adapter_cpi::{
-        state::{DaBump, DaConfig, Route, SEED_BUMP, SEED_CONFIG, SEED_ROUTE},
-        types::RouteItem,
+        error::CustomError,
+        state::{
+            DaBump, DaConfig, Route, FEE_BPS_MAX, ROUTE_MAX_LEN, SEED_BUMP, SEED_CONFIG, SEED_ROUTE,
+        },
+        types::{FeeSide, RouteItem},
     },
 };
 
@@ -43,15 +46,45 @@ pub struct SaveRoute<'info> {
 impl<'info> SaveRoute<'info> {
     pub fn save_route(
         &mut self,
-        _mint_first: Pubkey,
-        _mint_last: Pubkey,
+        mint_first: Pubkey,
+        mint_last: Pubkey,
         route: Vec<RouteItem>,
+        fee_bps: Option<u16>,
+        fee_side: Option<FeeSide>,
     ) -> Result<()> {
         if self.sender.key() != self.config.admin {
             Err(AuthError::Unauthorized)?;
         }
 
-        self.route.set_inner(Route { value: route });
+        // empty route would charge the fee without swapping
+        if route.is_empty() || route.len() > ROUTE_MAX_LEN {
+            Err(CustomError::InvalidRouteLength)?;
+        }
+
+        if route.last().map(|x| x.token_out) != Some(mint_last) {
+            Err(CustomError::RouteOutputMismatch)?;
+        }
+
+        // hop output is measured by the balance change of its token account
+        let mut token_in = mint_first;
+        for item in &route {
+            if item.token_out == token_in {
+                Err(CustomError::SameHopTokens)?;
+            }
+
+            token_in = item.token_out;
+        }
+
+        if fee_bps.is_some_and(|x| x > FEE_BPS_MAX) {
+            Err(CustomError::FeeTooHigh)?;
+        }
+
+        self.route.set_inner(Route {
+            value: route,
+            fee_bps,
+            fee_side,
+            version: Route::VERSION,
+        });
 
         Ok(())
     }
@@ -1,10 +1,14 @@
 use {
-    crate::helpers::{activate_account_on_registry, execute_clmm_swap},
+    crate::{
+        backend::{execute_swap_base_out_with_fee, execute_swap_with_fee},
+        helpers::activate_account_on_registry,
+    },
     anchor_lang::prelude::*,
     anchor_spl::{
         associated_token::AssociatedToken,
         token_interface::{Mint, TokenAccount, TokenInterface},
     },
+    base::pause::{is_paused, PAUSE_SWAP_AND_ACTIVATE},
     dex_adapter_cpi::{
         error::CustomError,
         state::{DaBump, DaConfig, Route, SEED_BUMP, SEED_CONFIG, SEED_ROUTE},
@@ -63,6 +67,13 @@ pub struct SwapAndActivate<'info> {
     )]
     pub registry_config: Box<Account<'info, registry_cpi::state::Config>>,
 
+    #[account(
+        seeds = [registry_cpi::state::SEED_FEE_WHITELIST.as_bytes()],
+        bump,
+        seeds::program = registry_program.key()
+    )]
+    pub registry_fee_whitelist: Box<Account<'info, registry_cpi::state::FeeWhitelist>>,
+
     #[account(
         mut,
         seeds = [registry_cpi::state::SEED_USER_ID.as_bytes(), &sender.key().to_bytes()],
@@ -88,6 +99,23 @@ pub struct SwapAndActivate<'info> {
     )]
     pub input_token_sender_ata: InterfaceAccount<'info, TokenAccount>,
 
+    // protocol fees are accumulated in config ATAs, only the fee side one is required if the fee isn't zero
+    #[account(
+        init_if_needed,
+        payer = sender,
+        associated_token::mint = input_token_mint,
+        associated_token::authority = config
+    )]
+    pub input_token_app_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        init_if_needed,
+        payer = sender,
+        associated_token::mint = output_token_mint,
+        associated_token::authority = config
+    )]
+    pub output_token_app_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
     #[account(
         init_if_needed,
         payer = sender,
@@ -96,8 +124,10 @@ pub struct SwapAndActivate<'info> {
     )]
     pub output_token_sender_ata: InterfaceAccount<'info, TokenAccount>,
 
+    // whitelisted assets may not have app ATA yet
     #[account(
-        mut,
+        init_if_needed,
+        payer = sender,
         associated_token::mint = output_token_mint,
         associated_token::authority = registry_config
     )]
@@ -120,32 +150,126 @@ impl<'info> SwapAndActivate<'info> {
             registry_program,
             sender,
             config,
+            route,
             registry_bump,
             registry_config,
+            registry_fee_whitelist,
             registry_user_id,
             input_token_mint,
             output_token_mint,
             input_token_sender_ata,
+            input_token_app_ata,
+            output_token_app_ata,
             output_token_sender_ata,
             revenue_app_ata,
             ..
         } = self;
 
+        // don't allow swap in paused program
+        if is_paused(config.paused_ops, PAUSE_SWAP_AND_ACTIVATE) {
+            Err(CustomError::ContractPaused)?;
+        }
+
         if amount_in == 0 {
             Err(CustomError::InvalidAmount)?;
         }
 
-        // execute multihop swap on clmm_mock
-        execute_clmm_swap(
+        // execute multihop swap, each hop is executed by its DEX
+        execute_swap_with_fee(
             amount_in,
             amount_out_minimum,
+            config,
+            route,
+            system_program,
             token_program,
+            associated_token_program,
             token_program_2022,
             memo_program,
-            &config.dex,
             sender,
             input_token_mint,
             input_token_sender_ata,
+            input_token_app_ata.as_deref(),
+            output_token_mint,
+            output_token_sender_ata,
+            output_token_app_ata.as_deref(),
+            remaining_accounts,
+        )?;
+
+        // activate account on registry program
+        activate_account_on_registry(
+            sender.key,
+            system_program,
+            token_program,
+            associated_token_program,
+            registry_program,
+            sender,
+            registry_bump,
+            registry_config,
+            registry_fee_whitelist,
+            registry_user_id,
+            output_token_mint,
+            output_token_sender_ata,
+            revenue_app_ata,
+        )?;
+
+        Ok(())
+    }
+
+    pub fn swap_base_out_and_activate(
+        &mut self,
+        remaining_accounts: &'info [AccountInfo<'info>],
+        amount_in_maximum: u64,
+    ) -> Result<()> {
+        let Self {
+            system_program,
+            token_program,
+            associated_token_program,
+            token_program_2022,
+            memo_program,
+            registry_program,
+            sender,
+            config,
+            route,
+            registry_bump,
+            registry_config,
+            registry_fee_whitelist,
+            registry_user_id,
+            input_token_mint,
+            output_token_mint,
+            input_token_sender_ata,
+            input_token_app_ata,
+            output_token_app_ata,
+            output_token_sender_ata,
+            revenue_app_ata,
+            ..
+        } = self;
+
+        // don't allow swap in paused program
+        if is_paused(config.paused_ops, PAUSE_SWAP_AND_ACTIVATE) {
+            Err(CustomError::ContractPaused)?;
+        }
+
+        // receive exactly the activation fee
+        let amount_out = registry_fee_whitelist
+            .get_fee_amount(&registry_config.registration_fee, &output_token_mint.key())
+            .ok_or(CustomError::UnacceptedFeeAsset)?;
+
+        // execute multihop swap receiving exact output amount
+        execute_swap_base_out_with_fee(
+            amount_out,
+            amount_in_maximum,
+            config,
+            route,
+            token_program,
+            token_program_2022,
+            memo_program,
+            sender,
+            input_token_mint,
+            input_token_sender_ata,
+            input_token_app_ata.as_deref(),
+            output_token_mint,
+            output_token_sender_ata,
+            output_token_app_ata.as_deref(),
             remaining_accounts,
         )?;
 
@@ -159,6 +283,7 @@ impl<'info> SwapAndActivate<'info> {
             sender,
             registry_bump,
             registry_config,
+            registry_fee_whitelist,
             registry_user_id,
             output_token_mint,
             output_token_sender_ata,
@@ -1,10 +1,11 @@
 use {
-    crate::helpers::{execute_clmm_swap, unwrap_wsol},
+    crate::{backend::execute_swap_with_fee, helpers::unwrap_wsol},
     anchor_lang::prelude::*,
     anchor_spl::{
         associated_token::AssociatedToken,
         token_interface::{Mint, TokenAccount, TokenInterface},
     },
+    base::pause::{is_paused, PAUSE_UNWRAP},
     dex_adapter_cpi::{
         error::CustomError,
         state::{DaBump, DaConfig, Route, SEED_BUMP, SEED_CONFIG, SEED_ROUTE},
@@ -64,6 +65,23 @@ pub struct SwapAndUnwrapWsol<'info> {
     )]
     pub input_token_sender_ata: InterfaceAccount<'info, TokenAccount>,
 
+    // protocol fees are accumulated in config ATAs, only the fee side one is required if the fee isn't zero
+    #[account(
+        init_if_needed,
+        payer = sender,
+        associated_token::mint = input_token_mint,
+        associated_token::authority = config
+    )]
+    pub input_token_app_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        init_if_needed,
+        payer = sender,
+        associated_token::mint = output_token_mint,
+        associated_token::authority = config
+    )]
+    pub output_token_app_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
     #[account(
         init_if_needed,
         payer = sender,
@@ -81,32 +99,50 @@ impl<'info> SwapAndUnwrapWsol<'info> {
         amount_out_minimum: u64,
     ) -> Result<()> {
         let Self {
+            system_program,
             token_program,
+            associated_token_program,
             token_program_2022,
             memo_program,
             sender,
             config,
+            route,
             input_token_mint,
+            output_token_mint,
             input_token_sender_ata,
+            input_token_app_ata,
+            output_token_app_ata,
             output_token_sender_ata,
             ..
         } = self;
 
+        // don't allow swap in paused program
+        if is_paused(config.paused_ops, PAUSE_UNWRAP) {
+            Err(CustomError::ContractPaused)?;
+        }
+
         if amount_in == 0 {
             Err(CustomError::InvalidAmount)?;
         }
 
-        // execute multihop swap on clmm_mock
-        execute_clmm_swap(
+        // execute multihop swap, each hop is executed by its DEX
+        execute_swap_with_fee(
             amount_in,
             amount_out_minimum,
+            config,
+            route,
+            system_program,
             token_program,
+            associated_token_program,
             token_program_2022,
             memo_program,
-            &config.dex,
             sender,
             input_token_mint,
             input_token_sender_ata,
+            input_token_app_ata.as_deref(),
+            output_token_mint,
+            output_token_sender_ata,
+            output_token_app_ata.as_deref(),
             remaining_accounts,
         )?;
 
@@ -4,8 +4,10 @@ use {
     dex_adapter_cpi::{
         error::CustomError,
         state::{
-            DaBump, DaConfig, RotationState, SEED_ADMIN_ROTATION_STATE, SEED_BUMP, SEED_CONFIG,
+            DaBump, DaConfig, RotationState, FEE_BPS_MAX, SEED_ADMIN_ROTATION_STATE, SEED_BUMP,
+            SEED_CONFIG,
         },
+        types::UpdateConfigArgs,
     },
 };
 
@@ -37,14 +39,18 @@ pub struct UpdateConfig<'info> {
 }
 
 impl<'info> UpdateConfig<'info> {
-    pub fn update_config(
-        &mut self,
-        admin: Option<Pubkey>,
-        dex: Option<Pubkey>,
-        registry: Option<Pubkey>,
-        is_paused: Option<bool>,
-        rotation_timeout: Option<u32>,
-    ) -> Result<()> {
+    pub fn update_config(&mut self, args: UpdateConfigArgs) -> Result<()> {
+        let UpdateConfigArgs {
+            admin,
+            dex,
+            registry,
+            paused_ops,
+            rotation_timeout,
+            fee_bps,
+            amm,
+            fee_side,
+        } = args;
+
         let Self {
             sender,
             config,
@@ -70,13 +76,18 @@ impl<'info> UpdateConfig<'info> {
             is_config_updated = true;
         }
 
+        if let Some(x) = amm {
+            config.amm = Some(x);
+            is_config_updated = true;
+        }
+
         if let Some(x) = registry {
             config.registry = Some(x);
             is_config_updated = true;
         }
 
-        if let Some(x) = is_paused {
-            config.is_paused = x;
+        if let Some(x) = paused_ops {
+            config.paused_ops = x;
             is_config_updated = true;
         }
 
@@ -85,6 +96,20 @@ impl<'info> UpdateConfig<'info> {
             is_config_updated = true;
         }
 
+        if let Some(x) = fee_bps {
+            if x > FEE_BPS_MAX {
+                Err(CustomError::FeeTooHigh)?;
+            }
+
+            config.fee_bps = x;
+            is_config_updated = true;
+        }
+
+        if let Some(x) = fee_side {
+            config.fee_side = x;
+            is_config_updated = true;
+        }
+
         // don't allow empty instructions
         if !is_config_updated {
             Err(CustomError::NoParameters)?;
@@ -0,0 +1,113 @@
+use {
+    anchor_lang::prelude::*,
+    anchor_spl::{
+        associated_token::AssociatedToken,
+        token_interface::{Mint, TokenAccount, TokenInterface},
+    },
+    base::{error::AuthError, helpers::transfer_token_from_program},
+    dex_adapter_cpi::{
+        error::CustomError,
+        events::FeesWithdrawn,
+        state::{DaBump, DaConfig, SEED_BUMP, SEED_CONFIG},
+    },
+};
+
+#[derive(Accounts)]
+pub struct WithdrawFees<'info> {
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    #[account(mut)]
+    pub sender: Signer<'info>,
+
+    // handle the option on client
+    pub recipient: SystemAccount<'info>,
+
+    // data storage
+    //
+    #[account(
+        seeds = [SEED_BUMP.as_bytes()],
+        bump
+    )]
+    pub bump: Account<'info, DaBump>,
+
+    #[account(
+        seeds = [SEED_CONFIG.as_bytes()],
+        bump = bump.config
+    )]
+    pub config: Account<'info, DaConfig>,
+
+    // mint
+    //
+    pub fee_mint: InterfaceAccount<'info, Mint>,
+
+    // ata
+    //
+    #[account(
+        init_if_needed,
+        payer = sender,
+        associated_token::mint = fee_mint,
+        associated_token::authority = recipient
+    )]
+    pub fee_recipient_ata: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = fee_mint,
+        associated_token::authority = config
+    )]
+    pub fee_app_ata: InterfaceAccount<'info, TokenAccount>,
+}
+
+impl<'info> WithdrawFees<'info> {
+    pub fn withdraw_fees(&mut self, amount: Option<u64>) -> Result<()> {
+        let Self {
+            token_program,
+            sender,
+            recipient,
+            bump,
+            config,
+            fee_mint,
+            fee_recipient_ata,
+            fee_app_ata,
+            ..
+        } = self;
+
+        // check sender
+        if sender.key() != config.admin {
+            Err(AuthError::Unauthorized)?;
+        }
+
+        let amount = amount.unwrap_or(fee_app_ata.amount);
+
+        // lower limit of amount to withdraw
+        if amount == 0 {
+            Err(CustomError::InvalidAmount)?;
+        }
+
+        // higher limit of amount to withdraw
+        if amount > fee_app_ata.amount {
+            Err(CustomError::ExceededAvailableAssetAmount)?;
+        }
+
+        transfer_token_from_program(
+            amount,
+            fee_mint,
+            fee_app_ata,
+            fee_recipient_ata,
+            &[SEED_CONFIG.as_bytes()],
+            bump.config,
+            config,
+            token_program,
+        )?;
+
+        emit!(FeesWithdrawn {
+            asset: fee_mint.key(),
+            amount,
+            recipient: recipient.key(),
+        });
+
+        Ok(())
+    }
+}
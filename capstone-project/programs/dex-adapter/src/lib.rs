@@ -2,14 +2,16 @@
 
 use anchor_lang::prelude::*;
 
+pub mod backend;
 pub mod helpers;
 pub mod instructions;
 
 use {
-    dex_adapter_cpi::types::RouteItem,
+    dex_adapter_cpi::types::{FeeSide, RouteItem, RouteQuote, UpdateConfigArgs},
     instructions::{
-        confirm_admin_rotation::*, init::*, save_route::*, swap::*, swap_and_activate::*,
-        swap_and_unwrap_wsol::*, update_config::*,
+        cancel_admin_rotation::*, confirm_admin_rotation::*, init::*, migrate::*, quote::*,
+        save_route::*, swap::*, swap_and_activate::*, swap_and_unwrap_wsol::*, update_config::*,
+        withdraw_fees::*,
     },
 };
 
@@ -30,32 +32,54 @@ pub mod dex_adapter {
             .init(ctx.bumps, dex, registry, rotation_timeout)
     }
 
-    pub fn update_config(
-        ctx: Context<UpdateConfig>,
-        admin: Option<Pubkey>,
-        dex: Option<Pubkey>,
-        registry: Option<Pubkey>,
-        is_paused: Option<bool>,
-        rotation_timeout: Option<u32>,
-    ) -> Result<()> {
-        ctx.accounts
-            .update_config(admin, dex, registry, is_paused, rotation_timeout)
+    pub fn update_config(ctx: Context<UpdateConfig>, args: UpdateConfigArgs) -> Result<()> {
+        ctx.accounts.update_config(args)
     }
 
     pub fn confirm_admin_rotation(ctx: Context<ConfirmAdminRotation>) -> Result<()> {
         ctx.accounts.confirm_admin_rotation()
     }
 
+    /// revokes pending or expired admin rotation
+    pub fn cancel_admin_rotation(ctx: Context<CancelAdminRotation>) -> Result<()> {
+        ctx.accounts.cancel_admin_rotation()
+    }
+
+    /// upgrades DaConfig and Route accounts to the latest layout \
+    /// Route accounts are passed as remaining accounts
+    pub fn migrate<'a, 'b, 'c: 'info, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, Migrate<'info>>,
+    ) -> Result<()> {
+        ctx.accounts.migrate(ctx.remaining_accounts)
+    }
+
+    /// swaps are allowed only along saved routes, route fee_bps and fee_side override the config ones
     pub fn save_route(
         ctx: Context<SaveRoute>,
         mint_first: Pubkey,
         mint_last: Pubkey,
         route: Vec<RouteItem>,
+        fee_bps: Option<u16>,
+        fee_side: Option<FeeSide>,
     ) -> Result<()> {
-        ctx.accounts.save_route(mint_first, mint_last, route)
+        ctx.accounts
+            .save_route(mint_first, mint_last, route, fee_bps, fee_side)
     }
 
-    /// swap across multiple pools
+    /// withdraws accumulated protocol fees to specified recipient
+    pub fn withdraw_fees(ctx: Context<WithdrawFees>, amount: Option<u64>) -> Result<()> {
+        ctx.accounts.withdraw_fees(amount)
+    }
+
+    /// read-only simulation of swap along the saved route, the result is returned via return data
+    pub fn quote<'a, 'b, 'c: 'info, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, Quote<'info>>,
+        amount_in: u64,
+    ) -> Result<RouteQuote> {
+        ctx.accounts.quote(ctx.remaining_accounts, amount_in)
+    }
+
+    /// swap across multiple pools, the pools must match the saved route
     pub fn swap<'a, 'b, 'c: 'info, 'info>(
         ctx: Context<'a, 'b, 'c, 'info, Swap<'info>>,
         amount_in: u64,
@@ -65,6 +89,16 @@ pub mod dex_adapter {
             .swap(ctx.remaining_accounts, amount_in, amount_out_minimum)
     }
 
+    /// swap across multiple pools receiving exact output amount
+    pub fn swap_base_out<'a, 'b, 'c: 'info, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, Swap<'info>>,
+        amount_out: u64,
+        amount_in_maximum: u64,
+    ) -> Result<()> {
+        ctx.accounts
+            .swap_base_out(ctx.remaining_accounts, amount_out, amount_in_maximum)
+    }
+
     /// swap tokens and call activate_account of registry program
     pub fn swap_and_activate<'a, 'b, 'c: 'info, 'info>(
         ctx: Context<'a, 'b, 'c, 'info, SwapAndActivate<'info>>,
@@ -75,6 +109,15 @@ pub mod dex_adapter {
             .swap_and_activate(ctx.remaining_accounts, amount_in, amount_out_minimum)
     }
 
+    /// swap tokens receiving exactly the activation fee and call activate_account of registry program
+    pub fn swap_base_out_and_activate<'a, 'b, 'c: 'info, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, SwapAndActivate<'info>>,
+        amount_in_maximum: u64,
+    ) -> Result<()> {
+        ctx.accounts
+            .swap_base_out_and_activate(ctx.remaining_accounts, amount_in_maximum)
+    }
+
     /// swap a token to WSOL and unwrap it to SOL
     pub fn swap_and_unwrap_wsol<'a, 'b, 'c: 'info, 'info>(
         ctx: Context<'a, 'b, 'c, 'info, SwapAndUnwrapWsol<'info>>,
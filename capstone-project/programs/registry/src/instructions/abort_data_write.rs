@@ -0,0 +1,34 @@
+use {
+    anchor_lang::prelude::*,
+    registry_cpi::state::{UserId, WriteBuffer, SEED_USER_ID, SEED_USER_WRITE_BUFFER},
+};
+
+#[derive(Accounts)]
+pub struct AbortDataWrite<'info> {
+    #[account(mut)]
+    pub sender: Signer<'info>,
+
+    // data storage
+    //
+    #[account(
+        seeds = [SEED_USER_ID.as_bytes(), sender.key().as_ref()],
+        bump
+    )]
+    pub user_id: Account<'info, UserId>,
+
+    #[account(
+        mut,
+        close = sender,
+        seeds = [SEED_USER_WRITE_BUFFER.as_bytes(), user_id.id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub user_write_buffer: Account<'info, WriteBuffer>,
+}
+
+impl<'info> AbortDataWrite<'info> {
+    /// buffered data is discarded, the write can be aborted regardless of account status \
+    /// to let the owner start a new one or redeem the rent if the buffer can't be committed
+    pub fn abort_data_write(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
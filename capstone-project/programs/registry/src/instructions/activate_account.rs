@@ -4,10 +4,18 @@ use {
         associated_token::AssociatedToken,
         token_interface::{Mint, TokenAccount, TokenInterface},
     },
-    base::helpers::transfer_token_from_user,
+    base::{
+        helpers::{get_clock_time, transfer_token_from_user},
+        pause::{is_paused, PAUSE_ACTIVATE},
+    },
     registry_cpi::{
         error::CustomError,
-        state::{Bump, Config, UserId, SEED_BUMP, SEED_CONFIG, SEED_USER_ID},
+        events::{AccountActivated, PromoCodeUsed},
+        state::{
+            Bump, Config, FeeWhitelist, PromoCode, UserId, SEED_BUMP, SEED_CONFIG,
+            SEED_FEE_WHITELIST, SEED_USER_ID,
+        },
+        types::AssetItem,
     },
 };
 
@@ -35,6 +43,12 @@ pub struct ActivateAccount<'info> {
     )]
     pub config: Account<'info, Config>,
 
+    #[account(
+        seeds = [SEED_FEE_WHITELIST.as_bytes()],
+        bump
+    )]
+    pub fee_whitelist: Account<'info, FeeWhitelist>,
+
     #[account(
         mut,
         seeds = [SEED_USER_ID.as_bytes(), user.as_ref()],
@@ -42,6 +56,10 @@ pub struct ActivateAccount<'info> {
     )]
     pub user_id: Account<'info, UserId>,
 
+    // optional discount, PromoCode accounts can be created by admin only
+    #[account(mut)]
+    pub promo_code: Option<Box<Account<'info, PromoCode>>>,
+
     // mint
     //
     pub revenue_mint: InterfaceAccount<'info, Mint>,
@@ -56,46 +74,80 @@ pub struct ActivateAccount<'info> {
     )]
     pub revenue_sender_ata: InterfaceAccount<'info, TokenAccount>,
 
+    // whitelisted assets may not have app ATA yet
     #[account(
-        mut,
+        init_if_needed,
+        payer = sender,
         associated_token::mint = revenue_mint,
         associated_token::authority = config
     )]
     pub revenue_app_ata: InterfaceAccount<'info, TokenAccount>,
+
+    // required if promo code has referrer
+    #[account(mut)]
+    pub referrer_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 }
 
 impl<'info> ActivateAccount<'info> {
-    pub fn activate_account(&mut self, _user: Pubkey) -> Result<()> {
+    pub fn activate_account(&mut self, user: Pubkey) -> Result<()> {
+        // only inactive account can be activated
+        if self.user_id.is_activated {
+            Err(CustomError::ActivateAccountTwice)?;
+        }
+
+        self.pay_activation_fee(user, false)
+    }
+
+    pub fn renew_activation(&mut self, user: Pubkey) -> Result<()> {
+        // only activated account can be renewed
+        if !self.user_id.is_activated {
+            Err(CustomError::AccountIsNotActivated)?;
+        }
+
+        self.pay_activation_fee(user, true)
+    }
+
+    fn pay_activation_fee(&mut self, user: Pubkey, is_renewal: bool) -> Result<()> {
         let Self {
             token_program,
             sender,
             config,
+            fee_whitelist,
             user_id,
+            promo_code,
             revenue_mint,
             revenue_sender_ata,
             revenue_app_ata,
+            referrer_ata,
             ..
         } = self;
 
+        // don't allow activate accounts in paused program
+        if is_paused(config.paused_ops, PAUSE_ACTIVATE) {
+            Err(CustomError::ContractIsPaused)?;
+        }
+
         // only open account can be activated
         if !user_id.is_open {
             Err(CustomError::AccountIsNotOpened)?;
         }
 
-        // only inactive account can be activated
-        if user_id.is_activated {
-            Err(CustomError::ActivateAccountTwice)?;
-        }
-
         // validate fee token
-        if revenue_mint.key() != config.registration_fee.asset {
-            Err(CustomError::WrongAssetType)?;
-        }
+        let fee_amount = fee_whitelist
+            .get_fee_amount(&config.registration_fee, &revenue_mint.key())
+            .ok_or(CustomError::WrongAssetType)?;
+
+        let clock_time = get_clock_time()?;
+        let (fee_amount, referrer_amount) = match promo_code {
+            Some(x) => x.apply(fee_amount, clock_time)?,
+            None => (fee_amount, 0),
+        };
 
         user_id.is_activated = true;
+        user_id.extend_activation(clock_time, config.activation_period);
 
         transfer_token_from_user(
-            config.registration_fee.amount,
+            fee_amount,
             revenue_mint,
             revenue_sender_ata,
             revenue_app_ata,
@@ -103,6 +155,45 @@ impl<'info> ActivateAccount<'info> {
             token_program,
         )?;
 
+        if let Some(promo_code) = promo_code {
+            if referrer_amount != 0 {
+                match referrer_ata {
+                    Some(x)
+                        if Some(x.owner) == promo_code.referrer && x.mint == revenue_mint.key() =>
+                    {
+                        transfer_token_from_user(
+                            referrer_amount,
+                            revenue_mint,
+                            revenue_sender_ata,
+                            x,
+                            sender,
+                            token_program,
+                        )?;
+                    }
+                    _ => Err(CustomError::WrongReferrerAccount)?,
+                }
+            }
+
+            emit!(PromoCodeUsed {
+                code: promo_code.code.clone(),
+                id: user_id.id,
+                referrer: promo_code.referrer,
+                referrer_amount,
+            });
+        }
+
+        emit!(AccountActivated {
+            id: user_id.id,
+            owner: user,
+            payer: sender.key(),
+            fee: AssetItem {
+                amount: fee_amount,
+                asset: revenue_mint.key(),
+            },
+            activated_until: user_id.activated_until,
+            is_renewal,
+        });
+
         Ok(())
     }
 }
@@ -0,0 +1,163 @@
+use {
+    anchor_lang::prelude::*,
+    base::{
+        helpers::{get_clock_time, transfer_sol_from_user},
+        pause::{is_paused, PAUSE_ACTIVATE},
+    },
+    registry_cpi::{
+        error::CustomError,
+        events::{AccountActivated, PromoCodeUsed},
+        state::{
+            Bump, Config, FeeWhitelist, PromoCode, UserId, NATIVE_SOL_ASSET, SEED_BUMP,
+            SEED_CONFIG, SEED_FEE_WHITELIST, SEED_USER_ID,
+        },
+        types::AssetItem,
+    },
+};
+
+#[derive(Accounts)]
+#[instruction(user: Pubkey)]
+pub struct ActivateAccountWithSol<'info> {
+    pub system_program: Program<'info, System>,
+
+    #[account(mut)]
+    pub sender: Signer<'info>,
+
+    // data storage
+    //
+    #[account(
+        seeds = [SEED_BUMP.as_bytes()],
+        bump
+    )]
+    pub bump: Account<'info, Bump>,
+
+    // SOL revenue is stored on config balance
+    #[account(
+        mut,
+        seeds = [SEED_CONFIG.as_bytes()],
+        bump = bump.config
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [SEED_FEE_WHITELIST.as_bytes()],
+        bump
+    )]
+    pub fee_whitelist: Account<'info, FeeWhitelist>,
+
+    #[account(
+        mut,
+        seeds = [SEED_USER_ID.as_bytes(), user.as_ref()],
+        bump
+    )]
+    pub user_id: Account<'info, UserId>,
+
+    // optional discount, PromoCode accounts can be created by admin only
+    #[account(mut)]
+    pub promo_code: Option<Account<'info, PromoCode>>,
+
+    // required if promo code has referrer
+    #[account(mut)]
+    pub referrer: Option<SystemAccount<'info>>,
+}
+
+impl<'info> ActivateAccountWithSol<'info> {
+    pub fn activate_account_with_sol(&mut self, user: Pubkey) -> Result<()> {
+        // only inactive account can be activated
+        if self.user_id.is_activated {
+            Err(CustomError::ActivateAccountTwice)?;
+        }
+
+        self.pay_activation_fee(user, false)
+    }
+
+    pub fn renew_activation_with_sol(&mut self, user: Pubkey) -> Result<()> {
+        // only activated account can be renewed
+        if !self.user_id.is_activated {
+            Err(CustomError::AccountIsNotActivated)?;
+        }
+
+        self.pay_activation_fee(user, true)
+    }
+
+    fn pay_activation_fee(&mut self, user: Pubkey, is_renewal: bool) -> Result<()> {
+        let Self {
+            system_program,
+            sender,
+            config,
+            fee_whitelist,
+            user_id,
+            promo_code,
+            referrer,
+            ..
+        } = self;
+
+        // don't allow activate accounts in paused program
+        if is_paused(config.paused_ops, PAUSE_ACTIVATE) {
+            Err(CustomError::ContractIsPaused)?;
+        }
+
+        // only open account can be activated
+        if !user_id.is_open {
+            Err(CustomError::AccountIsNotOpened)?;
+        }
+
+        // validate fee asset
+        let fee_amount = fee_whitelist
+            .get_fee_amount(&config.registration_fee, &NATIVE_SOL_ASSET)
+            .ok_or(CustomError::WrongAssetType)?;
+
+        let clock_time = get_clock_time()?;
+        let (fee_amount, referrer_amount) = match promo_code {
+            Some(x) => x.apply(fee_amount, clock_time)?,
+            None => (fee_amount, 0),
+        };
+
+        user_id.is_activated = true;
+        user_id.extend_activation(clock_time, config.activation_period);
+
+        transfer_sol_from_user(
+            fee_amount,
+            sender,
+            &config.to_account_info(),
+            system_program,
+        )?;
+
+        if let Some(promo_code) = promo_code {
+            if referrer_amount != 0 {
+                match referrer {
+                    Some(x) if Some(x.key()) == promo_code.referrer => {
+                        transfer_sol_from_user(
+                            referrer_amount,
+                            sender,
+                            &x.to_account_info(),
+                            system_program,
+                        )?;
+                    }
+                    _ => Err(CustomError::WrongReferrerAccount)?,
+                }
+            }
+
+            emit!(PromoCodeUsed {
+                code: promo_code.code.clone(),
+                id: user_id.id,
+                referrer: promo_code.referrer,
+                referrer_amount,
+            });
+        }
+
+        emit!(AccountActivated {
+            id: user_id.id,
+            owner: user,
+            payer: sender.key(),
+            fee: AssetItem {
+                amount: fee_amount,
+                asset: NATIVE_SOL_ASSET,
+            },
+            activated_until: user_id.activated_until,
+            is_renewal,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+use {
+    anchor_lang::prelude::*,
+    base::{
+        helpers::get_clock_time,
+        pause::{is_paused, PAUSE_WRITE},
+    },
+    registry_cpi::{
+        error::CustomError,
+        state::{
+            Bump, Config, UserId, WriteBuffer, SEED_BUMP, SEED_CONFIG, SEED_USER_ID,
+            SEED_USER_WRITE_BUFFER,
+        },
+    },
+};
+
+#[derive(Accounts)]
+pub struct AppendDataChunk<'info> {
+    pub sender: Signer<'info>,
+
+    // data storage
+    //
+    #[account(
+        seeds = [SEED_BUMP.as_bytes()],
+        bump
+    )]
+    pub bump: Account<'info, Bump>,
+
+    #[account(
+        seeds = [SEED_CONFIG.as_bytes()],
+        bump = bump.config
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [SEED_USER_ID.as_bytes(), sender.key().as_ref()],
+        bump
+    )]
+    pub user_id: Account<'info, UserId>,
+
+    #[account(
+        mut,
+        seeds = [SEED_USER_WRITE_BUFFER.as_bytes(), user_id.id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub user_write_buffer: Account<'info, WriteBuffer>,
+}
+
+impl<'info> AppendDataChunk<'info> {
+    pub fn append_data_chunk(&mut self, offset: u32, chunk: Vec<u8>) -> Result<()> {
+        let Self {
+            config,
+            user_id,
+            user_write_buffer,
+            ..
+        } = self;
+
+        // don't allow write data in paused program
+        if is_paused(config.paused_ops, PAUSE_WRITE) {
+            Err(CustomError::ContractIsPaused)?;
+        }
+
+        if user_id.is_suspended {
+            Err(CustomError::AccountIsSuspended)?;
+        }
+
+        if !user_id.is_activated {
+            Err(CustomError::AccountIsNotActivated)?;
+        }
+
+        if user_id.is_activation_expired(get_clock_time()?) {
+            Err(CustomError::ActivationIsExpired)?;
+        }
+
+        // chunks must be appended sequentially, it also rejects resent chunks
+        if offset as usize != user_write_buffer.data.len() {
+            Err(CustomError::BadChunkOffset)?;
+        }
+
+        if user_write_buffer.data.len() + chunk.len() > user_write_buffer.size as usize {
+            Err(CustomError::MaxDataSizeIsExceeded)?;
+        }
+
+        user_write_buffer.data.extend_from_slice(&chunk);
+
+        Ok(())
+    }
+}
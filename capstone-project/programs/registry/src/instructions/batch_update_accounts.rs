@@ -0,0 +1,230 @@
+use {
+    anchor_lang::{prelude::*, AccountsClose},
+    base::{
+        error::AuthError,
+        helpers::{close_account_if_exists, get_clock_time},
+    },
+    registry_cpi::{
+        error::CustomError,
+        events::{AccountActivated, AccountForceClosed, AccountSuspended},
+        state::{
+            Bump, Config, RotationState, UserAccount, UserId, BATCH_SIZE_MAX, SEED_BUMP,
+            SEED_CONFIG, SEED_USER_ACCOUNT, SEED_USER_ID, SEED_USER_ROTATION_STATE,
+            SEED_USER_WRITE_BUFFER,
+        },
+        types::AssetItem,
+    },
+};
+
+#[derive(Accounts)]
+pub struct BatchUpdateAccounts<'info> {
+    pub sender: Signer<'info>,
+
+    // data storage
+    //
+    #[account(
+        seeds = [SEED_BUMP.as_bytes()],
+        bump
+    )]
+    pub bump: Account<'info, Bump>,
+
+    #[account(
+        seeds = [SEED_CONFIG.as_bytes()],
+        bump = bump.config
+    )]
+    pub config: Account<'info, Config>,
+}
+
+impl<'info> BatchUpdateAccounts<'info> {
+    /// remaining_accounts: [owner, user_id] for each user
+    pub fn batch_activate_accounts(
+        &mut self,
+        remaining_accounts: &'info [AccountInfo<'info>],
+    ) -> Result<()> {
+        let Self { sender, config, .. } = self;
+
+        // check sender
+        if sender.key() != config.admin {
+            Err(AuthError::Unauthorized)?;
+        }
+
+        let clock_time = get_clock_time()?;
+
+        for accounts in get_batch(remaining_accounts, 2)? {
+            let [owner, user_id] = accounts else {
+                return Err(CustomError::WrongRemainingAccounts.into());
+            };
+            let mut user_id = load_user_id(owner, user_id)?;
+
+            // only open account can be activated
+            if !user_id.is_open {
+                Err(CustomError::AccountIsNotOpened)?;
+            }
+
+            let is_renewal = user_id.is_activated;
+            user_id.is_activated = true;
+            user_id.extend_activation(clock_time, config.activation_period);
+            user_id.exit(&crate::ID)?;
+
+            // complimentary activation, fee isn't charged
+            emit!(AccountActivated {
+                id: user_id.id,
+                owner: owner.key(),
+                payer: sender.key(),
+                fee: AssetItem {
+                    amount: 0,
+                    asset: config.registration_fee.asset,
+                },
+                activated_until: user_id.activated_until,
+                is_renewal,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// remaining_accounts: [owner, user_id] for each user
+    pub fn batch_suspend_accounts(
+        &mut self,
+        remaining_accounts: &'info [AccountInfo<'info>],
+    ) -> Result<()> {
+        let Self { sender, config, .. } = self;
+
+        // check sender
+        if sender.key() != config.admin {
+            Err(AuthError::Unauthorized)?;
+        }
+
+        for accounts in get_batch(remaining_accounts, 2)? {
+            let [owner, user_id] = accounts else {
+                return Err(CustomError::WrongRemainingAccounts.into());
+            };
+            let mut user_id = load_user_id(owner, user_id)?;
+
+            // already suspended account is skipped to not fail the whole batch
+            if user_id.is_suspended {
+                continue;
+            }
+
+            user_id.is_suspended = true;
+            user_id.exit(&crate::ID)?;
+
+            emit!(AccountSuspended {
+                id: user_id.id,
+                owner: owner.key(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// remaining_accounts: [owner, user_id, user_account, user_rotation_state, user_write_buffer, rent_recipient] for each user \
+    /// rent is refunded to the owners or the sponsors, write buffer rent is refunded to the owners
+    pub fn batch_close_accounts(
+        &mut self,
+        remaining_accounts: &'info [AccountInfo<'info>],
+    ) -> Result<()> {
+        let Self { sender, config, .. } = self;
+
+        // check sender
+        if sender.key() != config.admin {
+            Err(AuthError::Unauthorized)?;
+        }
+
+        for accounts in get_batch(remaining_accounts, 6)? {
+            let [owner, user_id, user_account, user_rotation_state, user_write_buffer, rent_recipient] =
+                accounts
+            else {
+                return Err(CustomError::WrongRemainingAccounts.into());
+            };
+            let mut user_id = load_user_id(owner, user_id)?;
+
+            // already closed account is skipped to not fail the whole batch
+            if !user_id.is_open {
+                continue;
+            }
+
+            let id = user_id.id.to_le_bytes();
+            check_address(
+                user_account,
+                &[SEED_USER_ACCOUNT.as_bytes(), &id, &[user_id.account_bump]],
+            )?;
+            check_address(
+                user_rotation_state,
+                &[
+                    SEED_USER_ROTATION_STATE.as_bytes(),
+                    &id,
+                    &[user_id.rotation_state_bump],
+                ],
+            )?;
+
+            // the buffer bump isn't stored as the buffer is temporary
+            let (user_write_buffer_address, _) =
+                Pubkey::find_program_address(&[SEED_USER_WRITE_BUFFER.as_bytes(), &id], &crate::ID);
+            if user_write_buffer.key() != user_write_buffer_address {
+                Err(ErrorCode::ConstraintSeeds)?;
+            }
+
+            if rent_recipient.key() != user_id.get_rent_recipient(owner.key()) {
+                Err(ErrorCode::ConstraintAddress)?;
+            }
+
+            Account::<UserAccount>::try_from(user_account)?.close(rent_recipient.clone())?;
+            Account::<RotationState>::try_from(user_rotation_state)?
+                .close(rent_recipient.clone())?;
+            close_account_if_exists(user_write_buffer, owner, &crate::ID)?;
+
+            user_id.is_open = false;
+            user_id.exit(&crate::ID)?;
+
+            emit!(AccountForceClosed {
+                id: user_id.id,
+                owner: owner.key(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// splits remaining accounts into groups of the same size, one per user
+fn get_batch<'a, 'info>(
+    remaining_accounts: &'a [AccountInfo<'info>],
+    group_size: usize,
+) -> Result<std::slice::ChunksExact<'a, AccountInfo<'info>>> {
+    if remaining_accounts.len() % group_size != 0 {
+        Err(CustomError::WrongRemainingAccounts)?;
+    }
+
+    let batch_size = remaining_accounts.len() / group_size;
+    if batch_size == 0 || batch_size > BATCH_SIZE_MAX {
+        Err(CustomError::BatchSizeIsOutOfRange)?;
+    }
+
+    Ok(remaining_accounts.chunks_exact(group_size))
+}
+
+fn load_user_id<'info>(
+    owner: &AccountInfo<'info>,
+    user_id: &'info AccountInfo<'info>,
+) -> Result<Account<'info, UserId>> {
+    let (address, _) =
+        Pubkey::find_program_address(&[SEED_USER_ID.as_bytes(), owner.key().as_ref()], &crate::ID);
+
+    if user_id.key() != address {
+        Err(CustomError::WrongUserId)?;
+    }
+
+    Account::try_from(user_id)
+}
+
+fn check_address(account: &AccountInfo, seeds_with_bump: &[&[u8]]) -> Result<()> {
+    let address = Pubkey::create_program_address(seeds_with_bump, &crate::ID)
+        .map_err(|_| error!(ErrorCode::ConstraintSeeds))?;
+
+    if account.key() != address {
+        Err(ErrorCode::ConstraintSeeds)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,98 @@
+use {
+    anchor_lang::prelude::*,
+    base::{
+        helpers::get_clock_time,
+        pause::{is_paused, PAUSE_WRITE},
+    },
+    registry_cpi::{
+        error::CustomError,
+        state::{
+            Bump, Config, UserAccount, UserId, WriteBuffer, SEED_BUMP, SEED_CONFIG,
+            SEED_USER_ACCOUNT, SEED_USER_ID, SEED_USER_WRITE_BUFFER,
+        },
+    },
+};
+
+#[derive(Accounts)]
+#[instruction(data_size: u32)]
+pub struct BeginDataWrite<'info> {
+    pub system_program: Program<'info, System>,
+
+    #[account(mut)]
+    pub sender: Signer<'info>,
+
+    // data storage
+    //
+    #[account(
+        seeds = [SEED_BUMP.as_bytes()],
+        bump
+    )]
+    pub bump: Account<'info, Bump>,
+
+    #[account(
+        seeds = [SEED_CONFIG.as_bytes()],
+        bump = bump.config
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [SEED_USER_ID.as_bytes(), sender.key().as_ref()],
+        bump
+    )]
+    pub user_id: Account<'info, UserId>,
+
+    #[account(
+        seeds = [SEED_USER_ACCOUNT.as_bytes(), user_id.id.to_le_bytes().as_ref()],
+        bump = user_id.account_bump
+    )]
+    pub user_account: Account<'info, UserAccount>,
+
+    #[account(
+        init,
+        payer = sender,
+        space = WriteBuffer::get_space(data_size),
+        seeds = [SEED_USER_WRITE_BUFFER.as_bytes(), user_id.id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub user_write_buffer: Account<'info, WriteBuffer>,
+}
+
+impl<'info> BeginDataWrite<'info> {
+    pub fn begin_data_write(&mut self, data_size: u32) -> Result<()> {
+        let Self {
+            config,
+            user_id,
+            user_account,
+            user_write_buffer,
+            ..
+        } = self;
+
+        // don't allow write data in paused program
+        if is_paused(config.paused_ops, PAUSE_WRITE) {
+            Err(CustomError::ContractIsPaused)?;
+        }
+
+        if user_id.is_suspended {
+            Err(CustomError::AccountIsSuspended)?;
+        }
+
+        if !user_id.is_activated {
+            Err(CustomError::AccountIsNotActivated)?;
+        }
+
+        if user_id.is_activation_expired(get_clock_time()?) {
+            Err(CustomError::ActivationIsExpired)?;
+        }
+
+        if data_size > user_account.max_size {
+            Err(CustomError::MaxDataSizeIsExceeded)?;
+        }
+
+        user_write_buffer.set_inner(WriteBuffer {
+            data: Vec::with_capacity(data_size as usize),
+            size: data_size,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,56 @@
+use {
+    anchor_lang::prelude::*,
+    base::{error::AuthError, helpers::get_clock_time},
+    registry_cpi::{
+        events::AccountRotationCancelled,
+        state::{RotationState, UserId, SEED_USER_ID, SEED_USER_ROTATION_STATE},
+    },
+};
+
+#[derive(Accounts)]
+pub struct CancelAccountRotation<'info> {
+    pub sender: Signer<'info>,
+
+    // data storage
+    //
+    #[account(
+        seeds = [SEED_USER_ID.as_bytes(), sender.key().as_ref()],
+        bump
+    )]
+    pub user_id: Account<'info, UserId>,
+
+    #[account(
+        mut,
+        seeds = [SEED_USER_ROTATION_STATE.as_bytes(), user_id.id.to_le_bytes().as_ref()],
+        bump = user_id.rotation_state_bump
+    )]
+    pub user_rotation_state: Account<'info, RotationState>,
+}
+
+impl<'info> CancelAccountRotation<'info> {
+    pub fn cancel_account_rotation(&mut self) -> Result<()> {
+        let Self {
+            sender,
+            user_id,
+            user_rotation_state,
+            ..
+        } = self;
+
+        // both pending and expired rotations can be cancelled
+        let new_owner = user_rotation_state
+            .new_owner
+            .ok_or(AuthError::NoRotationToCancel)?;
+
+        user_rotation_state.new_owner = None;
+        user_rotation_state.expiration_date = get_clock_time()?;
+        user_rotation_state.clear_data = false;
+
+        emit!(AccountRotationCancelled {
+            id: user_id.id,
+            owner: sender.key(),
+            new_owner,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,65 @@
+use {
+    anchor_lang::prelude::*,
+    base::{error::AuthError, helpers::get_clock_time},
+    registry_cpi::{
+        events::AdminRotationCancelled,
+        state::{Bump, Config, RotationState, SEED_ADMIN_ROTATION_STATE, SEED_BUMP, SEED_CONFIG},
+    },
+};
+
+#[derive(Accounts)]
+pub struct CancelAdminRotation<'info> {
+    pub sender: Signer<'info>,
+
+    // data storage
+    //
+    #[account(
+        seeds = [SEED_BUMP.as_bytes()],
+        bump
+    )]
+    pub bump: Account<'info, Bump>,
+
+    #[account(
+        seeds = [SEED_CONFIG.as_bytes()],
+        bump = bump.config
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [SEED_ADMIN_ROTATION_STATE.as_bytes()],
+        bump = bump.rotation_state
+    )]
+    pub admin_rotation_state: Account<'info, RotationState>,
+}
+
+impl<'info> CancelAdminRotation<'info> {
+    pub fn cancel_admin_rotation(&mut self) -> Result<()> {
+        let Self {
+            sender,
+            config,
+            admin_rotation_state,
+            ..
+        } = self;
+
+        // check sender
+        if sender.key() != config.admin {
+            Err(AuthError::Unauthorized)?;
+        }
+
+        // both pending and expired rotations can be cancelled
+        let new_admin = admin_rotation_state
+            .new_owner
+            .ok_or(AuthError::NoRotationToCancel)?;
+
+        admin_rotation_state.new_owner = None;
+        admin_rotation_state.expiration_date = get_clock_time()?;
+
+        emit!(AdminRotationCancelled {
+            admin: sender.key(),
+            new_admin,
+        });
+
+        Ok(())
+    }
+}
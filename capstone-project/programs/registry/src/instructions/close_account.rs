@@ -1,10 +1,12 @@
 use {
     anchor_lang::prelude::*,
+    base::helpers::close_account_if_exists,
     registry_cpi::{
         error::CustomError,
+        events::AccountClosed,
         state::{
             RotationState, UserAccount, UserId, SEED_USER_ACCOUNT, SEED_USER_ID,
-            SEED_USER_ROTATION_STATE,
+            SEED_USER_ROTATION_STATE, SEED_USER_WRITE_BUFFER,
         },
     },
 };
@@ -16,6 +18,13 @@ pub struct CloseAccount<'info> {
     #[account(mut)]
     pub sender: Signer<'info>,
 
+    /// receives the rent, it's the sponsor if it reclaims rent or the sender otherwise
+    #[account(
+        mut,
+        address = user_id.get_rent_recipient(sender.key())
+    )]
+    pub rent_recipient: SystemAccount<'info>,
+
     // data storage
     //
     #[account(
@@ -27,7 +36,7 @@ pub struct CloseAccount<'info> {
 
     #[account(
         mut,
-        close = sender,
+        close = rent_recipient,
         seeds = [SEED_USER_ACCOUNT.as_bytes(), user_id.id.to_le_bytes().as_ref()],
         bump = user_id.account_bump
     )]
@@ -35,24 +44,44 @@ pub struct CloseAccount<'info> {
 
     #[account(
         mut,
-        close = sender,
+        close = rent_recipient,
         seeds = [SEED_USER_ROTATION_STATE.as_bytes(), user_id.id.to_le_bytes().as_ref()],
         bump = user_id.rotation_state_bump
     )]
     pub user_rotation_state: Account<'info, RotationState>,
+
+    /// CHECK: closed if uncommitted data write exists, its rent is refunded to the sender
+    #[account(
+        mut,
+        seeds = [SEED_USER_WRITE_BUFFER.as_bytes(), user_id.id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub user_write_buffer: UncheckedAccount<'info>,
 }
 
 impl<'info> CloseAccount<'info> {
     pub fn close_account(&mut self) -> Result<()> {
-        let Self { user_id, .. } = self;
+        let Self {
+            sender,
+            user_id,
+            user_write_buffer,
+            ..
+        } = self;
 
         // only open account can be closed
         if !user_id.is_open {
             Err(CustomError::AccountIsNotOpened)?;
         }
 
+        close_account_if_exists(user_write_buffer, sender, &crate::ID)?;
+
         user_id.is_open = false;
 
+        emit!(AccountClosed {
+            id: user_id.id,
+            owner: sender.key(),
+        });
+
         Ok(())
     }
 }
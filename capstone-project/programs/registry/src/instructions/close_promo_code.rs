@@ -0,0 +1,62 @@
+use {
+    anchor_lang::prelude::*,
+    base::error::AuthError,
+    registry_cpi::{
+        events::PromoCodeClosed,
+        state::{Bump, Config, PromoCode, SEED_BUMP, SEED_CONFIG, SEED_PROMO_CODE},
+    },
+};
+
+#[derive(Accounts)]
+#[instruction(code: String)]
+pub struct ClosePromoCode<'info> {
+    pub system_program: Program<'info, System>,
+
+    #[account(mut)]
+    pub sender: Signer<'info>,
+
+    // data storage
+    //
+    #[account(
+        seeds = [SEED_BUMP.as_bytes()],
+        bump
+    )]
+    pub bump: Account<'info, Bump>,
+
+    #[account(
+        seeds = [SEED_CONFIG.as_bytes()],
+        bump = bump.config
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        close = sender,
+        seeds = [SEED_PROMO_CODE.as_bytes(), PromoCode::get_seed(&code)?],
+        bump
+    )]
+    pub promo_code: Account<'info, PromoCode>,
+}
+
+impl<'info> ClosePromoCode<'info> {
+    pub fn close_promo_code(&mut self, code: String) -> Result<()> {
+        let Self {
+            sender,
+            config,
+            promo_code,
+            ..
+        } = self;
+
+        // check sender
+        if sender.key() != config.admin {
+            Err(AuthError::Unauthorized)?;
+        }
+
+        emit!(PromoCodeClosed {
+            code,
+            usage_count: promo_code.usage_count,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,127 @@
+use {
+    anchor_lang::prelude::*,
+    base::{
+        helpers::get_clock_time,
+        pause::{is_paused, PAUSE_WRITE},
+    },
+    registry_cpi::{
+        error::CustomError,
+        events::DataWritten,
+        state::{
+            Bump, Config, UserAccount, UserId, WriteBuffer, SEED_BUMP, SEED_CONFIG,
+            SEED_USER_ACCOUNT, SEED_USER_ID, SEED_USER_WRITE_BUFFER,
+        },
+        types::DataHeader,
+    },
+};
+
+#[derive(Accounts)]
+pub struct CommitDataWrite<'info> {
+    #[account(mut)]
+    pub sender: Signer<'info>,
+
+    // data storage
+    //
+    #[account(
+        seeds = [SEED_BUMP.as_bytes()],
+        bump
+    )]
+    pub bump: Account<'info, Bump>,
+
+    #[account(
+        seeds = [SEED_CONFIG.as_bytes()],
+        bump = bump.config
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [SEED_USER_ID.as_bytes(), sender.key().as_ref()],
+        bump
+    )]
+    pub user_id: Account<'info, UserId>,
+
+    #[account(
+        mut,
+        seeds = [SEED_USER_ACCOUNT.as_bytes(), user_id.id.to_le_bytes().as_ref()],
+        bump = user_id.account_bump
+    )]
+    pub user_account: Account<'info, UserAccount>,
+
+    #[account(
+        mut,
+        close = sender,
+        seeds = [SEED_USER_WRITE_BUFFER.as_bytes(), user_id.id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub user_write_buffer: Account<'info, WriteBuffer>,
+}
+
+impl<'info> CommitDataWrite<'info> {
+    pub fn commit_data_write(
+        &mut self,
+        nonce: u64,
+        expected_nonce: Option<u64>,
+        header: DataHeader,
+    ) -> Result<()> {
+        let Self {
+            sender,
+            config,
+            user_id,
+            user_account,
+            user_write_buffer,
+            ..
+        } = self;
+
+        // don't allow write data in paused program
+        if is_paused(config.paused_ops, PAUSE_WRITE) {
+            Err(CustomError::ContractIsPaused)?;
+        }
+
+        if user_id.is_suspended {
+            Err(CustomError::AccountIsSuspended)?;
+        }
+
+        if !user_id.is_activated {
+            Err(CustomError::AccountIsNotActivated)?;
+        }
+
+        if user_id.is_activation_expired(get_clock_time()?) {
+            Err(CustomError::ActivationIsExpired)?;
+        }
+
+        // data and nonce are swapped only when all chunks are received
+        if !user_write_buffer.is_complete() {
+            Err(CustomError::IncompleteDataWrite)?;
+        }
+
+        if user_write_buffer.data.len() > user_account.max_size as usize {
+            Err(CustomError::MaxDataSizeIsExceeded)?;
+        }
+
+        if nonce == user_account.nonce {
+            Err(CustomError::BadNonce)?;
+        }
+
+        // compare-and-swap to prevent overwriting data written by another device
+        if let Some(x) = expected_nonce {
+            if x != user_account.nonce {
+                Err(CustomError::NonceMismatch)?;
+            }
+        }
+
+        header.validate(&user_write_buffer.data)?;
+
+        user_account.data = std::mem::take(&mut user_write_buffer.data);
+        user_account.nonce = nonce;
+        user_account.header = header;
+
+        emit!(DataWritten {
+            id: user_id.id,
+            writer: sender.key(),
+            nonce,
+            length: user_account.data.len() as u32,
+        });
+
+        Ok(())
+    }
+}
@@ -3,8 +3,19 @@ use {
     base::{
         error::AuthError,
         helpers::{get_clock_time, get_space},
+        migration::Versioned,
+        pause::{is_paused, PAUSE_ROTATE},
+    },
+    registry_cpi::{
+        error::CustomError,
+        events::AccountRotationConfirmed,
+        state::{
+            Bump, Config, RotationState, UserAccount, UserId, UserIndexPage, SEED_BUMP,
+            SEED_CONFIG, SEED_USER_ACCOUNT, SEED_USER_ID, SEED_USER_INDEX_PAGE,
+            SEED_USER_ROTATION_STATE, SEED_USER_WRITE_BUFFER,
+        },
+        types::DataHeader,
     },
-    registry_cpi::state::{RotationState, UserId, SEED_USER_ID, SEED_USER_ROTATION_STATE},
 };
 
 #[derive(Accounts)]
@@ -16,6 +27,18 @@ pub struct ConfirmAccountRotation<'info> {
 
     // data storage
     //
+    #[account(
+        seeds = [SEED_BUMP.as_bytes()],
+        bump
+    )]
+    pub bump: Account<'info, Bump>,
+
+    #[account(
+        seeds = [SEED_CONFIG.as_bytes()],
+        bump = bump.config
+    )]
+    pub config: Account<'info, Config>,
+
     #[account(
         mut,
         close = sender,
@@ -39,18 +62,56 @@ pub struct ConfirmAccountRotation<'info> {
         bump = user_id_pre.rotation_state_bump
     )]
     pub user_rotation_state: Account<'info, RotationState>,
+
+    #[account(
+        mut,
+        seeds = [SEED_USER_ACCOUNT.as_bytes(), user_id_pre.id.to_le_bytes().as_ref()],
+        bump = user_id_pre.account_bump
+    )]
+    pub user_account: Account<'info, UserAccount>,
+
+    /// CHECK: only emptiness is checked, pending data of the previous owner can't be handed off
+    #[account(
+        seeds = [SEED_USER_WRITE_BUFFER.as_bytes(), user_id_pre.id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub user_write_buffer: UncheckedAccount<'info>,
+
+    // the page can be missing for accounts registered before the index was introduced
+    // parentheses keep the seed out of IDL PDA resolution as it's computed from account data
+    #[account(
+        init_if_needed,
+        payer = sender,
+        space = get_space(UserIndexPage::INIT_SPACE),
+        seeds = [SEED_USER_INDEX_PAGE.as_bytes(), &(UserIndexPage::get_page(user_id_pre.id).to_le_bytes())],
+        bump
+    )]
+    pub user_index_page: Account<'info, UserIndexPage>,
 }
 
 impl<'info> ConfirmAccountRotation<'info> {
     pub fn confirm_account_rotation(&mut self) -> Result<()> {
         let Self {
             sender,
+            config,
             user_id_pre,
             user_id,
             user_rotation_state,
+            user_account,
+            user_write_buffer,
+            user_index_page,
             ..
         } = self;
 
+        // don't allow rotate accounts in paused program
+        if is_paused(config.paused_ops, PAUSE_ROTATE) {
+            Err(CustomError::ContractIsPaused)?;
+        }
+
+        if user_id_pre.is_suspended {
+            Err(CustomError::AccountIsSuspended)?;
+        }
+
         let clock_time = get_clock_time()?;
 
         match user_rotation_state.new_owner {
@@ -64,12 +125,38 @@ impl<'info> ConfirmAccountRotation<'info> {
                     Err(AuthError::TransferOwnerDeadline)?;
                 }
 
+                let prev_owner = user_rotation_state.owner;
+                let clear_data = user_rotation_state.clear_data;
+
+                // previous owner's payload isn't exposed to the new owner, stored nonce
+                // is bumped to let clients know the data must be re-encrypted
+                if clear_data {
+                    if !user_write_buffer.data_is_empty() {
+                        Err(CustomError::IncompleteDataWrite)?;
+                    }
+
+                    user_account.data = Vec::default();
+                    user_account.nonce = user_account.nonce.wrapping_add(1);
+                    user_account.header = DataHeader::default();
+                }
+
                 user_id.set_inner(user_id_pre.clone().into_inner());
 
                 user_rotation_state.set_inner(RotationState {
                     owner: new_owner,
                     new_owner: None,
                     expiration_date: clock_time,
+                    clear_data: false,
+                    version: RotationState::VERSION,
+                });
+
+                user_index_page.set_owner(user_id.id, new_owner);
+
+                emit!(AccountRotationConfirmed {
+                    id: user_id.id,
+                    prev_owner,
+                    new_owner,
+                    clear_data,
                 });
             }
         }
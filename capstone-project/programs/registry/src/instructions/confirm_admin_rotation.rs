@@ -1,8 +1,9 @@
 use {
     anchor_lang::prelude::*,
-    base::{error::AuthError, helpers::get_clock_time},
-    registry_cpi::state::{
-        Bump, Config, RotationState, SEED_ADMIN_ROTATION_STATE, SEED_BUMP, SEED_CONFIG,
+    base::{error::AuthError, helpers::get_clock_time, migration::Versioned},
+    registry_cpi::{
+        events::AdminRotationConfirmed,
+        state::{Bump, Config, RotationState, SEED_ADMIN_ROTATION_STATE, SEED_BUMP, SEED_CONFIG},
     },
 };
 
@@ -55,12 +56,19 @@ impl<'info> ConfirmAdminRotation<'info> {
                     Err(AuthError::TransferOwnerDeadline)?;
                 }
 
+                emit!(AdminRotationConfirmed {
+                    prev_admin: config.admin,
+                    new_admin,
+                });
+
                 config.admin = new_admin;
 
                 admin_rotation_state.set_inner(RotationState {
                     owner: new_admin,
                     new_owner: None,
                     expiration_date: clock_time,
+                    clear_data: false,
+                    version: RotationState::VERSION,
                 });
             }
         }
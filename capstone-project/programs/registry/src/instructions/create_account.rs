@@ -1,12 +1,19 @@
 use {
     anchor_lang::prelude::*,
-    base::helpers::{get_clock_time, get_space},
+    base::{
+        helpers::{get_clock_time, get_space},
+        migration::Versioned,
+        pause::{is_paused, PAUSE_CREATE},
+    },
     registry_cpi::{
         error::CustomError,
+        events::AccountCreated,
         state::{
-            Bump, Config, RotationState, UserAccount, UserCounter, UserId, SEED_BUMP, SEED_CONFIG,
-            SEED_USER_ACCOUNT, SEED_USER_COUNTER, SEED_USER_ID, SEED_USER_ROTATION_STATE,
+            Bump, Config, RotationState, UserAccount, UserCounter, UserId, UserIndexPage,
+            SEED_BUMP, SEED_CONFIG, SEED_USER_ACCOUNT, SEED_USER_COUNTER, SEED_USER_ID,
+            SEED_USER_INDEX_PAGE, SEED_USER_ROTATION_STATE,
         },
+        types::DataHeader,
     },
 };
 
@@ -15,9 +22,12 @@ use {
 pub struct CreateAccount<'info> {
     pub system_program: Program<'info, System>,
 
-    #[account(mut)]
     pub sender: Signer<'info>,
 
+    /// pays rent, it's recorded as sponsor if it differs from the sender
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
     // data storage
     //
     #[account(
@@ -41,7 +51,7 @@ pub struct CreateAccount<'info> {
 
     #[account(
         init,
-        payer = sender,
+        payer = payer,
         space = get_space(UserId::INIT_SPACE),
         seeds = [SEED_USER_ID.as_bytes(), sender.key().as_ref()],
         bump
@@ -51,7 +61,7 @@ pub struct CreateAccount<'info> {
     // user_id.id doesn't exist yet, use user_counter.last_user_id + 1 instead
     #[account(
         init,
-        payer = sender,
+        payer = payer,
         space = UserAccount::get_space(max_data_size),
         seeds = [SEED_USER_ACCOUNT.as_bytes(), (user_counter.last_user_id + 1).to_le_bytes().as_ref()],
         bump
@@ -60,28 +70,44 @@ pub struct CreateAccount<'info> {
 
     #[account(
         init,
-        payer = sender,
+        payer = payer,
         space = get_space(RotationState::INIT_SPACE),
         seeds = [SEED_USER_ROTATION_STATE.as_bytes(), (user_counter.last_user_id + 1).to_le_bytes().as_ref()],
         bump
     )]
     pub user_rotation_state: Account<'info, RotationState>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = get_space(UserIndexPage::INIT_SPACE),
+        seeds = [SEED_USER_INDEX_PAGE.as_bytes(), UserIndexPage::get_page(user_counter.last_user_id + 1).to_le_bytes().as_ref()],
+        bump
+    )]
+    pub user_index_page: Account<'info, UserIndexPage>,
 }
 
 impl<'info> CreateAccount<'info> {
-    pub fn create_account(&mut self, bumps: CreateAccountBumps, max_data_size: u32) -> Result<()> {
+    pub fn create_account(
+        &mut self,
+        bumps: CreateAccountBumps,
+        max_data_size: u32,
+        is_rent_reclaimable: bool,
+    ) -> Result<()> {
         let Self {
             sender,
+            payer,
             config,
             user_counter,
             user_id,
             user_account,
             user_rotation_state,
+            user_index_page,
             ..
         } = self;
 
         // don't allow register accounts in paused program
-        if config.is_paused {
+        if is_paused(config.paused_ops, PAUSE_CREATE) {
             Err(CustomError::ContractIsPaused)?;
         }
 
@@ -91,6 +117,12 @@ impl<'info> CreateAccount<'info> {
             Err(CustomError::MaxDataSizeIsOutOfRange)?;
         }
 
+        let sponsor = if payer.key() != sender.key() {
+            Some(payer.key())
+        } else {
+            None
+        };
+
         let current_user_id = user_counter.last_user_id + 1;
         user_counter.last_user_id = current_user_id;
 
@@ -100,18 +132,35 @@ impl<'info> CreateAccount<'info> {
             is_activated: false,
             account_bump: bumps.user_account,
             rotation_state_bump: bumps.user_rotation_state,
+            activated_until: 0,
+            is_suspended: false,
+            version: UserId::VERSION,
+            sponsor,
+            is_rent_reclaimable: sponsor.is_some() && is_rent_reclaimable,
         });
 
         user_account.set_inner(UserAccount {
-            data: String::default(),
+            data: Vec::default(),
             nonce: 0,
             max_size: max_data_size,
+            header: DataHeader::default(),
         });
 
         user_rotation_state.set_inner(RotationState {
             owner: sender.key(),
             new_owner: None,
             expiration_date: get_clock_time()?,
+            clear_data: false,
+            version: RotationState::VERSION,
+        });
+
+        user_index_page.set_owner(current_user_id, sender.key());
+
+        emit!(AccountCreated {
+            id: current_user_id,
+            owner: sender.key(),
+            max_data_size,
+            sponsor,
         });
 
         Ok(())
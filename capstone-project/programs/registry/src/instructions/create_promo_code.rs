@@ -0,0 +1,103 @@
+use {
+    anchor_lang::prelude::*,
+    base::{
+        error::AuthError,
+        helpers::{get_clock_time, get_space},
+    },
+    registry_cpi::{
+        error::CustomError,
+        events::PromoCodeCreated,
+        state::{Bump, Config, PromoCode, BPS_MAX, SEED_BUMP, SEED_CONFIG, SEED_PROMO_CODE},
+    },
+};
+
+#[derive(Accounts)]
+#[instruction(code: String)]
+pub struct CreatePromoCode<'info> {
+    pub system_program: Program<'info, System>,
+
+    #[account(mut)]
+    pub sender: Signer<'info>,
+
+    // data storage
+    //
+    #[account(
+        seeds = [SEED_BUMP.as_bytes()],
+        bump
+    )]
+    pub bump: Account<'info, Bump>,
+
+    #[account(
+        seeds = [SEED_CONFIG.as_bytes()],
+        bump = bump.config
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = sender,
+        space = get_space(PromoCode::INIT_SPACE),
+        seeds = [SEED_PROMO_CODE.as_bytes(), PromoCode::get_seed(&code)?],
+        bump
+    )]
+    pub promo_code: Account<'info, PromoCode>,
+}
+
+impl<'info> CreatePromoCode<'info> {
+    pub fn create_promo_code(
+        &mut self,
+        code: String,
+        discount_bps: u16,
+        referrer: Option<Pubkey>,
+        referrer_share_bps: u16,
+        usage_limit: Option<u32>,
+        expiration_date: Option<u64>,
+    ) -> Result<()> {
+        let Self {
+            sender,
+            config,
+            promo_code,
+            ..
+        } = self;
+
+        // check sender
+        if sender.key() != config.admin {
+            Err(AuthError::Unauthorized)?;
+        }
+
+        if discount_bps > BPS_MAX || referrer_share_bps > BPS_MAX {
+            Err(CustomError::BpsIsOutOfRange)?;
+        }
+
+        if referrer.is_none() && referrer_share_bps != 0 {
+            Err(CustomError::NoReferrer)?;
+        }
+
+        if let Some(x) = expiration_date {
+            if x <= get_clock_time()? {
+                Err(CustomError::PromoCodeIsExpired)?;
+            }
+        }
+
+        promo_code.set_inner(PromoCode {
+            code: code.clone(),
+            discount_bps,
+            referrer,
+            referrer_share_bps,
+            usage_limit,
+            usage_count: 0,
+            expiration_date,
+        });
+
+        emit!(PromoCodeCreated {
+            code,
+            discount_bps,
+            referrer,
+            referrer_share_bps,
+            usage_limit,
+            expiration_date,
+        });
+
+        Ok(())
+    }
+}
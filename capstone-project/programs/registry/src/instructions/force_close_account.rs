@@ -0,0 +1,113 @@
+use {
+    anchor_lang::prelude::*,
+    base::{error::AuthError, helpers::close_account_if_exists},
+    registry_cpi::{
+        error::CustomError,
+        events::AccountForceClosed,
+        state::{
+            Bump, Config, RotationState, UserAccount, UserId, SEED_BUMP, SEED_CONFIG,
+            SEED_USER_ACCOUNT, SEED_USER_ID, SEED_USER_ROTATION_STATE, SEED_USER_WRITE_BUFFER,
+        },
+    },
+};
+
+#[derive(Accounts)]
+#[instruction(user: Pubkey)]
+pub struct ForceCloseAccount<'info> {
+    pub system_program: Program<'info, System>,
+
+    pub sender: Signer<'info>,
+
+    /// receives the rent, it's the sponsor if it reclaims rent or the owner otherwise
+    #[account(
+        mut,
+        address = user_id.get_rent_recipient(user)
+    )]
+    pub rent_recipient: SystemAccount<'info>,
+
+    /// receives the write buffer rent as it's always paid by the owner
+    #[account(
+        mut,
+        address = user
+    )]
+    pub owner: SystemAccount<'info>,
+
+    // data storage
+    //
+    #[account(
+        seeds = [SEED_BUMP.as_bytes()],
+        bump
+    )]
+    pub bump: Account<'info, Bump>,
+
+    #[account(
+        seeds = [SEED_CONFIG.as_bytes()],
+        bump = bump.config
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [SEED_USER_ID.as_bytes(), user.as_ref()],
+        bump
+    )]
+    pub user_id: Account<'info, UserId>,
+
+    #[account(
+        mut,
+        close = rent_recipient,
+        seeds = [SEED_USER_ACCOUNT.as_bytes(), user_id.id.to_le_bytes().as_ref()],
+        bump = user_id.account_bump
+    )]
+    pub user_account: Account<'info, UserAccount>,
+
+    #[account(
+        mut,
+        close = rent_recipient,
+        seeds = [SEED_USER_ROTATION_STATE.as_bytes(), user_id.id.to_le_bytes().as_ref()],
+        bump = user_id.rotation_state_bump
+    )]
+    pub user_rotation_state: Account<'info, RotationState>,
+
+    /// CHECK: closed if uncommitted data write exists
+    #[account(
+        mut,
+        seeds = [SEED_USER_WRITE_BUFFER.as_bytes(), user_id.id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub user_write_buffer: UncheckedAccount<'info>,
+}
+
+impl<'info> ForceCloseAccount<'info> {
+    pub fn force_close_account(&mut self, user: Pubkey) -> Result<()> {
+        let Self {
+            sender,
+            owner,
+            config,
+            user_id,
+            user_write_buffer,
+            ..
+        } = self;
+
+        // check sender
+        if sender.key() != config.admin {
+            Err(AuthError::Unauthorized)?;
+        }
+
+        // only open account can be closed
+        if !user_id.is_open {
+            Err(CustomError::AccountIsNotOpened)?;
+        }
+
+        close_account_if_exists(user_write_buffer, owner, &crate::ID)?;
+
+        user_id.is_open = false;
+
+        emit!(AccountForceClosed {
+            id: user_id.id,
+            owner: user,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,76 @@
+use {
+    anchor_lang::prelude::*,
+    base::helpers::{get_clock_time, get_space},
+    registry_cpi::{
+        error::CustomError,
+        events::DelegateGranted,
+        state::{UserDelegate, UserId, SEED_USER_DELEGATE, SEED_USER_ID},
+    },
+};
+
+#[derive(Accounts)]
+#[instruction(delegate: Pubkey)]
+pub struct GrantDelegate<'info> {
+    pub system_program: Program<'info, System>,
+
+    #[account(mut)]
+    pub sender: Signer<'info>,
+
+    // data storage
+    //
+    #[account(
+        seeds = [SEED_USER_ID.as_bytes(), sender.key().as_ref()],
+        bump
+    )]
+    pub user_id: Account<'info, UserId>,
+
+    #[account(
+        init_if_needed,
+        payer = sender,
+        space = get_space(UserDelegate::INIT_SPACE),
+        seeds = [SEED_USER_DELEGATE.as_bytes(), user_id.id.to_le_bytes().as_ref(), delegate.as_ref()],
+        bump
+    )]
+    pub user_delegate: Account<'info, UserDelegate>,
+}
+
+impl<'info> GrantDelegate<'info> {
+    pub fn grant_delegate(&mut self, delegate: Pubkey, expiration_date: Option<u64>) -> Result<()> {
+        let Self {
+            sender,
+            user_id,
+            user_delegate,
+            ..
+        } = self;
+
+        // only open account can be delegated
+        if !user_id.is_open {
+            Err(CustomError::AccountIsNotOpened)?;
+        }
+
+        if delegate == sender.key() {
+            Err(CustomError::SelfDelegation)?;
+        }
+
+        if let Some(x) = expiration_date {
+            if x <= get_clock_time()? {
+                Err(CustomError::BadDelegateExpirationDate)?;
+            }
+        }
+
+        // existing permission is overwritten
+        user_delegate.set_inner(UserDelegate {
+            owner: sender.key(),
+            expiration_date,
+        });
+
+        emit!(DelegateGranted {
+            id: user_id.id,
+            owner: sender.key(),
+            delegate,
+            expiration_date,
+        });
+
+        Ok(())
+    }
+}
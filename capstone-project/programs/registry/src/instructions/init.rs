@@ -7,12 +7,15 @@ use {
     base::{
         error::AuthError,
         helpers::{get_clock_time, get_space},
+        migration::Versioned,
+        pause::PAUSE_NONE,
     },
     registry_cpi::{
         state::{
-            Bump, Config, RotationState, UserCounter, ACCOUNT_DATA_SIZE_MAX, ACCOUNT_DATA_SIZE_MIN,
-            ACCOUNT_REGISTRATION_FEE_AMOUNT, ACCOUNT_REGISTRATION_FEE_ASSET, CLOCK_TIME_MIN,
-            MAINNET_ADMIN, ROTATION_TIMEOUT, SEED_ADMIN_ROTATION_STATE, SEED_BUMP, SEED_CONFIG,
+            Bump, Config, FeeWhitelist, RotationState, UserCounter, ACCOUNT_DATA_SIZE_MAX,
+            ACCOUNT_DATA_SIZE_MIN, ACCOUNT_REGISTRATION_FEE_AMOUNT, ACCOUNT_REGISTRATION_FEE_ASSET,
+            ACTIVATION_PERIOD, CLOCK_TIME_MIN, MAINNET_ADMIN, ROTATION_TIMEOUT,
+            SEED_ADMIN_ROTATION_STATE, SEED_BUMP, SEED_CONFIG, SEED_FEE_WHITELIST,
             SEED_USER_COUNTER,
         },
         types::{AssetItem, Range},
@@ -66,6 +69,15 @@ pub struct Init<'info> {
     )]
     pub admin_rotation_state: Account<'info, RotationState>,
 
+    #[account(
+        init,
+        payer = sender,
+        space = get_space(FeeWhitelist::INIT_SPACE),
+        seeds = [SEED_FEE_WHITELIST.as_bytes()],
+        bump
+    )]
+    pub fee_whitelist: Account<'info, FeeWhitelist>,
+
     // mint
     //
     pub revenue_mint: InterfaceAccount<'info, Mint>,
@@ -88,6 +100,7 @@ impl<'info> Init<'info> {
         rotation_timeout: Option<u32>,
         account_registration_fee: Option<AssetItem>,
         account_data_size_range: Option<Range>,
+        activation_period: Option<u32>,
     ) -> Result<()> {
         let clock_time = get_clock_time()?;
         let Self {
@@ -96,6 +109,7 @@ impl<'info> Init<'info> {
             config,
             user_counter,
             admin_rotation_state,
+            fee_whitelist,
             ..
         } = self;
 
@@ -108,11 +122,12 @@ impl<'info> Init<'info> {
             config: bumps.config,
             user_counter: bumps.user_counter,
             rotation_state: bumps.admin_rotation_state,
+            version: Bump::VERSION,
         });
 
         config.set_inner(Config {
             admin: sender.key(),
-            is_paused: false,
+            paused_ops: PAUSE_NONE,
             rotation_timeout: rotation_timeout.unwrap_or(ROTATION_TIMEOUT),
             registration_fee: account_registration_fee.unwrap_or(AssetItem {
                 amount: ACCOUNT_REGISTRATION_FEE_AMOUNT,
@@ -122,14 +137,19 @@ impl<'info> Init<'info> {
                 min: ACCOUNT_DATA_SIZE_MIN,
                 max: ACCOUNT_DATA_SIZE_MAX,
             }),
+            activation_period: activation_period.unwrap_or(ACTIVATION_PERIOD),
+            version: Config::VERSION,
         });
 
         user_counter.set_inner(UserCounter::default());
+        fee_whitelist.set_inner(FeeWhitelist::default());
 
         admin_rotation_state.set_inner(RotationState {
             owner: sender.key(),
             new_owner: None,
             expiration_date: clock_time,
+            clear_data: false,
+            version: RotationState::VERSION,
         });
 
         Ok(())
@@ -0,0 +1,141 @@
+use {
+    anchor_lang::prelude::*,
+    base::{
+        error::AuthError,
+        helpers::{get_space, DISCRIMINATOR_SPACE},
+        migration::{migrate_account, Versioned},
+    },
+    registry_cpi::{
+        events::AccountMigrated,
+        state::{
+            Bump, Config, FeeWhitelist, RotationState, UserId, SEED_ADMIN_ROTATION_STATE,
+            SEED_BUMP, SEED_CONFIG, SEED_FEE_WHITELIST,
+        },
+    },
+};
+
+#[derive(Accounts)]
+pub struct Migrate<'info> {
+    pub system_program: Program<'info, System>,
+
+    #[account(mut)]
+    pub sender: Signer<'info>,
+
+    // data storage
+    //
+    /// CHECK: legacy layout can't be deserialized as Bump, it's checked in the handler
+    #[account(
+        mut,
+        seeds = [SEED_BUMP.as_bytes()],
+        bump
+    )]
+    pub bump: UncheckedAccount<'info>,
+
+    /// CHECK: legacy layout can't be deserialized as Config, it's checked in the handler
+    #[account(
+        mut,
+        seeds = [SEED_CONFIG.as_bytes()],
+        bump
+    )]
+    pub config: UncheckedAccount<'info>,
+
+    /// CHECK: legacy layout can't be deserialized as RotationState, it's checked in the handler
+    #[account(
+        mut,
+        seeds = [SEED_ADMIN_ROTATION_STATE.as_bytes()],
+        bump
+    )]
+    pub admin_rotation_state: UncheckedAccount<'info>,
+
+    // created for programs initialized before the whitelist was introduced as activation requires it
+    #[account(
+        init_if_needed,
+        payer = sender,
+        space = get_space(FeeWhitelist::INIT_SPACE),
+        seeds = [SEED_FEE_WHITELIST.as_bytes()],
+        bump
+    )]
+    pub fee_whitelist: Account<'info, FeeWhitelist>,
+}
+
+impl<'info> Migrate<'info> {
+    /// remaining_accounts: UserId and user RotationState accounts to migrate
+    pub fn migrate(&mut self, remaining_accounts: &[AccountInfo<'info>]) -> Result<()> {
+        let Self {
+            system_program,
+            sender,
+            bump,
+            config,
+            admin_rotation_state,
+            ..
+        } = self;
+
+        // check sender, admin is the first field in any Config layout version
+        let admin = {
+            let data = config.try_borrow_data()?;
+
+            if !data.starts_with(Config::DISCRIMINATOR) {
+                Err(ErrorCode::AccountDiscriminatorMismatch)?;
+            }
+
+            Pubkey::deserialize(&mut &data[DISCRIMINATOR_SPACE..])?
+        };
+
+        if sender.key() != admin {
+            Err(AuthError::Unauthorized)?;
+        }
+
+        let bump = bump.to_account_info();
+        if migrate_account::<Bump>(&bump, sender, system_program, &crate::ID)? {
+            emit!(AccountMigrated {
+                address: bump.key(),
+                version: Bump::VERSION,
+            });
+        }
+
+        let config = config.to_account_info();
+        if migrate_account::<Config>(&config, sender, system_program, &crate::ID)? {
+            emit!(AccountMigrated {
+                address: config.key(),
+                version: Config::VERSION,
+            });
+        }
+
+        let admin_rotation_state = admin_rotation_state.to_account_info();
+        if migrate_account::<RotationState>(
+            &admin_rotation_state,
+            sender,
+            system_program,
+            &crate::ID,
+        )? {
+            emit!(AccountMigrated {
+                address: admin_rotation_state.key(),
+                version: RotationState::VERSION,
+            });
+        }
+
+        for account in remaining_accounts {
+            // account type is recognized by its discriminator
+            let is_rotation_state = account
+                .try_borrow_data()?
+                .starts_with(RotationState::DISCRIMINATOR);
+
+            let version = if is_rotation_state {
+                migrate_account::<RotationState>(account, sender, system_program, &crate::ID)?
+                    .then_some(RotationState::VERSION)
+            } else {
+                migrate_account::<UserId>(account, sender, system_program, &crate::ID)?
+                    .then_some(UserId::VERSION)
+            };
+
+            if let Some(version) = version {
+                emit!(AccountMigrated {
+                    address: account.key(),
+                    version,
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
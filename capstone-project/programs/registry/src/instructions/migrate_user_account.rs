@@ -0,0 +1,78 @@
+use {
+    anchor_lang::prelude::*,
+    base::helpers::transfer_sol_from_user,
+    registry_cpi::{
+        error::CustomError,
+        state::{UserAccount, UserId, SEED_USER_ACCOUNT, SEED_USER_ID},
+    },
+};
+
+#[derive(Accounts)]
+pub struct MigrateUserAccount<'info> {
+    pub system_program: Program<'info, System>,
+
+    #[account(mut)]
+    pub sender: Signer<'info>,
+
+    // data storage
+    //
+    #[account(
+        seeds = [SEED_USER_ID.as_bytes(), sender.key().as_ref()],
+        bump
+    )]
+    pub user_id: Account<'info, UserId>,
+
+    /// CHECK: legacy layout can't be deserialized as UserAccount, owner and discriminator are checked in the handler
+    #[account(
+        mut,
+        seeds = [SEED_USER_ACCOUNT.as_bytes(), user_id.id.to_le_bytes().as_ref()],
+        bump = user_id.account_bump,
+        owner = crate::ID
+    )]
+    pub user_account: UncheckedAccount<'info>,
+}
+
+impl<'info> MigrateUserAccount<'info> {
+    pub fn migrate_user_account(&mut self) -> Result<()> {
+        let Self {
+            system_program,
+            sender,
+            user_account,
+            ..
+        } = self;
+
+        let account_info = user_account.to_account_info();
+        let max_size = {
+            let account_data = account_info.try_borrow_data()?;
+
+            if !account_data.starts_with(UserAccount::DISCRIMINATOR) {
+                Err(ErrorCode::AccountDiscriminatorMismatch)?;
+            }
+
+            UserAccount::read_max_size(&account_data).ok_or(ErrorCode::AccountDidNotDeserialize)?
+        };
+
+        // accounts created after the header was introduced already have enough space
+        let space = UserAccount::get_space(max_size);
+        if account_info.data_len() >= space {
+            Err(CustomError::NothingToMigrate)?;
+        }
+
+        let rent_exempt_pre = account_info.lamports();
+        let rent_exempt = Rent::get()?.minimum_balance(space);
+
+        if rent_exempt > rent_exempt_pre {
+            transfer_sol_from_user(
+                rent_exempt - rent_exempt_pre,
+                sender,
+                &account_info,
+                system_program,
+            )?;
+        }
+
+        // zeroed header is parsed as untagged data
+        account_info.realloc(space, true)?;
+
+        Ok(())
+    }
+}
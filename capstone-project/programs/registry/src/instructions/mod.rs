@@ -1,23 +1,59 @@
+pub mod abort_data_write;
 pub mod activate_account;
+pub mod activate_account_with_sol;
+pub mod append_data_chunk;
+pub mod batch_update_accounts;
+pub mod begin_data_write;
+pub mod cancel_account_rotation;
+pub mod cancel_admin_rotation;
 pub mod close_account;
+pub mod close_promo_code;
+pub mod commit_data_write;
 pub mod confirm_account_rotation;
 pub mod confirm_admin_rotation;
 pub mod create_account;
+pub mod create_promo_code;
+pub mod force_close_account;
+pub mod grant_delegate;
 pub mod init;
+pub mod migrate;
+pub mod migrate_user_account;
 pub mod reopen_account;
 pub mod request_account_rotation;
+pub mod resize_account;
+pub mod revoke_delegate;
+pub mod suspend_account;
 pub mod update_config;
 pub mod withdraw_revenue;
+pub mod withdraw_revenue_with_sol;
 pub mod write_data;
 
+pub use abort_data_write::*;
 pub use activate_account::*;
+pub use activate_account_with_sol::*;
+pub use append_data_chunk::*;
+pub use batch_update_accounts::*;
+pub use begin_data_write::*;
+pub use cancel_account_rotation::*;
+pub use cancel_admin_rotation::*;
 pub use close_account::*;
+pub use close_promo_code::*;
+pub use commit_data_write::*;
 pub use confirm_account_rotation::*;
 pub use confirm_admin_rotation::*;
 pub use create_account::*;
+pub use create_promo_code::*;
+pub use force_close_account::*;
+pub use grant_delegate::*;
 pub use init::*;
+pub use migrate::*;
+pub use migrate_user_account::*;
 pub use reopen_account::*;
 pub use request_account_rotation::*;
+pub use resize_account::*;
+pub use revoke_delegate::*;
+pub use suspend_account::*;
 pub use update_config::*;
 pub use withdraw_revenue::*;
+pub use withdraw_revenue_with_sol::*;
 pub use write_data::*;
@@ -1,12 +1,18 @@
 use {
     anchor_lang::prelude::*,
-    base::helpers::{get_clock_time, get_space},
+    base::{
+        helpers::{get_clock_time, get_space},
+        migration::Versioned,
+        pause::{is_paused, PAUSE_CREATE},
+    },
     registry_cpi::{
         error::CustomError,
+        events::AccountReopened,
         state::{
             Bump, Config, RotationState, UserAccount, UserId, SEED_BUMP, SEED_CONFIG,
             SEED_USER_ACCOUNT, SEED_USER_ID, SEED_USER_ROTATION_STATE,
         },
+        types::DataHeader,
     },
 };
 
@@ -69,6 +75,11 @@ impl<'info> ReopenAccount<'info> {
             ..
         } = self;
 
+        // don't allow reopen accounts in paused program
+        if is_paused(config.paused_ops, PAUSE_CREATE) {
+            Err(CustomError::ContractIsPaused)?;
+        }
+
         // only closed account can be open
         if user_id.is_open {
             Err(CustomError::OpenAccountTwice)?;
@@ -81,17 +92,28 @@ impl<'info> ReopenAccount<'info> {
         }
 
         user_id.is_open = true;
+        // rent is paid by the owner now
+        user_id.is_rent_reclaimable = false;
 
         user_account.set_inner(UserAccount {
-            data: String::default(),
+            data: Vec::default(),
             nonce: 0,
             max_size: max_data_size,
+            header: DataHeader::default(),
         });
 
         user_rotation_state.set_inner(RotationState {
             owner: sender.key(),
             new_owner: None,
             expiration_date: get_clock_time()?,
+            clear_data: false,
+            version: RotationState::VERSION,
+        });
+
+        emit!(AccountReopened {
+            id: user_id.id,
+            owner: sender.key(),
+            max_data_size,
         });
 
         Ok(())
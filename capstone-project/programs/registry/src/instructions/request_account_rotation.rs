@@ -1,9 +1,17 @@
 use {
     anchor_lang::prelude::*,
-    base::{error::AuthError, helpers::get_clock_time},
-    registry_cpi::state::{
-        Bump, Config, RotationState, UserId, SEED_BUMP, SEED_CONFIG, SEED_USER_ID,
-        SEED_USER_ROTATION_STATE,
+    base::{
+        error::AuthError,
+        helpers::get_clock_time,
+        pause::{is_paused, PAUSE_ROTATE},
+    },
+    registry_cpi::{
+        error::CustomError,
+        events::AccountRotationRequested,
+        state::{
+            Bump, Config, RotationState, UserId, SEED_BUMP, SEED_CONFIG, SEED_USER_ID,
+            SEED_USER_ROTATION_STATE,
+        },
     },
 };
 
@@ -40,20 +48,39 @@ pub struct RequestAccountRotation<'info> {
 }
 
 impl<'info> RequestAccountRotation<'info> {
-    pub fn request_account_rotation(&mut self, new_owner: Pubkey) -> Result<()> {
+    pub fn request_account_rotation(&mut self, new_owner: Pubkey, clear_data: bool) -> Result<()> {
         let Self {
             sender,
-            user_rotation_state,
             config,
+            user_id,
+            user_rotation_state,
             ..
         } = self;
 
+        // don't allow rotate accounts in paused program
+        if is_paused(config.paused_ops, PAUSE_ROTATE) {
+            Err(CustomError::ContractIsPaused)?;
+        }
+
+        if user_id.is_suspended {
+            Err(CustomError::AccountIsSuspended)?;
+        }
+
         if new_owner == sender.key() {
             Err(AuthError::UselessRotation)?;
         }
 
         user_rotation_state.new_owner = Some(new_owner);
         user_rotation_state.expiration_date = get_clock_time()? + config.rotation_timeout as u64;
+        user_rotation_state.clear_data = clear_data;
+
+        emit!(AccountRotationRequested {
+            id: user_id.id,
+            owner: sender.key(),
+            new_owner,
+            expiration_date: user_rotation_state.expiration_date,
+            clear_data,
+        });
 
         Ok(())
     }
@@ -0,0 +1,128 @@
+use {
+    anchor_lang::prelude::*,
+    base::{
+        helpers::transfer_sol_from_user,
+        pause::{is_paused, PAUSE_WRITE},
+    },
+    registry_cpi::{
+        error::CustomError,
+        events::AccountResized,
+        state::{
+            Bump, Config, UserAccount, UserId, SEED_BUMP, SEED_CONFIG, SEED_USER_ACCOUNT,
+            SEED_USER_ID,
+        },
+    },
+};
+
+#[derive(Accounts)]
+pub struct ResizeAccount<'info> {
+    pub system_program: Program<'info, System>,
+
+    #[account(mut)]
+    pub sender: Signer<'info>,
+
+    /// pays rent on growing and receives redeemed rent on shrinking to keep the account rent \
+    /// refundable to the party that paid it, it's the sponsor if it reclaims rent or the sender otherwise
+    #[account(
+        mut,
+        address = user_id.get_rent_recipient(sender.key())
+    )]
+    pub rent_payer: Signer<'info>,
+
+    // data storage
+    //
+    #[account(
+        seeds = [SEED_BUMP.as_bytes()],
+        bump
+    )]
+    pub bump: Account<'info, Bump>,
+
+    #[account(
+        seeds = [SEED_CONFIG.as_bytes()],
+        bump = bump.config
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [SEED_USER_ID.as_bytes(), sender.key().as_ref()],
+        bump
+    )]
+    pub user_id: Account<'info, UserId>,
+
+    #[account(
+        mut,
+        seeds = [SEED_USER_ACCOUNT.as_bytes(), user_id.id.to_le_bytes().as_ref()],
+        bump = user_id.account_bump
+    )]
+    pub user_account: Account<'info, UserAccount>,
+}
+
+impl<'info> ResizeAccount<'info> {
+    pub fn resize_account(&mut self, max_data_size: u32) -> Result<()> {
+        let Self {
+            system_program,
+            sender,
+            rent_payer,
+            config,
+            user_id,
+            user_account,
+            ..
+        } = self;
+
+        // don't allow resize data storage in paused program
+        if is_paused(config.paused_ops, PAUSE_WRITE) {
+            Err(CustomError::ContractIsPaused)?;
+        }
+
+        if user_id.is_suspended {
+            Err(CustomError::AccountIsSuspended)?;
+        }
+
+        // only open account can be resized
+        if !user_id.is_open {
+            Err(CustomError::AccountIsNotOpened)?;
+        }
+
+        // validate max allocated data size
+        if max_data_size < config.data_size_range.min || max_data_size > config.data_size_range.max
+        {
+            Err(CustomError::MaxDataSizeIsOutOfRange)?;
+        }
+
+        // existing data must be preserved
+        if user_account.data.len() > max_data_size as usize {
+            Err(CustomError::DataDoesNotFit)?;
+        }
+
+        let account_info = user_account.to_account_info();
+        let rent_exempt_pre = account_info.lamports();
+        let rent_exempt = Rent::get()?.minimum_balance(UserAccount::get_space(max_data_size));
+
+        if rent_exempt > rent_exempt_pre {
+            transfer_sol_from_user(
+                rent_exempt - rent_exempt_pre,
+                rent_payer,
+                &account_info,
+                system_program,
+            )?;
+        }
+
+        account_info.realloc(UserAccount::get_space(max_data_size), false)?;
+
+        // system program can't transfer from accounts with data, redeem rent directly
+        if rent_exempt_pre > rent_exempt {
+            user_account.sub_lamports(rent_exempt_pre - rent_exempt)?;
+            rent_payer.add_lamports(rent_exempt_pre - rent_exempt)?;
+        }
+
+        user_account.max_size = max_data_size;
+
+        emit!(AccountResized {
+            id: user_id.id,
+            owner: sender.key(),
+            max_data_size,
+        });
+
+        Ok(())
+    }
+}
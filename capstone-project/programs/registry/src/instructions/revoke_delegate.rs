@@ -0,0 +1,49 @@
+use {
+    anchor_lang::prelude::*,
+    registry_cpi::{
+        events::DelegateRevoked,
+        state::{UserDelegate, UserId, SEED_USER_DELEGATE, SEED_USER_ID},
+    },
+};
+
+#[derive(Accounts)]
+#[instruction(delegate: Pubkey)]
+pub struct RevokeDelegate<'info> {
+    pub system_program: Program<'info, System>,
+
+    #[account(mut)]
+    pub sender: Signer<'info>,
+
+    // data storage
+    //
+    #[account(
+        seeds = [SEED_USER_ID.as_bytes(), sender.key().as_ref()],
+        bump
+    )]
+    pub user_id: Account<'info, UserId>,
+
+    // current owner can revoke permissions granted by previous one
+    #[account(
+        mut,
+        close = sender,
+        seeds = [SEED_USER_DELEGATE.as_bytes(), user_id.id.to_le_bytes().as_ref(), delegate.as_ref()],
+        bump
+    )]
+    pub user_delegate: Account<'info, UserDelegate>,
+}
+
+impl<'info> RevokeDelegate<'info> {
+    pub fn revoke_delegate(&mut self, delegate: Pubkey) -> Result<()> {
+        let Self {
+            sender, user_id, ..
+        } = self;
+
+        emit!(DelegateRevoked {
+            id: user_id.id,
+            owner: sender.key(),
+            delegate,
+        });
+
+        Ok(())
+    }
+}
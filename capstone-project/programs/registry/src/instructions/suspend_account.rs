@@ -0,0 +1,92 @@
+use {
+    anchor_lang::prelude::*,
+    base::error::AuthError,
+    registry_cpi::{
+        error::CustomError,
+        events::{AccountSuspended, AccountUnsuspended},
+        state::{Bump, Config, UserId, SEED_BUMP, SEED_CONFIG, SEED_USER_ID},
+    },
+};
+
+#[derive(Accounts)]
+#[instruction(user: Pubkey)]
+pub struct SuspendAccount<'info> {
+    pub sender: Signer<'info>,
+
+    // data storage
+    //
+    #[account(
+        seeds = [SEED_BUMP.as_bytes()],
+        bump
+    )]
+    pub bump: Account<'info, Bump>,
+
+    #[account(
+        seeds = [SEED_CONFIG.as_bytes()],
+        bump = bump.config
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [SEED_USER_ID.as_bytes(), user.as_ref()],
+        bump
+    )]
+    pub user_id: Account<'info, UserId>,
+}
+
+impl<'info> SuspendAccount<'info> {
+    pub fn suspend_account(&mut self, user: Pubkey) -> Result<()> {
+        let Self {
+            sender,
+            config,
+            user_id,
+            ..
+        } = self;
+
+        // check sender
+        if sender.key() != config.admin {
+            Err(AuthError::Unauthorized)?;
+        }
+
+        if user_id.is_suspended {
+            Err(CustomError::SuspendAccountTwice)?;
+        }
+
+        user_id.is_suspended = true;
+
+        emit!(AccountSuspended {
+            id: user_id.id,
+            owner: user,
+        });
+
+        Ok(())
+    }
+
+    pub fn unsuspend_account(&mut self, user: Pubkey) -> Result<()> {
+        let Self {
+            sender,
+            config,
+            user_id,
+            ..
+        } = self;
+
+        // check sender
+        if sender.key() != config.admin {
+            Err(AuthError::Unauthorized)?;
+        }
+
+        if !user_id.is_suspended {
+            Err(CustomError::AccountIsNotSuspended)?;
+        }
+
+        user_id.is_suspended = false;
+
+        emit!(AccountUnsuspended {
+            id: user_id.id,
+            owner: user,
+        });
+
+        Ok(())
+    }
+}
@@ -1,15 +1,25 @@
 use {
     anchor_lang::prelude::*,
-    base::{error::AuthError, helpers::get_clock_time},
+    base::{
+        error::AuthError,
+        helpers::{get_clock_time, get_space, has_duplicates},
+    },
     registry_cpi::{
         error::CustomError,
-        state::{Bump, Config, RotationState, SEED_ADMIN_ROTATION_STATE, SEED_BUMP, SEED_CONFIG},
-        types::Range,
+        events::{AdminRotationRequested, ConfigUpdated},
+        state::{
+            Bump, Config, FeeWhitelist, RotationState, FEE_WHITELIST_MAX_LEN,
+            SEED_ADMIN_ROTATION_STATE, SEED_BUMP, SEED_CONFIG, SEED_FEE_WHITELIST,
+        },
+        types::UpdateConfigArgs,
     },
 };
 
 #[derive(Accounts)]
 pub struct UpdateConfig<'info> {
+    pub system_program: Program<'info, System>,
+
+    #[account(mut)]
     pub sender: Signer<'info>,
 
     // data storage
@@ -33,21 +43,35 @@ pub struct UpdateConfig<'info> {
         bump = bump.rotation_state
     )]
     pub admin_rotation_state: Account<'info, RotationState>,
+
+    // created on demand for programs initialized before the whitelist was introduced
+    #[account(
+        init_if_needed,
+        payer = sender,
+        space = get_space(FeeWhitelist::INIT_SPACE),
+        seeds = [SEED_FEE_WHITELIST.as_bytes()],
+        bump
+    )]
+    pub fee_whitelist: Account<'info, FeeWhitelist>,
 }
 
 impl<'info> UpdateConfig<'info> {
-    pub fn update_config(
-        &mut self,
-        admin: Option<Pubkey>,
-        is_paused: Option<bool>,
-        rotation_timeout: Option<u32>,
-        registration_fee_amount: Option<u64>,
-        data_size_range: Option<Range>,
-    ) -> Result<()> {
+    pub fn update_config(&mut self, args: UpdateConfigArgs) -> Result<()> {
+        let UpdateConfigArgs {
+            admin,
+            paused_ops,
+            rotation_timeout,
+            registration_fee_amount,
+            data_size_range,
+            fee_whitelist,
+            activation_period,
+        } = args;
+
         let Self {
             sender,
             config,
             admin_rotation_state,
+            fee_whitelist: fee_whitelist_account,
             ..
         } = self;
 
@@ -67,10 +91,16 @@ impl<'info> UpdateConfig<'info> {
             admin_rotation_state.expiration_date =
                 get_clock_time()? + config.rotation_timeout as u64;
             is_config_updated = true;
+
+            emit!(AdminRotationRequested {
+                admin: sender.key(),
+                new_admin,
+                expiration_date: admin_rotation_state.expiration_date,
+            });
         }
 
-        if let Some(x) = is_paused {
-            config.is_paused = x;
+        if let Some(x) = paused_ops {
+            config.paused_ops = x;
             is_config_updated = true;
         }
 
@@ -89,11 +119,39 @@ impl<'info> UpdateConfig<'info> {
             is_config_updated = true;
         }
 
+        if let Some(x) = activation_period {
+            config.activation_period = x;
+            is_config_updated = true;
+        }
+
+        if let Some(x) = fee_whitelist {
+            if x.len() > FEE_WHITELIST_MAX_LEN {
+                Err(CustomError::FeeWhitelistIsTooLong)?;
+            }
+
+            // registration fee asset can't be overridden by the whitelist
+            let assets: Vec<Pubkey> = std::iter::once(config.registration_fee.asset)
+                .chain(x.iter().map(|y| y.asset))
+                .collect();
+
+            if has_duplicates(&assets) {
+                Err(CustomError::FeeAssetDuplication)?;
+            }
+
+            fee_whitelist_account.value = x;
+            is_config_updated = true;
+        }
+
         // don't allow empty instructions
         if !is_config_updated {
             Err(CustomError::NoParameters)?;
         }
 
+        emit!(ConfigUpdated {
+            config: config.clone().into_inner(),
+            fee_whitelist: fee_whitelist_account.value.clone(),
+        });
+
         Ok(())
     }
 }
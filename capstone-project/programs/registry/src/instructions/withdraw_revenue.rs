@@ -7,6 +7,7 @@ use {
     base::{error::AuthError, helpers::transfer_token_from_program},
     registry_cpi::{
         error::CustomError,
+        events::RevenueWithdrawn,
         state::{Bump, Config, SEED_BUMP, SEED_CONFIG},
     },
 };
@@ -39,6 +40,7 @@ pub struct WithdrawRevenue<'info> {
 
     // mint
     //
+    // any mint held by the config can be withdrawn including previous fee assets and stray deposits
     pub revenue_mint: InterfaceAccount<'info, Mint>,
 
     // ata
@@ -64,6 +66,7 @@ impl<'info> WithdrawRevenue<'info> {
         let Self {
             token_program,
             sender,
+            recipient,
             bump,
             config,
             revenue_mint,
@@ -77,11 +80,6 @@ impl<'info> WithdrawRevenue<'info> {
             Err(AuthError::Unauthorized)?;
         }
 
-        // validate fee token
-        if revenue_mint.key() != config.registration_fee.asset {
-            Err(CustomError::WrongAssetType)?;
-        }
-
         let amount = amount.unwrap_or(revenue_app_ata.amount);
 
         // lower limit of amount to withdraw
@@ -105,6 +103,12 @@ impl<'info> WithdrawRevenue<'info> {
             token_program,
         )?;
 
+        emit!(RevenueWithdrawn {
+            asset: revenue_mint.key(),
+            amount,
+            recipient: recipient.key(),
+        });
+
         Ok(())
     }
 }
@@ -0,0 +1,80 @@
+use {
+    anchor_lang::prelude::*,
+    base::error::AuthError,
+    registry_cpi::{
+        error::CustomError,
+        events::RevenueWithdrawn,
+        state::{Bump, Config, NATIVE_SOL_ASSET, SEED_BUMP, SEED_CONFIG},
+    },
+};
+
+#[derive(Accounts)]
+pub struct WithdrawRevenueWithSol<'info> {
+    pub system_program: Program<'info, System>,
+
+    #[account(mut)]
+    pub sender: Signer<'info>,
+
+    // handle the option on client
+    #[account(mut)]
+    pub recipient: SystemAccount<'info>,
+
+    // data storage
+    //
+    #[account(
+        seeds = [SEED_BUMP.as_bytes()],
+        bump
+    )]
+    pub bump: Account<'info, Bump>,
+
+    #[account(
+        mut,
+        seeds = [SEED_CONFIG.as_bytes()],
+        bump = bump.config
+    )]
+    pub config: Account<'info, Config>,
+}
+
+impl<'info> WithdrawRevenueWithSol<'info> {
+    pub fn withdraw_revenue_with_sol(&mut self, amount: Option<u64>) -> Result<()> {
+        let Self {
+            sender,
+            recipient,
+            config,
+            ..
+        } = self;
+
+        // check sender
+        if sender.key() != config.admin {
+            Err(AuthError::Unauthorized)?;
+        }
+
+        // only excess above rent exempt is available
+        let account_info = config.to_account_info();
+        let rent_exempt = Rent::get()?.minimum_balance(account_info.data_len());
+        let available_amount = account_info.lamports().saturating_sub(rent_exempt);
+        let amount = amount.unwrap_or(available_amount);
+
+        // lower limit of amount to withdraw
+        if amount == 0 {
+            Err(CustomError::ZeroAmount)?;
+        }
+
+        // higher limit of amount to withdraw
+        if amount > available_amount {
+            Err(CustomError::ExceededAvailableAssetAmount)?;
+        }
+
+        // system program can't transfer from accounts with data, send lamports directly
+        config.sub_lamports(amount)?;
+        recipient.add_lamports(amount)?;
+
+        emit!(RevenueWithdrawn {
+            asset: NATIVE_SOL_ASSET,
+            amount,
+            recipient: recipient.key(),
+        });
+
+        Ok(())
+    }
+}
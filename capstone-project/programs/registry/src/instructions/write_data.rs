@@ -1,19 +1,43 @@
 use {
     anchor_lang::prelude::*,
+    base::{
+        error::AuthError,
+        helpers::get_clock_time,
+        pause::{is_paused, PAUSE_WRITE},
+    },
     registry_cpi::{
         error::CustomError,
-        state::{UserAccount, UserId, SEED_USER_ACCOUNT, SEED_USER_ID},
+        events::DataWritten,
+        state::{
+            Bump, Config, UserAccount, UserDelegate, UserId, SEED_BUMP, SEED_CONFIG,
+            SEED_USER_ACCOUNT, SEED_USER_DELEGATE, SEED_USER_ID,
+        },
+        types::DataHeader,
     },
 };
 
 #[derive(Accounts)]
+#[instruction(user: Pubkey)]
 pub struct WriteData<'info> {
+    /// the owner or its delegate
     pub sender: Signer<'info>,
 
     // data storage
     //
     #[account(
-        seeds = [SEED_USER_ID.as_bytes(), sender.key().as_ref()],
+        seeds = [SEED_BUMP.as_bytes()],
+        bump
+    )]
+    pub bump: Account<'info, Bump>,
+
+    #[account(
+        seeds = [SEED_CONFIG.as_bytes()],
+        bump = bump.config
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [SEED_USER_ID.as_bytes(), user.as_ref()],
         bump
     )]
     pub user_id: Account<'info, UserId>,
@@ -24,30 +48,101 @@ pub struct WriteData<'info> {
         bump = user_id.account_bump
     )]
     pub user_account: Account<'info, UserAccount>,
+
+    // required if sender isn't the owner
+    #[account(
+        seeds = [SEED_USER_DELEGATE.as_bytes(), user_id.id.to_le_bytes().as_ref(), sender.key().as_ref()],
+        bump
+    )]
+    pub user_delegate: Option<Account<'info, UserDelegate>>,
 }
 
 impl<'info> WriteData<'info> {
-    pub fn write_data(&mut self, data: String, nonce: u64) -> Result<()> {
+    pub fn write_data(
+        &mut self,
+        user: Pubkey,
+        data: String,
+        nonce: u64,
+        expected_nonce: Option<u64>,
+        header: DataHeader,
+    ) -> Result<()> {
+        self.write_bytes(user, data.into_bytes(), nonce, expected_nonce, header)
+    }
+
+    pub fn write_bytes(
+        &mut self,
+        user: Pubkey,
+        data: Vec<u8>,
+        nonce: u64,
+        expected_nonce: Option<u64>,
+        header: DataHeader,
+    ) -> Result<()> {
         let Self {
+            sender,
+            config,
             user_id,
             user_account,
+            user_delegate,
             ..
         } = self;
 
+        // don't allow write data in paused program
+        if is_paused(config.paused_ops, PAUSE_WRITE) {
+            Err(CustomError::ContractIsPaused)?;
+        }
+
+        let clock_time = get_clock_time()?;
+
+        if sender.key() != user {
+            match user_delegate {
+                None => Err(AuthError::Unauthorized)?,
+                Some(x) => {
+                    if !x.is_valid(&user, clock_time) {
+                        Err(CustomError::DelegateIsNotValid)?;
+                    }
+                }
+            }
+        }
+
+        if user_id.is_suspended {
+            Err(CustomError::AccountIsSuspended)?;
+        }
+
         if !user_id.is_activated {
             Err(CustomError::AccountIsNotActivated)?;
         }
 
+        if user_id.is_activation_expired(clock_time) {
+            Err(CustomError::ActivationIsExpired)?;
+        }
+
         if data.len() > user_account.max_size as usize {
             Err(CustomError::MaxDataSizeIsExceeded)?;
         }
 
+        header.validate(&data)?;
+
         if nonce == user_account.nonce {
             Err(CustomError::BadNonce)?;
         }
 
+        // compare-and-swap to prevent overwriting data written by another device
+        if let Some(x) = expected_nonce {
+            if x != user_account.nonce {
+                Err(CustomError::NonceMismatch)?;
+            }
+        }
+
+        emit!(DataWritten {
+            id: user_id.id,
+            writer: sender.key(),
+            nonce,
+            length: data.len() as u32,
+        });
+
         user_account.data = data;
         user_account.nonce = nonce;
+        user_account.header = header;
 
         Ok(())
     }
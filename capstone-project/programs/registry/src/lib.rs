@@ -5,11 +5,16 @@ pub mod instructions;
 use {
     anchor_lang::prelude::*,
     instructions::{
-        activate_account::*, close_account::*, confirm_account_rotation::*,
-        confirm_admin_rotation::*, create_account::*, init::*, reopen_account::*,
-        request_account_rotation::*, update_config::*, withdraw_revenue::*, write_data::*,
+        abort_data_write::*, activate_account::*, activate_account_with_sol::*,
+        append_data_chunk::*, batch_update_accounts::*, begin_data_write::*,
+        cancel_account_rotation::*, cancel_admin_rotation::*, close_account::*,
+        close_promo_code::*, commit_data_write::*, confirm_account_rotation::*,
+        confirm_admin_rotation::*, create_account::*, create_promo_code::*, force_close_account::*,
+        grant_delegate::*, init::*, migrate::*, migrate_user_account::*, reopen_account::*,
+        request_account_rotation::*, resize_account::*, revoke_delegate::*, suspend_account::*,
+        update_config::*, withdraw_revenue::*, withdraw_revenue_with_sol::*, write_data::*,
     },
-    registry_cpi::types::{AssetItem, Range},
+    registry_cpi::types::{AssetItem, DataHeader, Range, UpdateConfigArgs},
 };
 
 // IDL builder doesn't see ID from cpi package, we need to duplicate it here
@@ -24,43 +29,90 @@ pub mod registry {
         rotation_timeout: Option<u32>,
         account_registration_fee: Option<AssetItem>,
         account_data_size_range: Option<Range>,
+        activation_period: Option<u32>,
     ) -> Result<()> {
         ctx.accounts.init(
             ctx.bumps,
             rotation_timeout,
             account_registration_fee,
             account_data_size_range,
+            activation_period,
         )
     }
 
-    pub fn update_config(
-        ctx: Context<UpdateConfig>,
-        admin: Option<Pubkey>,
-        is_paused: Option<bool>,
-        rotation_timeout: Option<u32>,
-        registration_fee_amount: Option<u64>,
-        data_size_range: Option<Range>,
-    ) -> Result<()> {
-        ctx.accounts.update_config(
-            admin,
-            is_paused,
-            rotation_timeout,
-            registration_fee_amount,
-            data_size_range,
-        )
+    pub fn update_config(ctx: Context<UpdateConfig>, args: UpdateConfigArgs) -> Result<()> {
+        ctx.accounts.update_config(args)
     }
 
     pub fn confirm_admin_rotation(ctx: Context<ConfirmAdminRotation>) -> Result<()> {
         ctx.accounts.confirm_admin_rotation()
     }
 
+    /// revokes pending or expired admin rotation
+    pub fn cancel_admin_rotation(ctx: Context<CancelAdminRotation>) -> Result<()> {
+        ctx.accounts.cancel_admin_rotation()
+    }
+
+    /// withdraws any token held by the config to specified recipient
     pub fn withdraw_revenue(ctx: Context<WithdrawRevenue>, amount: Option<u64>) -> Result<()> {
         ctx.accounts.withdraw_revenue(amount)
     }
 
-    /// creates user PDA account taking rent exempt in SOL
-    pub fn create_account(ctx: Context<CreateAccount>, max_data_size: u32) -> Result<()> {
-        ctx.accounts.create_account(ctx.bumps, max_data_size)
+    /// withdraws SOL held by the config above rent exempt to specified recipient
+    pub fn withdraw_revenue_with_sol(
+        ctx: Context<WithdrawRevenueWithSol>,
+        amount: Option<u64>,
+    ) -> Result<()> {
+        ctx.accounts.withdraw_revenue_with_sol(amount)
+    }
+
+    /// blocks data writes and rotations of the account
+    pub fn suspend_account(ctx: Context<SuspendAccount>, user: Pubkey) -> Result<()> {
+        ctx.accounts.suspend_account(user)
+    }
+
+    pub fn unsuspend_account(ctx: Context<SuspendAccount>, user: Pubkey) -> Result<()> {
+        ctx.accounts.unsuspend_account(user)
+    }
+
+    /// closes the account refunding rent to its owner
+    pub fn force_close_account(ctx: Context<ForceCloseAccount>, user: Pubkey) -> Result<()> {
+        ctx.accounts.force_close_account(user)
+    }
+
+    /// creates activation fee discount code, a part of discounted fee can be paid to referrer
+    pub fn create_promo_code(
+        ctx: Context<CreatePromoCode>,
+        code: String,
+        discount_bps: u16,
+        referrer: Option<Pubkey>,
+        referrer_share_bps: u16,
+        usage_limit: Option<u32>,
+        expiration_date: Option<u64>,
+    ) -> Result<()> {
+        ctx.accounts.create_promo_code(
+            code,
+            discount_bps,
+            referrer,
+            referrer_share_bps,
+            usage_limit,
+            expiration_date,
+        )
+    }
+
+    pub fn close_promo_code(ctx: Context<ClosePromoCode>, code: String) -> Result<()> {
+        ctx.accounts.close_promo_code(code)
+    }
+
+    /// creates user PDA account taking rent exempt in SOL from the payer \
+    /// the payer distinct from the sender is a sponsor, it can reclaim rent on account closing
+    pub fn create_account(
+        ctx: Context<CreateAccount>,
+        max_data_size: u32,
+        is_rent_reclaimable: bool,
+    ) -> Result<()> {
+        ctx.accounts
+            .create_account(ctx.bumps, max_data_size, is_rent_reclaimable)
     }
 
     /// 1st step to to change allocated data space or just to redeem rent
@@ -73,24 +125,163 @@ pub mod registry {
         ctx.accounts.reopen_account(max_data_size)
     }
 
-    /// activates account with fee asset payment
+    /// changes allocated data space keeping stored data, tops up or redeems rent \
+    /// rent of sponsored account is paid and redeemed by the sponsor if it reclaims rent
+    pub fn resize_account(ctx: Context<ResizeAccount>, max_data_size: u32) -> Result<()> {
+        ctx.accounts.resize_account(max_data_size)
+    }
+
+    /// complimentary activation of up to BATCH_SIZE_MAX accounts, fee isn't charged
+    pub fn batch_activate_accounts<'a, 'b, 'c: 'info, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, BatchUpdateAccounts<'info>>,
+    ) -> Result<()> {
+        ctx.accounts.batch_activate_accounts(ctx.remaining_accounts)
+    }
+
+    /// suspends up to BATCH_SIZE_MAX accounts, already suspended ones are skipped
+    pub fn batch_suspend_accounts<'a, 'b, 'c: 'info, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, BatchUpdateAccounts<'info>>,
+    ) -> Result<()> {
+        ctx.accounts.batch_suspend_accounts(ctx.remaining_accounts)
+    }
+
+    /// force closes up to BATCH_SIZE_MAX accounts refunding rent to the owners \
+    /// already closed ones are skipped
+    pub fn batch_close_accounts<'a, 'b, 'c: 'info, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, BatchUpdateAccounts<'info>>,
+    ) -> Result<()> {
+        ctx.accounts.batch_close_accounts(ctx.remaining_accounts)
+    }
+
+    /// upgrades Bump, Config, admin RotationState and user accounts to the latest layout \
+    /// UserId and user RotationState accounts are passed as remaining accounts \
+    /// FeeWhitelist is created if it's missing
+    pub fn migrate<'a, 'b, 'c: 'info, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, Migrate<'info>>,
+    ) -> Result<()> {
+        ctx.accounts.migrate(ctx.remaining_accounts)
+    }
+
+    /// allocates space for data header in accounts created before it was introduced
+    pub fn migrate_user_account(ctx: Context<MigrateUserAccount>) -> Result<()> {
+        ctx.accounts.migrate_user_account()
+    }
+
+    /// activates account with fee asset payment, promo code can be applied
     pub fn activate_account(ctx: Context<ActivateAccount>, user: Pubkey) -> Result<()> {
         ctx.accounts.activate_account(user)
     }
 
-    pub fn write_data(ctx: Context<WriteData>, data: String, nonce: u64) -> Result<()> {
-        ctx.accounts.write_data(data, nonce)
+    /// activates account with fee payment in SOL if it's whitelisted
+    pub fn activate_account_with_sol(
+        ctx: Context<ActivateAccountWithSol>,
+        user: Pubkey,
+    ) -> Result<()> {
+        ctx.accounts.activate_account_with_sol(user)
     }
 
+    /// extends account activation for another activation period with fee asset payment
+    pub fn renew_activation(ctx: Context<ActivateAccount>, user: Pubkey) -> Result<()> {
+        ctx.accounts.renew_activation(user)
+    }
+
+    /// extends account activation for another activation period with fee payment in SOL
+    pub fn renew_activation_with_sol(
+        ctx: Context<ActivateAccountWithSol>,
+        user: Pubkey,
+    ) -> Result<()> {
+        ctx.accounts.renew_activation_with_sol(user)
+    }
+
+    /// can be called by the owner or its delegate \
+    /// write fails if expected_nonce is specified and doesn't match stored one \
+    /// header must describe the data, it's stored alongside to let clients decode it
+    pub fn write_data(
+        ctx: Context<WriteData>,
+        user: Pubkey,
+        data: String,
+        nonce: u64,
+        expected_nonce: Option<u64>,
+        header: DataHeader,
+    ) -> Result<()> {
+        ctx.accounts
+            .write_data(user, data, nonce, expected_nonce, header)
+    }
+
+    /// the same as write_data but stores raw bytes, it lets avoid encoding of encrypted data
+    pub fn write_bytes(
+        ctx: Context<WriteData>,
+        user: Pubkey,
+        data: Vec<u8>,
+        nonce: u64,
+        expected_nonce: Option<u64>,
+        header: DataHeader,
+    ) -> Result<()> {
+        ctx.accounts
+            .write_bytes(user, data, nonce, expected_nonce, header)
+    }
+
+    /// allows the delegate to write data, existing permission is overwritten
+    pub fn grant_delegate(
+        ctx: Context<GrantDelegate>,
+        delegate: Pubkey,
+        expiration_date: Option<u64>,
+    ) -> Result<()> {
+        ctx.accounts.grant_delegate(delegate, expiration_date)
+    }
+
+    /// removes the delegate permission and redeems its rent
+    pub fn revoke_delegate(ctx: Context<RevokeDelegate>, delegate: Pubkey) -> Result<()> {
+        ctx.accounts.revoke_delegate(delegate)
+    }
+
+    /// 1st step to write data exceeding a single transaction, allocates the write buffer
+    pub fn begin_data_write(ctx: Context<BeginDataWrite>, data_size: u32) -> Result<()> {
+        ctx.accounts.begin_data_write(data_size)
+    }
+
+    /// 2nd step, can be repeated until the write buffer is filled
+    pub fn append_data_chunk(
+        ctx: Context<AppendDataChunk>,
+        offset: u32,
+        chunk: Vec<u8>,
+    ) -> Result<()> {
+        ctx.accounts.append_data_chunk(offset, chunk)
+    }
+
+    /// 3rd step, moves buffered data to user account and redeems the write buffer rent \
+    /// commit fails if expected_nonce is specified and doesn't match stored one
+    pub fn commit_data_write(
+        ctx: Context<CommitDataWrite>,
+        nonce: u64,
+        expected_nonce: Option<u64>,
+        header: DataHeader,
+    ) -> Result<()> {
+        ctx.accounts
+            .commit_data_write(nonce, expected_nonce, header)
+    }
+
+    /// discards the write buffer and redeems its rent, e.g. if buffered data can't be committed
+    pub fn abort_data_write(ctx: Context<AbortDataWrite>) -> Result<()> {
+        ctx.accounts.abort_data_write()
+    }
+
+    /// data is cleared on confirmation if clear_data is specified, it isn't handed off to the new owner
     pub fn request_account_rotation(
         ctx: Context<RequestAccountRotation>,
         new_owner: Pubkey,
+        clear_data: bool,
     ) -> Result<()> {
-        ctx.accounts.request_account_rotation(new_owner)
+        ctx.accounts.request_account_rotation(new_owner, clear_data)
     }
 
     /// updates address - id pair
     pub fn confirm_account_rotation(ctx: Context<ConfirmAccountRotation>) -> Result<()> {
         ctx.accounts.confirm_account_rotation()
     }
+
+    /// revokes pending or expired account rotation
+    pub fn cancel_account_rotation(ctx: Context<CancelAccountRotation>) -> Result<()> {
+        ctx.accounts.cancel_account_rotation()
+    }
 }
@@ -70,7 +70,11 @@ async function main() {
   // const userId = await registry.queryUserId(user, true);
   // await registry.queryUserAccount(user, true);
 
-  // await registry.tryCreateAccount(420, TX_PARAMS);
+  // await registry.tryCreateAccount(
+  //   { maxDataSize: 420, isRentReclaimable: false },
+  //   undefined,
+  //   TX_PARAMS
+  // );
   await registry.queryUserAccount(ownerKeypair.publicKey, true);
   // await registry.tryRequestAccountRotation({ newOwner: user }, TX_PARAMS);
   await registry.queryUserRotationState(ownerKeypair.publicKey, true);
@@ -87,7 +91,9 @@ async function main() {
   //   {
   //     mintFirst: mintWsol,
   //     mintLast: mintUsdc,
-  //     route: [{ ammIndex: 0, tokenOut: mintUsdc }],
+  //     route: [
+  //       { ammIndex: 0, tokenOut: mintUsdc, dex: DexKind.Clmm, poolId: 0 },
+  //     ],
   //   },
   //   TX_PARAMS
   // );
@@ -1,5 +1,7 @@
 import { gcmsiv } from "@noble/ciphers/aes";
+import { sha256 } from "@noble/hashes/sha2";
 import * as base64js from "base64-js";
+import { DATA_HEADER } from "../config";
 
 const ENC_KEY_LEN = 32;
 
@@ -44,6 +46,26 @@ export interface EncryptedResponse {
   value: string;
 }
 
+export interface DataHeader {
+  version: number;
+  schemaId: number;
+  encryptionSchemeId: number;
+  contentLength: number;
+  checksum: number[];
+}
+
+export function getDataHeader(data: string): DataHeader {
+  const bytes = new TextEncoder().encode(data);
+
+  return {
+    version: DATA_HEADER.VERSION,
+    schemaId: DATA_HEADER.SCHEMA_ID,
+    encryptionSchemeId: DATA_HEADER.ENCRYPTION_SCHEME_ID,
+    contentLength: bytes.length,
+    checksum: Array.from(sha256(bytes)),
+  };
+}
+
 export function addressToSalt(address: string): string {
   return address.repeat(2);
 }
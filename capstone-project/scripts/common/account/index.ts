@@ -8,7 +8,11 @@ import {
   SystemProgram,
 } from "@solana/web3.js";
 import { DataRecord, TxParams } from "../interfaces";
-import { decryptDeserialize, serializeEncrypt } from "./converters";
+import {
+  decryptDeserialize,
+  getDataHeader,
+  serializeEncrypt,
+} from "./converters";
 import { generateEncryptionKey, MessageSigningWallet } from "./encryption";
 import {
   getHandleTx,
@@ -31,6 +35,9 @@ import { Registry } from "../schema/types/registry";
 import { DexAdapter } from "../schema/types/dex_adapter";
 import { ClmmMock } from "../schema/types/clmm_mock";
 
+// registry_cpi::state::USER_INDEX_PAGE_SIZE
+const USER_INDEX_PAGE_SIZE = 64;
+
 export class RegistryHelpers {
   private provider: anchor.AnchorProvider;
   private program: anchor.Program<Registry>;
@@ -124,8 +131,10 @@ export class RegistryHelpers {
     return await this.handleTx([ix], params, isDisplayed);
   }
 
+  // payer must be added to params.signers if it differs from the sender
   async tryCreateAccount(
-    maxDataSize: number,
+    args: IRegistry.CreateAccountArgs,
+    payer?: PublicKey,
     params: TxParams = {},
     isDisplayed: boolean = false
   ): Promise<anchor.web3.TransactionSignature> {
@@ -133,22 +142,27 @@ export class RegistryHelpers {
     const expectedUserId = lastUserId + 1;
     const [userAccountPda] = this.getUserAccountPda(expectedUserId);
     const [userRotationStatePda] = this.getUserRotationStatePda(expectedUserId);
+    const [userIndexPagePda] = this.getUserIndexPagePda(expectedUserId);
 
     const ix = await this.program.methods
-      .createAccount(maxDataSize)
+      .createAccount(...IARegistry.convertCreateAccountArgs(args))
       .accounts({
         sender: this.sender,
+        payer: payer || this.sender,
         userAccount: userAccountPda,
         userRotationState: userRotationStatePda,
+        userIndexPage: userIndexPagePda,
       })
       .instruction();
 
     return await this.handleTx([ix], params, isDisplayed);
   }
 
+  // payer must be added to params.signers if it differs from the sender
   async tryCreateAndActivateAccount(
-    maxDataSize: number,
+    args: IRegistry.CreateAccountArgs,
     revenueMint: PublicKey,
+    payer?: PublicKey,
     params: TxParams = {},
     isDisplayed: boolean = false
   ): Promise<anchor.web3.TransactionSignature> {
@@ -156,13 +170,16 @@ export class RegistryHelpers {
     const expectedUserId = lastUserId + 1;
     const [userAccountPda] = this.getUserAccountPda(expectedUserId);
     const [userRotationStatePda] = this.getUserRotationStatePda(expectedUserId);
+    const [userIndexPagePda] = this.getUserIndexPagePda(expectedUserId);
 
     const createIx = await this.program.methods
-      .createAccount(maxDataSize)
+      .createAccount(...IARegistry.convertCreateAccountArgs(args))
       .accounts({
         sender: this.sender,
+        payer: payer || this.sender,
         userAccount: userAccountPda,
         userRotationState: userRotationStatePda,
+        userIndexPage: userIndexPagePda,
       })
       .instruction();
 
@@ -171,7 +188,9 @@ export class RegistryHelpers {
       .accounts({
         tokenProgram: await this.getTokenProgram(revenueMint),
         sender: this.sender,
+        promoCode: null,
         revenueMint,
+        referrerAta: null,
       })
       .instruction();
 
@@ -180,7 +199,7 @@ export class RegistryHelpers {
 
   // get estimated tx cost in SOL
   async simulateCreateAccount(
-    maxDataSize: number,
+    args: IRegistry.CreateAccountArgs,
     lamportsPerCu: number = 10_000,
     isDisplayed: boolean = false
   ) {
@@ -188,13 +207,16 @@ export class RegistryHelpers {
     const expectedUserId = lastUserId + 1;
     const [userAccountPda] = this.getUserAccountPda(expectedUserId);
     const [userRotationStatePda] = this.getUserRotationStatePda(expectedUserId);
+    const [userIndexPagePda] = this.getUserIndexPagePda(expectedUserId);
 
     const res = await this.program.methods
-      .createAccount(maxDataSize)
+      .createAccount(...IARegistry.convertCreateAccountArgs(args))
       .accounts({
         sender: this.sender,
+        payer: this.sender,
         userAccount: userAccountPda,
         userRotationState: userRotationStatePda,
+        userIndexPage: userIndexPagePda,
       })
       .simulate();
 
@@ -259,27 +281,45 @@ export class RegistryHelpers {
       .accounts({
         tokenProgram: await this.getTokenProgram(revenueMint),
         sender: this.sender,
+        promoCode: null,
         revenueMint,
+        referrerAta: null,
       })
       .instruction();
 
     return await this.handleTx([ix], params, isDisplayed);
   }
 
+  // sender writes data of the user as a delegate if the user is specified
   async tryWriteData(
     wallet: MessageSigningWallet,
     data: DataRecord[],
+    user?: PublicKey,
     params: TxParams = {},
     isDisplayed: boolean = false
   ): Promise<anchor.web3.TransactionSignature> {
     const encKey = await generateEncryptionKey(wallet);
     const timestamp = getTimestamp();
     const { value } = serializeEncrypt(encKey, timestamp, data);
+    const owner = user || this.sender;
+
+    let userDelegatePda: PublicKey | null = null;
+    if (!owner.equals(this.sender)) {
+      const { id } = await this.queryUserId(owner);
+      [userDelegatePda] = this.getUserDelegatePda(id, this.sender);
+    }
 
     const ix = await this.program.methods
-      .writeData(value, new anchor.BN(timestamp))
-      .accounts({
+      .writeData(
+        owner,
+        value,
+        new anchor.BN(timestamp),
+        null,
+        getDataHeader(value)
+      )
+      .accountsPartial({
         sender: this.sender,
+        userDelegate: userDelegatePda,
       })
       .instruction();
 
@@ -512,6 +552,26 @@ export class RegistryHelpers {
       this.program.programId
     );
   }
+
+  getUserDelegatePda(id: number, delegate: PublicKey) {
+    return PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("user_delegate"),
+        numberToRustBuffer(id, "u32"),
+        delegate.toBuffer(),
+      ],
+      this.program.programId
+    );
+  }
+
+  getUserIndexPagePda(id: number) {
+    const page = Math.floor((id - 1) / USER_INDEX_PAGE_SIZE);
+
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("user_index_page"), numberToRustBuffer(page, "u32")],
+      this.program.programId
+    );
+  }
 }
 
 export class DexAdapterHelpers {
@@ -628,6 +688,11 @@ export class DexAdapterHelpers {
       this.sender
     );
 
+    const { inputTokenAppAta, outputTokenAppAta } = await this.getFeeAppAtas(
+      tokenIn,
+      tokenOut
+    );
+
     // Build remaining accounts for the route
     const remainingAccounts = await this.buildRemainingAccountsForRoute(
       tokenIn,
@@ -648,6 +713,8 @@ export class DexAdapterHelpers {
       inputTokenMint: tokenIn,
       outputTokenMint: tokenOut,
       inputTokenSenderAta,
+      inputTokenAppAta,
+      outputTokenAppAta,
       outputTokenSenderAta,
     };
 
@@ -690,6 +757,11 @@ export class DexAdapterHelpers {
       true
     );
 
+    const { inputTokenAppAta, outputTokenAppAta } = await this.getFeeAppAtas(
+      tokenIn,
+      tokenOut
+    );
+
     // Build remaining accounts for the route
     const remainingAccounts = await this.buildRemainingAccountsForRoute(
       tokenIn,
@@ -714,6 +786,8 @@ export class DexAdapterHelpers {
       inputTokenMint: tokenIn,
       outputTokenMint: tokenOut,
       inputTokenSenderAta,
+      inputTokenAppAta,
+      outputTokenAppAta,
       outputTokenSenderAta,
       revenueAppAta,
     };
@@ -750,6 +824,11 @@ export class DexAdapterHelpers {
       this.sender
     );
 
+    const { inputTokenAppAta, outputTokenAppAta } = await this.getFeeAppAtas(
+      tokenIn,
+      tokenOut
+    );
+
     // Build remaining accounts for the route
     const remainingAccounts = await this.buildRemainingAccountsForRoute(
       tokenIn,
@@ -770,6 +849,8 @@ export class DexAdapterHelpers {
       inputTokenMint: tokenIn,
       outputTokenMint: tokenOut,
       inputTokenSenderAta,
+      inputTokenAppAta,
+      outputTokenAppAta,
       outputTokenSenderAta,
     };
 
@@ -782,6 +863,40 @@ export class DexAdapterHelpers {
     return await this.handleTx([ix], params, isDisplayed);
   }
 
+  // only the fee side config ATA is required if the fee isn't zero
+  private async getFeeAppAtas(
+    mintIn: PublicKey,
+    mintOut: PublicKey
+  ): Promise<{
+    inputTokenAppAta: PublicKey | null;
+    outputTokenAppAta: PublicKey | null;
+  }> {
+    const [config] = this.getConfigPda();
+    const configData = await this.queryConfig();
+    const route = await this.queryRoute(mintIn, mintOut);
+
+    const feeBps = route.feeBps ?? configData.feeBps;
+    const feeSide = route.feeSide ?? configData.feeSide;
+
+    if (!feeBps) {
+      return { inputTokenAppAta: null, outputTokenAppAta: null };
+    }
+
+    return "input" in feeSide
+      ? {
+          inputTokenAppAta: getAssociatedTokenAddressSync(mintIn, config, true),
+          outputTokenAppAta: null,
+        }
+      : {
+          inputTokenAppAta: null,
+          outputTokenAppAta: getAssociatedTokenAddressSync(
+            mintOut,
+            config,
+            true
+          ),
+        };
+  }
+
   private async buildRemainingAccountsForRoute(
     mintIn: PublicKey,
     mintOut: PublicKey
@@ -798,6 +913,11 @@ export class DexAdapterHelpers {
       const tokenB = tokenSequence[i + 1]; // output token for this hop
       const ammConfigIndex = routeItems[i].ammIndex;
 
+      // amm hop accounts aren't supported by the helpers yet
+      if (!("clmm" in routeItems[i].dex)) {
+        throw new Error(`Unsupported dex of route hop ${i}`);
+      }
+
       // Sort mints (pools are created with sorted tokens)
       const [token0Mint, token1Mint] = this.sortMints(tokenA, tokenB);
 
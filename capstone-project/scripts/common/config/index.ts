@@ -26,3 +26,10 @@ export const UTILS = {
 export const REVENUE_MINT = {
   DEVNET: new PublicKey("fPcP9vGoowPikgu7oTRCJKHUvSNn9N5WZhYshR4UXyo"),
 };
+
+// registry user data header, schema 1 is DataRecord[] json, encryption scheme 1 is aes-gcm-siv
+export const DATA_HEADER = {
+  VERSION: 1,
+  SCHEMA_ID: 1,
+  ENCRYPTION_SCHEME_ID: 1,
+};
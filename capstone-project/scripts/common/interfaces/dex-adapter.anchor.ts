@@ -1,7 +1,7 @@
 // Auto-generated Anchor types and converters
 import * as anchor from '@coral-xyz/anchor';
 import { PublicKey } from '@solana/web3.js';
-import { InitArgs, UpdateConfigArgs, SaveRouteArgs, RouteItem, SwapArgs } from './dex-adapter';
+import { InitArgs, UpdateConfigArgs, ConfigUpdate, SaveRouteArgs, RouteItem, FeeSide, SwapArgs } from './dex-adapter';
 
 // Anchor-generated types
 export type AnchorInitArgs = [
@@ -11,24 +11,37 @@ export type AnchorInitArgs = [
 ];
 
 export type AnchorUpdateConfigArgs = [
-  PublicKey | null,
-  PublicKey | null,
-  PublicKey | null,
-  boolean | null,
-  number | null
+  AnchorConfigUpdate
 ];
 
+export interface AnchorConfigUpdate {
+  admin?: PublicKey;
+  dex?: PublicKey;
+  registry?: PublicKey;
+  pausedOps?: number;
+  rotationTimeout?: number;
+  feeBps?: number;
+  amm?: PublicKey;
+  feeSide?: AnchorFeeSide;
+}
+
 export type AnchorSaveRouteArgs = [
   PublicKey,
   PublicKey,
-  AnchorRouteItem[]
+  AnchorRouteItem[],
+  number | null,
+  AnchorFeeSide | null
 ];
 
 export interface AnchorRouteItem {
   ammIndex: number;
   tokenOut: PublicKey;
+  dex: { clmm: {} } | { amm: {} };
+  poolId: anchor.BN;
 }
 
+export type AnchorFeeSide = { input: {} } | { output: {} };
+
 export type AnchorSwapArgs = [
   PublicKey,
   PublicKey,
@@ -52,21 +65,34 @@ export function convertUpdateConfigArgs(
   args: UpdateConfigArgs
 ): AnchorUpdateConfigArgs {
   return [
-    args.admin !== undefined ? args.admin : null,
-    args.dex !== undefined ? args.dex : null,
-    args.registry !== undefined ? args.registry : null,
-    args.isPaused !== undefined ? args.isPaused : null,
-    args.rotationTimeout !== undefined ? args.rotationTimeout : null
+    convertConfigUpdate(args.args)
   ];
 }
 
+export function convertConfigUpdate(
+  obj: ConfigUpdate
+): AnchorConfigUpdate {
+  return {
+    admin: obj.admin !== undefined ? obj.admin : null,
+    dex: obj.dex !== undefined ? obj.dex : null,
+    registry: obj.registry !== undefined ? obj.registry : null,
+    pausedOps: obj.pausedOps !== undefined ? obj.pausedOps : null,
+    rotationTimeout: obj.rotationTimeout !== undefined ? obj.rotationTimeout : null,
+    feeBps: obj.feeBps !== undefined ? obj.feeBps : null,
+    amm: obj.amm !== undefined ? obj.amm : null,
+    feeSide: obj.feeSide !== undefined ? convertFeeSide(obj.feeSide) : null,
+  };
+}
+
 export function convertSaveRouteArgs(
   args: SaveRouteArgs
 ): AnchorSaveRouteArgs {
   return [
     args.mintFirst,
     args.mintLast,
-    args.route.map(convertRouteItem)
+    args.route.map(convertRouteItem),
+    args.feeBps !== undefined ? args.feeBps : null,
+    args.feeSide !== undefined ? convertFeeSide(args.feeSide) : null
   ];
 }
 
@@ -76,9 +102,17 @@ export function convertRouteItem(
   return {
     ammIndex: obj.ammIndex,
     tokenOut: obj.tokenOut,
+    dex: { [obj.dex.toLowerCase()]: {} } as AnchorRouteItem["dex"],
+    poolId: new anchor.BN(obj.poolId),
   };
 }
 
+export function convertFeeSide(
+  feeSide: FeeSide
+): AnchorFeeSide {
+  return { [feeSide.toLowerCase()]: {} } as AnchorFeeSide;
+}
+
 export function convertSwapArgs(
   args: SwapArgs
 ): AnchorSwapArgs {
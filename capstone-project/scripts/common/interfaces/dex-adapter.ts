@@ -8,22 +8,43 @@ export interface InitArgs {
 }
 
 export interface UpdateConfigArgs {
+  args: ConfigUpdate;
+}
+
+export interface ConfigUpdate {
   admin?: PublicKey;
   dex?: PublicKey;
   registry?: PublicKey;
-  isPaused?: boolean;
+  pausedOps?: N<8>;
   rotationTimeout?: N<32>;
+  feeBps?: N<16>;
+  amm?: PublicKey;
+  feeSide?: FeeSide;
 }
 
 export interface SaveRouteArgs {
   mintFirst: PublicKey;
   mintLast: PublicKey;
   route: RouteItem[];
+  feeBps?: N<16>;
+  feeSide?: FeeSide;
 }
 
 export interface RouteItem {
   ammIndex: N<16>;
   tokenOut: PublicKey;
+  dex: DexKind;
+  poolId: N<64>;
+}
+
+export enum DexKind {
+  Clmm = "Clmm",
+  Amm = "Amm",
+}
+
+export enum FeeSide {
+  Input = "Input",
+  Output = "Output",
 }
 
 export interface SwapArgs {
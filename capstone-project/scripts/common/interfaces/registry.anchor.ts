@@ -1,13 +1,14 @@
 // Auto-generated Anchor types and converters
 import * as anchor from '@coral-xyz/anchor';
 import { PublicKey } from '@solana/web3.js';
-import { InitArgs, AssetItem, Range, UpdateConfigArgs, WithdrawRevenueArgs, ReopenAccountArgs, ActivateAccountArgs, RequestAccountRotationArgs } from './registry';
+import { InitArgs, AssetItem, Range, UpdateConfigArgs, ConfigUpdate, WithdrawRevenueArgs, CreateAccountArgs, ReopenAccountArgs, ActivateAccountArgs, RequestAccountRotationArgs } from './registry';
 
 // Anchor-generated types
 export type AnchorInitArgs = [
   number | null,
   AnchorAssetItem | null,
-  AnchorRange | null
+  AnchorRange | null,
+  number | null
 ];
 
 export interface AnchorAssetItem {
@@ -21,17 +22,28 @@ export interface AnchorRange {
 }
 
 export type AnchorUpdateConfigArgs = [
-  PublicKey | null,
-  boolean | null,
-  number | null,
-  anchor.BN | null,
-  AnchorRange | null
+  AnchorConfigUpdate
 ];
 
+export interface AnchorConfigUpdate {
+  admin?: PublicKey;
+  pausedOps?: number;
+  rotationTimeout?: number;
+  registrationFeeAmount?: anchor.BN;
+  dataSizeRange?: AnchorRange;
+  feeWhitelist?: AnchorAssetItem[];
+  activationPeriod?: number;
+}
+
 export type AnchorWithdrawRevenueArgs = [
   anchor.BN | null
 ];
 
+export type AnchorCreateAccountArgs = [
+  number,
+  boolean
+];
+
 export type AnchorReopenAccountArgs = [
   number
 ];
@@ -52,7 +64,8 @@ export function convertInitArgs(
   return [
     args.rotationTimeout !== undefined ? args.rotationTimeout : null,
     args.accountRegistrationFee !== undefined ? convertAssetItem(args.accountRegistrationFee) : null,
-    args.accountDataSizeRange !== undefined ? convertRange(args.accountDataSizeRange) : null
+    args.accountDataSizeRange !== undefined ? convertRange(args.accountDataSizeRange) : null,
+    args.activationPeriod !== undefined ? args.activationPeriod : null
   ];
 }
 
@@ -78,14 +91,24 @@ export function convertUpdateConfigArgs(
   args: UpdateConfigArgs
 ): AnchorUpdateConfigArgs {
   return [
-    args.admin !== undefined ? args.admin : null,
-    args.is_paused !== undefined ? args.is_paused : null,
-    args.rotation_timeout !== undefined ? args.rotation_timeout : null,
-    args.registration_fee_amount !== undefined ? new anchor.BN(args.registration_fee_amount) : null,
-    args.data_size_range !== undefined ? convertRange(args.data_size_range) : null
+    convertConfigUpdate(args.args)
   ];
 }
 
+export function convertConfigUpdate(
+  obj: ConfigUpdate
+): AnchorConfigUpdate {
+  return {
+    admin: obj.admin !== undefined ? obj.admin : null,
+    pausedOps: obj.pausedOps !== undefined ? obj.pausedOps : null,
+    rotationTimeout: obj.rotationTimeout !== undefined ? obj.rotationTimeout : null,
+    registrationFeeAmount: obj.registrationFeeAmount !== undefined ? new anchor.BN(obj.registrationFeeAmount) : null,
+    dataSizeRange: obj.dataSizeRange !== undefined ? convertRange(obj.dataSizeRange) : null,
+    feeWhitelist: obj.feeWhitelist !== undefined ? obj.feeWhitelist.map(convertAssetItem) : null,
+    activationPeriod: obj.activationPeriod !== undefined ? obj.activationPeriod : null,
+  };
+}
+
 export function convertWithdrawRevenueArgs(
   args: WithdrawRevenueArgs
 ): AnchorWithdrawRevenueArgs {
@@ -94,6 +117,15 @@ export function convertWithdrawRevenueArgs(
   ];
 }
 
+export function convertCreateAccountArgs(
+  args: CreateAccountArgs
+): AnchorCreateAccountArgs {
+  return [
+    args.maxDataSize,
+    args.isRentReclaimable
+  ];
+}
+
 export function convertReopenAccountArgs(
   args: ReopenAccountArgs
 ): AnchorReopenAccountArgs {
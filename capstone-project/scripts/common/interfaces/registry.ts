@@ -5,6 +5,7 @@ export interface InitArgs {
   rotationTimeout?: N<32>;
   accountRegistrationFee?: AssetItem;
   accountDataSizeRange?: Range;
+  activationPeriod?: N<32>;
 }
 
 export interface AssetItem {
@@ -18,17 +19,28 @@ export interface Range {
 }
 
 export interface UpdateConfigArgs {
+  args: ConfigUpdate;
+}
+
+export interface ConfigUpdate {
   admin?: PublicKey;
-  is_paused?: boolean;
-  rotation_timeout?: N<32>;
-  registration_fee_amount?: N<64>;
-  data_size_range?: Range;
+  pausedOps?: N<8>;
+  rotationTimeout?: N<32>;
+  registrationFeeAmount?: N<64>;
+  dataSizeRange?: Range;
+  feeWhitelist?: AssetItem[];
+  activationPeriod?: N<32>;
 }
 
 export interface WithdrawRevenueArgs {
   amount?: N<64>;
 }
 
+export interface CreateAccountArgs {
+  maxDataSize: N<32>;
+  isRentReclaimable: boolean;
+}
+
 export interface ReopenAccountArgs {
   maxDataSize: N<32>;
 }
@@ -14,6 +14,96 @@ export type DexAdapter = {
     "repository": "https://github.com/M-Daeva/solana-boilerplate"
   },
   "instructions": [
+    {
+      "name": "cancelAdminRotation",
+      "docs": [
+        "revokes pending or expired admin rotation"
+      ],
+      "discriminator": [
+        213,
+        54,
+        2,
+        41,
+        251,
+        11,
+        205,
+        110
+      ],
+      "accounts": [
+        {
+          "name": "sender",
+          "signer": true
+        },
+        {
+          "name": "bump",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  117,
+                  109,
+                  112
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "adminRotationState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  114,
+                  111,
+                  116,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
     {
       "name": "confirmAdminRotation",
       "discriminator": [
@@ -213,8 +303,153 @@ export type DexAdapter = {
         }
       ]
     },
+    {
+      "name": "migrate",
+      "docs": [
+        "upgrades DaConfig and Route accounts to the latest layout \\",
+        "Route accounts are passed as remaining accounts"
+      ],
+      "discriminator": [
+        155,
+        234,
+        231,
+        146,
+        236,
+        158,
+        162,
+        30
+      ],
+      "accounts": [
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "sender",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "quote",
+      "docs": [
+        "read-only simulation of swap along the saved route, the result is returned via return data"
+      ],
+      "discriminator": [
+        149,
+        42,
+        109,
+        247,
+        134,
+        146,
+        213,
+        123
+      ],
+      "accounts": [
+        {
+          "name": "bump",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  117,
+                  109,
+                  112
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "route",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  111,
+                  117,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "inputTokenMint"
+              },
+              {
+                "kind": "account",
+                "path": "outputTokenMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "inputTokenMint"
+        },
+        {
+          "name": "outputTokenMint"
+        }
+      ],
+      "args": [
+        {
+          "name": "amountIn",
+          "type": "u64"
+        }
+      ],
+      "returns": {
+        "defined": {
+          "name": "routeQuote"
+        }
+      }
+    },
     {
       "name": "saveRoute",
+      "docs": [
+        "swaps are allowed only along saved routes, route fee_bps and fee_side override the config ones"
+      ],
       "discriminator": [
         159,
         32,
@@ -314,13 +549,29 @@ export type DexAdapter = {
               }
             }
           }
+        },
+        {
+          "name": "feeBps",
+          "type": {
+            "option": "u16"
+          }
+        },
+        {
+          "name": "feeSide",
+          "type": {
+            "option": {
+              "defined": {
+                "name": "feeSide"
+              }
+            }
+          }
         }
       ]
     },
     {
       "name": "swap",
       "docs": [
-        "swap across multiple pools"
+        "swap across multiple pools, the pools must match the saved route"
       ],
       "discriminator": [
         248,
@@ -516,13 +767,14 @@ export type DexAdapter = {
           }
         },
         {
-          "name": "outputTokenSenderAta",
+          "name": "inputTokenAppAta",
           "writable": true,
+          "optional": true,
           "pda": {
             "seeds": [
               {
                 "kind": "account",
-                "path": "sender"
+                "path": "config"
               },
               {
                 "kind": "const",
@@ -563,7 +815,7 @@ export type DexAdapter = {
               },
               {
                 "kind": "account",
-                "path": "outputTokenMint"
+                "path": "inputTokenMint"
               }
             ],
             "program": {
@@ -604,42 +856,223 @@ export type DexAdapter = {
               ]
             }
           }
-        }
-      ],
-      "args": [
-        {
-          "name": "amountIn",
-          "type": "u64"
-        },
-        {
-          "name": "amountOutMinimum",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "swapAndActivate",
-      "docs": [
-        "swap tokens and call activate_account of registry program"
-      ],
-      "discriminator": [
-        211,
-        229,
-        13,
-        51,
-        221,
-        165,
-        179,
-        242
-      ],
-      "accounts": [
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
         },
         {
-          "name": "tokenProgram"
-        },
+          "name": "outputTokenAppAta",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "outputTokenMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "outputTokenSenderAta",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "sender"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "outputTokenMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "amountIn",
+          "type": "u64"
+        },
+        {
+          "name": "amountOutMinimum",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "swapAndActivate",
+      "docs": [
+        "swap tokens and call activate_account of registry program"
+      ],
+      "discriminator": [
+        211,
+        229,
+        13,
+        51,
+        221,
+        165,
+        179,
+        242
+      ],
+      "accounts": [
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram"
+        },
         {
           "name": "associatedTokenProgram",
           "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
@@ -762,6 +1195,35 @@ export type DexAdapter = {
             }
           }
         },
+        {
+          "name": "registryFeeWhitelist",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  119,
+                  104,
+                  105,
+                  116,
+                  101,
+                  108,
+                  105,
+                  115,
+                  116
+                ]
+              }
+            ],
+            "program": {
+              "kind": "account",
+              "path": "registryProgram"
+            }
+          }
+        },
         {
           "name": "registryUserId",
           "writable": true,
@@ -889,13 +1351,14 @@ export type DexAdapter = {
           }
         },
         {
-          "name": "outputTokenSenderAta",
+          "name": "inputTokenAppAta",
           "writable": true,
+          "optional": true,
           "pda": {
             "seeds": [
               {
                 "kind": "account",
-                "path": "sender"
+                "path": "config"
               },
               {
                 "kind": "const",
@@ -936,7 +1399,7 @@ export type DexAdapter = {
               },
               {
                 "kind": "account",
-                "path": "outputTokenMint"
+                "path": "inputTokenMint"
               }
             ],
             "program": {
@@ -979,13 +1442,14 @@ export type DexAdapter = {
           }
         },
         {
-          "name": "revenueAppAta",
+          "name": "outputTokenAppAta",
           "writable": true,
+          "optional": true,
           "pda": {
             "seeds": [
               {
                 "kind": "account",
-                "path": "registryConfig"
+                "path": "config"
               },
               {
                 "kind": "const",
@@ -1067,22 +1531,202 @@ export type DexAdapter = {
               ]
             }
           }
-        }
-      ],
-      "args": [
-        {
-          "name": "amountIn",
-          "type": "u64"
         },
         {
-          "name": "amountOutMinimum",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "swapAndUnwrapWsol",
-      "docs": [
+          "name": "outputTokenSenderAta",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "sender"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "outputTokenMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "revenueAppAta",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "registryConfig"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "outputTokenMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "amountIn",
+          "type": "u64"
+        },
+        {
+          "name": "amountOutMinimum",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "swapAndUnwrapWsol",
+      "docs": [
         "swap a token to WSOL and unwrap it to SOL"
       ],
       "discriminator": [
@@ -1279,13 +1923,14 @@ export type DexAdapter = {
           }
         },
         {
-          "name": "outputTokenSenderAta",
+          "name": "inputTokenAppAta",
           "writable": true,
+          "optional": true,
           "pda": {
             "seeds": [
               {
                 "kind": "account",
-                "path": "sender"
+                "path": "config"
               },
               {
                 "kind": "const",
@@ -1326,7 +1971,7 @@ export type DexAdapter = {
               },
               {
                 "kind": "account",
-                "path": "outputTokenMint"
+                "path": "inputTokenMint"
               }
             ],
             "program": {
@@ -1367,69 +2012,1402 @@ export type DexAdapter = {
               ]
             }
           }
-        }
-      ],
-      "args": [
-        {
-          "name": "amountIn",
-          "type": "u64"
-        },
-        {
-          "name": "amountOutMinimum",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "updateConfig",
-      "discriminator": [
-        29,
-        158,
-        252,
-        191,
-        10,
-        83,
-        219,
-        99
-      ],
-      "accounts": [
-        {
-          "name": "sender",
-          "signer": true
-        },
-        {
-          "name": "bump",
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  98,
-                  117,
-                  109,
-                  112
-                ]
-              }
-            ]
-          }
         },
         {
-          "name": "config",
+          "name": "outputTokenAppAta",
           "writable": true,
+          "optional": true,
           "pda": {
             "seeds": [
+              {
+                "kind": "account",
+                "path": "config"
+              },
               {
                 "kind": "const",
                 "value": [
-                  99,
-                  111,
-                  110,
-                  102,
-                  105,
-                  103
-                ]
-              }
-            ]
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "outputTokenMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "outputTokenSenderAta",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "sender"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "outputTokenMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "amountIn",
+          "type": "u64"
+        },
+        {
+          "name": "amountOutMinimum",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "swapBaseOut",
+      "docs": [
+        "swap across multiple pools receiving exact output amount"
+      ],
+      "discriminator": [
+        163,
+        210,
+        155,
+        208,
+        175,
+        146,
+        213,
+        150
+      ],
+      "accounts": [
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "tokenProgram2022"
+        },
+        {
+          "name": "memoProgram"
+        },
+        {
+          "name": "clmmMockProgram"
+        },
+        {
+          "name": "sender",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bump",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  117,
+                  109,
+                  112
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "route",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  111,
+                  117,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "inputTokenMint"
+              },
+              {
+                "kind": "account",
+                "path": "outputTokenMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "inputTokenMint",
+          "writable": true
+        },
+        {
+          "name": "outputTokenMint",
+          "writable": true
+        },
+        {
+          "name": "inputTokenSenderAta",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "sender"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "inputTokenMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "inputTokenAppAta",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "inputTokenMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "outputTokenAppAta",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "outputTokenMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "outputTokenSenderAta",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "sender"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "outputTokenMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "amountOut",
+          "type": "u64"
+        },
+        {
+          "name": "amountInMaximum",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "swapBaseOutAndActivate",
+      "docs": [
+        "swap tokens receiving exactly the activation fee and call activate_account of registry program"
+      ],
+      "discriminator": [
+        253,
+        218,
+        242,
+        156,
+        37,
+        104,
+        62,
+        111
+      ],
+      "accounts": [
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "tokenProgram2022"
+        },
+        {
+          "name": "memoProgram"
+        },
+        {
+          "name": "clmmMockProgram"
+        },
+        {
+          "name": "registryProgram"
+        },
+        {
+          "name": "sender",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bump",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  117,
+                  109,
+                  112
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "route",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  111,
+                  117,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "inputTokenMint"
+              },
+              {
+                "kind": "account",
+                "path": "outputTokenMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "registryBump",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  117,
+                  109,
+                  112
+                ]
+              }
+            ],
+            "program": {
+              "kind": "account",
+              "path": "registryProgram"
+            }
+          }
+        },
+        {
+          "name": "registryConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ],
+            "program": {
+              "kind": "account",
+              "path": "registryProgram"
+            }
+          }
+        },
+        {
+          "name": "registryFeeWhitelist",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  119,
+                  104,
+                  105,
+                  116,
+                  101,
+                  108,
+                  105,
+                  115,
+                  116
+                ]
+              }
+            ],
+            "program": {
+              "kind": "account",
+              "path": "registryProgram"
+            }
+          }
+        },
+        {
+          "name": "registryUserId",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  105,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "sender"
+              }
+            ],
+            "program": {
+              "kind": "account",
+              "path": "registryProgram"
+            }
+          }
+        },
+        {
+          "name": "inputTokenMint",
+          "writable": true
+        },
+        {
+          "name": "outputTokenMint",
+          "writable": true
+        },
+        {
+          "name": "inputTokenSenderAta",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "sender"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "inputTokenMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "inputTokenAppAta",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "inputTokenMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "outputTokenAppAta",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "outputTokenMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "outputTokenSenderAta",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "sender"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "outputTokenMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "revenueAppAta",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "registryConfig"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "outputTokenMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "amountInMaximum",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "updateConfig",
+      "discriminator": [
+        29,
+        158,
+        252,
+        191,
+        10,
+        83,
+        219,
+        99
+      ],
+      "accounts": [
+        {
+          "name": "sender",
+          "signer": true
+        },
+        {
+          "name": "bump",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  117,
+                  109,
+                  112
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
           }
         },
         {
@@ -1468,33 +3446,273 @@ export type DexAdapter = {
       ],
       "args": [
         {
-          "name": "admin",
+          "name": "args",
           "type": {
-            "option": "pubkey"
+            "defined": {
+              "name": "updateConfigArgs"
+            }
           }
+        }
+      ]
+    },
+    {
+      "name": "withdrawFees",
+      "docs": [
+        "withdraws accumulated protocol fees to specified recipient"
+      ],
+      "discriminator": [
+        198,
+        212,
+        171,
+        109,
+        144,
+        215,
+        174,
+        89
+      ],
+      "accounts": [
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
         },
         {
-          "name": "dex",
-          "type": {
-            "option": "pubkey"
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "sender",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "recipient"
+        },
+        {
+          "name": "bump",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  117,
+                  109,
+                  112
+                ]
+              }
+            ]
           }
         },
         {
-          "name": "registry",
-          "type": {
-            "option": "pubkey"
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
           }
         },
         {
-          "name": "isPaused",
-          "type": {
-            "option": "bool"
+          "name": "feeMint"
+        },
+        {
+          "name": "feeRecipientAta",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "recipient"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "feeMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "feeAppAta",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "feeMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
           }
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "rotationTimeout",
+          "name": "amount",
           "type": {
-            "option": "u32"
+            "option": "u64"
           }
         }
       ]
@@ -1553,6 +3771,19 @@ export type DexAdapter = {
         201
       ]
     },
+    {
+      "name": "feeWhitelist",
+      "discriminator": [
+        6,
+        209,
+        183,
+        73,
+        67,
+        24,
+        102,
+        82
+      ]
+    },
     {
       "name": "rotationState",
       "discriminator": [
@@ -1629,6 +3860,10 @@ export type DexAdapter = {
           {
             "name": "rotationState",
             "type": "u8"
+          },
+          {
+            "name": "version",
+            "type": "u8"
           }
         ]
       }
@@ -1646,8 +3881,11 @@ export type DexAdapter = {
             "type": "pubkey"
           },
           {
-            "name": "isPaused",
-            "type": "bool"
+            "name": "pausedOps",
+            "docs": [
+              "bit mask of paused operations, see base::pause"
+            ],
+            "type": "u8"
           },
           {
             "name": "rotationTimeout",
@@ -1668,6 +3906,17 @@ export type DexAdapter = {
                 "name": "range"
               }
             }
+          },
+          {
+            "name": "activationPeriod",
+            "docs": [
+              "activation is extended by this period for each fee payment"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "version",
+            "type": "u8"
           }
         ]
       }
@@ -1705,6 +3954,9 @@ export type DexAdapter = {
           },
           {
             "name": "dex",
+            "docs": [
+              "clmm program"
+            ],
             "type": "pubkey"
           },
           {
@@ -1714,12 +3966,114 @@ export type DexAdapter = {
             }
           },
           {
-            "name": "isPaused",
-            "type": "bool"
+            "name": "pausedOps",
+            "docs": [
+              "bit mask of paused operations, see base::pause"
+            ],
+            "type": "u8"
           },
           {
             "name": "rotationTimeout",
             "type": "u32"
+          },
+          {
+            "name": "feeBps",
+            "docs": [
+              "protocol fee taken from swap input or output amount, see fee_side"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "amm",
+            "docs": [
+              "constant product amm program"
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "feeSide",
+            "type": {
+              "defined": {
+                "name": "feeSide"
+              }
+            }
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "dexKind",
+      "docs": [
+        "program executing the route hop"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "clmm"
+          },
+          {
+            "name": "amm"
+          }
+        ]
+      }
+    },
+    {
+      "name": "feeSide",
+      "docs": [
+        "token the protocol fee is taken in"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "input"
+          },
+          {
+            "name": "output"
+          }
+        ]
+      }
+    },
+    {
+      "name": "feeWhitelist",
+      "docs": [
+        "assets accepted for account activation in addition to Config.registration_fee"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "value",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "assetItem"
+                }
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "hopQuote",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amountIn",
+            "type": "u64"
+          },
+          {
+            "name": "amountOut",
+            "type": "u64"
           }
         ]
       }
@@ -1779,6 +4133,26 @@ export type DexAdapter = {
                 }
               }
             }
+          },
+          {
+            "name": "feeBps",
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "feeSide",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "feeSide"
+                }
+              }
+            }
+          },
+          {
+            "name": "version",
+            "type": "u8"
           }
         ]
       }
@@ -1790,11 +4164,149 @@ export type DexAdapter = {
         "fields": [
           {
             "name": "ammIndex",
+            "docs": [
+              "amm config index, used by Clmm hops"
+            ],
             "type": "u16"
           },
           {
             "name": "tokenOut",
             "type": "pubkey"
+          },
+          {
+            "name": "dex",
+            "type": {
+              "defined": {
+                "name": "dexKind"
+              }
+            }
+          },
+          {
+            "name": "poolId",
+            "docs": [
+              "pool id, used by Amm hops"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "routeQuote",
+      "docs": [
+        "expected result of a swap along the saved route"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amountIn",
+            "type": "u64"
+          },
+          {
+            "name": "feeAmount",
+            "docs": [
+              "protocol fee taken from amount_in before the first hop or from the last hop output"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "feeSide",
+            "type": {
+              "defined": {
+                "name": "feeSide"
+              }
+            }
+          },
+          {
+            "name": "amountOut",
+            "docs": [
+              "output amount after the protocol fee"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "hops",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "hopQuote"
+                }
+              }
+            }
+          },
+          {
+            "name": "priceImpactBps",
+            "docs": [
+              "output reduction caused by pool reserves change, fees aren't included"
+            ],
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "updateConfigArgs",
+      "docs": [
+        "config fields to update, unspecified ones are kept, at least one must be specified"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "docs": [
+              "new admin must confirm the rotation"
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "dex",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "registry",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "pausedOps",
+            "type": {
+              "option": "u8"
+            }
+          },
+          {
+            "name": "rotationTimeout",
+            "type": {
+              "option": "u32"
+            }
+          },
+          {
+            "name": "feeBps",
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "amm",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "feeSide",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "feeSide"
+                }
+              }
+            }
           }
         ]
       }
@@ -1826,6 +4338,40 @@ export type DexAdapter = {
           {
             "name": "rotationStateBump",
             "type": "u8"
+          },
+          {
+            "name": "activatedUntil",
+            "docs": [
+              "activation expiration date"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "isSuspended",
+            "docs": [
+              "blocks data writes and rotations, set by admin"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "sponsor",
+            "docs": [
+              "paid rent on account creation instead of the owner"
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "isRentReclaimable",
+            "docs": [
+              "rent is refunded to the sponsor on account closing"
+            ],
+            "type": "bool"
           }
         ]
       }
@@ -14,10 +14,92 @@ export type Registry = {
     "repository": "https://github.com/M-Daeva/solana-boilerplate"
   },
   "instructions": [
+    {
+      "name": "abortDataWrite",
+      "docs": [
+        "discards the write buffer and redeems its rent, e.g. if buffered data can't be committed"
+      ],
+      "discriminator": [
+        58,
+        249,
+        117,
+        102,
+        186,
+        108,
+        207,
+        180
+      ],
+      "accounts": [
+        {
+          "name": "sender",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "userId",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  105,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "sender"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userWriteBuffer",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  119,
+                  114,
+                  105,
+                  116,
+                  101,
+                  95,
+                  98,
+                  117,
+                  102,
+                  102,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user_id.id",
+                "account": "userId"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
     {
       "name": "activateAccount",
       "docs": [
-        "activates account with fee asset payment"
+        "activates account with fee asset payment, promo code can be applied"
       ],
       "discriminator": [
         128,
@@ -80,6 +162,31 @@ export type Registry = {
             ]
           }
         },
+        {
+          "name": "feeWhitelist",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  119,
+                  104,
+                  105,
+                  116,
+                  101,
+                  108,
+                  105,
+                  115,
+                  116
+                ]
+              }
+            ]
+          }
+        },
         {
           "name": "userId",
           "writable": true,
@@ -104,6 +211,11 @@ export type Registry = {
             ]
           }
         },
+        {
+          "name": "promoCode",
+          "writable": true,
+          "optional": true
+        },
         {
           "name": "revenueMint"
         },
@@ -286,6 +398,11 @@ export type Registry = {
               ]
             }
           }
+        },
+        {
+          "name": "referrerAta",
+          "writable": true,
+          "optional": true
         }
       ],
       "args": [
@@ -296,19 +413,19 @@ export type Registry = {
       ]
     },
     {
-      "name": "closeAccount",
+      "name": "activateAccountWithSol",
       "docs": [
-        "1st step to to change allocated data space or just to redeem rent"
+        "activates account with fee payment in SOL if it's whitelisted"
       ],
       "discriminator": [
-        125,
-        255,
-        149,
-        14,
-        110,
-        34,
-        72,
-        24
+        38,
+        189,
+        94,
+        10,
+        30,
+        242,
+        221,
+        206
       ],
       "accounts": [
         {
@@ -321,61 +438,67 @@ export type Registry = {
           "signer": true
         },
         {
-          "name": "userId",
-          "writable": true,
+          "name": "bump",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
+                  98,
                   117,
-                  115,
-                  101,
-                  114,
-                  95,
-                  105,
-                  100
+                  109,
+                  112
                 ]
-              },
-              {
-                "kind": "account",
-                "path": "sender"
               }
             ]
           }
         },
         {
-          "name": "userAccount",
+          "name": "config",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  117,
-                  115,
-                  101,
-                  114,
-                  95,
-                  97,
-                  99,
                   99,
                   111,
-                  117,
                   110,
-                  116
+                  102,
+                  105,
+                  103
                 ]
-              },
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeWhitelist",
+          "pda": {
+            "seeds": [
               {
-                "kind": "account",
-                "path": "user_id.id",
-                "account": "userId"
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  119,
+                  104,
+                  105,
+                  116,
+                  101,
+                  108,
+                  105,
+                  115,
+                  116
+                ]
               }
             ]
           }
         },
         {
-          "name": "userRotationState",
+          "name": "userId",
           "writable": true,
           "pda": {
             "seeds": [
@@ -387,101 +510,106 @@ export type Registry = {
                   101,
                   114,
                   95,
-                  114,
-                  111,
-                  116,
-                  97,
-                  116,
                   105,
-                  111,
-                  110,
-                  95,
-                  115,
-                  116,
-                  97,
-                  116,
-                  101
+                  100
                 ]
               },
               {
-                "kind": "account",
-                "path": "user_id.id",
-                "account": "userId"
+                "kind": "arg",
+                "path": "user"
               }
             ]
           }
+        },
+        {
+          "name": "promoCode",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "referrer",
+          "writable": true,
+          "optional": true
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "user",
+          "type": "pubkey"
+        }
+      ]
     },
     {
-      "name": "confirmAccountRotation",
+      "name": "appendDataChunk",
       "docs": [
-        "updates address - id pair"
+        "2nd step, can be repeated until the write buffer is filled"
       ],
       "discriminator": [
-        46,
-        196,
-        252,
-        234,
-        140,
-        190,
-        55,
-        250
+        103,
+        248,
+        64,
+        90,
+        47,
+        115,
+        77,
+        9
       ],
       "accounts": [
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        },
         {
           "name": "sender",
-          "writable": true,
           "signer": true
         },
         {
-          "name": "userIdPre",
-          "writable": true,
+          "name": "bump",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
+                  98,
                   117,
-                  115,
-                  101,
-                  114,
-                  95,
-                  105,
-                  100
+                  109,
+                  112
                 ]
-              },
-              {
-                "kind": "account",
-                "path": "user_rotation_state.owner",
-                "account": "rotationState"
               }
             ]
           }
         },
         {
-          "name": "userId",
-          "writable": true,
+          "name": "config",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  117,
-                  115,
-                  101,
-                  114,
-                  95,
+                  99,
+                  111,
+                  110,
+                  102,
                   105,
-                  100
+                  103
                 ]
-              },
-              {
+              }
+            ]
+          }
+        },
+        {
+          "name": "userId",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  105,
+                  100
+                ]
+              },
+              {
                 "kind": "account",
                 "path": "sender"
               }
@@ -489,7 +617,7 @@ export type Registry = {
           }
         },
         {
-          "name": "userRotationState",
+          "name": "userWriteBuffer",
           "writable": true,
           "pda": {
             "seeds": [
@@ -501,44 +629,54 @@ export type Registry = {
                   101,
                   114,
                   95,
+                  119,
                   114,
-                  111,
-                  116,
-                  97,
-                  116,
                   105,
-                  111,
-                  110,
-                  95,
-                  115,
-                  116,
-                  97,
                   116,
-                  101
+                  101,
+                  95,
+                  98,
+                  117,
+                  102,
+                  102,
+                  101,
+                  114
                 ]
               },
               {
                 "kind": "account",
-                "path": "user_id_pre.id",
+                "path": "user_id.id",
                 "account": "userId"
               }
             ]
           }
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "offset",
+          "type": "u32"
+        },
+        {
+          "name": "chunk",
+          "type": "bytes"
+        }
+      ]
     },
     {
-      "name": "confirmAdminRotation",
+      "name": "batchActivateAccounts",
+      "docs": [
+        "complimentary activation of up to BATCH_SIZE_MAX accounts, fee isn't charged"
+      ],
       "discriminator": [
-        35,
-        96,
-        147,
-        139,
-        128,
-        212,
-        60,
-        237
+        15,
+        210,
+        223,
+        119,
+        240,
+        201,
+        240,
+        30
       ],
       "accounts": [
         {
@@ -563,7 +701,6 @@ export type Registry = {
         },
         {
           "name": "config",
-          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -579,66 +716,29 @@ export type Registry = {
               }
             ]
           }
-        },
-        {
-          "name": "adminRotationState",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  97,
-                  100,
-                  109,
-                  105,
-                  110,
-                  95,
-                  114,
-                  111,
-                  116,
-                  97,
-                  116,
-                  105,
-                  111,
-                  110,
-                  95,
-                  115,
-                  116,
-                  97,
-                  116,
-                  101
-                ]
-              }
-            ]
-          }
         }
       ],
       "args": []
     },
     {
-      "name": "createAccount",
+      "name": "batchCloseAccounts",
       "docs": [
-        "creates user PDA account taking rent exempt in SOL"
+        "force closes up to BATCH_SIZE_MAX accounts refunding rent to the owners \\",
+        "already closed ones are skipped"
       ],
       "discriminator": [
-        99,
-        20,
-        130,
-        119,
-        196,
-        235,
-        131,
-        149
+        162,
+        125,
+        57,
+        0,
+        8,
+        4,
+        158,
+        254
       ],
       "accounts": [
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        },
         {
           "name": "sender",
-          "writable": true,
           "signer": true
         },
         {
@@ -674,96 +774,87 @@ export type Registry = {
               }
             ]
           }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "batchSuspendAccounts",
+      "docs": [
+        "suspends up to BATCH_SIZE_MAX accounts, already suspended ones are skipped"
+      ],
+      "discriminator": [
+        58,
+        196,
+        254,
+        76,
+        53,
+        169,
+        200,
+        125
+      ],
+      "accounts": [
+        {
+          "name": "sender",
+          "signer": true
         },
         {
-          "name": "userCounter",
-          "writable": true,
+          "name": "bump",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
+                  98,
                   117,
-                  115,
-                  101,
-                  114,
-                  95,
-                  99,
-                  111,
-                  117,
-                  110,
-                  116,
-                  101,
-                  114
+                  109,
+                  112
                 ]
               }
             ]
           }
         },
         {
-          "name": "userId",
-          "writable": true,
+          "name": "config",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  117,
-                  115,
-                  101,
-                  114,
-                  95,
+                  99,
+                  111,
+                  110,
+                  102,
                   105,
-                  100
+                  103
                 ]
-              },
-              {
-                "kind": "account",
-                "path": "sender"
               }
             ]
           }
-        },
-        {
-          "name": "userAccount",
-          "writable": true
-        },
-        {
-          "name": "userRotationState",
-          "writable": true
         }
       ],
-      "args": [
-        {
-          "name": "maxDataSize",
-          "type": "u32"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "init",
+      "name": "beginDataWrite",
+      "docs": [
+        "1st step to write data exceeding a single transaction, allocates the write buffer"
+      ],
       "discriminator": [
-        220,
-        59,
-        207,
-        236,
-        108,
-        250,
-        47,
-        100
+        239,
+        182,
+        237,
+        110,
+        24,
+        240,
+        248,
+        111
       ],
       "accounts": [
         {
           "name": "systemProgram",
           "address": "11111111111111111111111111111111"
         },
-        {
-          "name": "tokenProgram"
-        },
-        {
-          "name": "associatedTokenProgram",
-          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
-        },
         {
           "name": "sender",
           "writable": true,
@@ -771,7 +862,6 @@ export type Registry = {
         },
         {
           "name": "bump",
-          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -788,7 +878,6 @@ export type Registry = {
         },
         {
           "name": "config",
-          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -806,8 +895,7 @@ export type Registry = {
           }
         },
         {
-          "name": "userCounter",
-          "writable": true,
+          "name": "userId",
           "pda": {
             "seeds": [
               {
@@ -818,236 +906,111 @@ export type Registry = {
                   101,
                   114,
                   95,
-                  99,
-                  111,
-                  117,
-                  110,
-                  116,
-                  101,
-                  114
+                  105,
+                  100
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "sender"
               }
             ]
           }
         },
         {
-          "name": "adminRotationState",
-          "writable": true,
+          "name": "userAccount",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  97,
-                  100,
-                  109,
-                  105,
-                  110,
-                  95,
+                  117,
+                  115,
+                  101,
                   114,
-                  111,
-                  116,
+                  95,
                   97,
-                  116,
-                  105,
+                  99,
+                  99,
                   111,
+                  117,
                   110,
-                  95,
-                  115,
-                  116,
-                  97,
-                  116,
-                  101
+                  116
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "user_id.id",
+                "account": "userId"
               }
             ]
           }
         },
         {
-          "name": "revenueMint"
-        },
-        {
-          "name": "revenueAppAta",
+          "name": "userWriteBuffer",
           "writable": true,
           "pda": {
             "seeds": [
-              {
-                "kind": "account",
-                "path": "config"
-              },
               {
                 "kind": "const",
                 "value": [
-                  6,
-                  221,
-                  246,
-                  225,
-                  215,
+                  117,
+                  115,
                   101,
-                  161,
-                  147,
-                  217,
-                  203,
-                  225,
-                  70,
-                  206,
-                  235,
-                  121,
-                  172,
-                  28,
-                  180,
-                  133,
-                  237,
+                  114,
                   95,
-                  91,
-                  55,
-                  145,
-                  58,
-                  140,
-                  245,
-                  133,
-                  126,
-                  255,
-                  0,
-                  169
+                  119,
+                  114,
+                  105,
+                  116,
+                  101,
+                  95,
+                  98,
+                  117,
+                  102,
+                  102,
+                  101,
+                  114
                 ]
               },
               {
                 "kind": "account",
-                "path": "revenueMint"
+                "path": "user_id.id",
+                "account": "userId"
               }
-            ],
-            "program": {
-              "kind": "const",
-              "value": [
-                140,
-                151,
-                37,
-                143,
-                78,
-                36,
-                137,
-                241,
-                187,
-                61,
-                16,
-                41,
-                20,
-                142,
-                13,
-                131,
-                11,
-                90,
-                19,
-                153,
-                218,
-                255,
-                16,
-                132,
-                4,
-                142,
-                123,
-                216,
-                219,
-                233,
-                248,
-                89
-              ]
-            }
+            ]
           }
         }
       ],
       "args": [
         {
-          "name": "rotationTimeout",
-          "type": {
-            "option": "u32"
-          }
-        },
-        {
-          "name": "accountRegistrationFee",
-          "type": {
-            "option": {
-              "defined": {
-                "name": "assetItem"
-              }
-            }
-          }
-        },
-        {
-          "name": "accountDataSizeRange",
-          "type": {
-            "option": {
-              "defined": {
-                "name": "range"
-              }
-            }
-          }
+          "name": "dataSize",
+          "type": "u32"
         }
       ]
     },
     {
-      "name": "reopenAccount",
+      "name": "cancelAccountRotation",
       "docs": [
-        "2nd step to to change allocated data space"
+        "revokes pending or expired account rotation"
       ],
       "discriminator": [
-        5,
-        177,
-        67,
-        216,
-        10,
-        118,
-        229,
-        41
+        235,
+        37,
+        30,
+        210,
+        160,
+        215,
+        207,
+        121
       ],
       "accounts": [
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        },
         {
           "name": "sender",
-          "writable": true,
           "signer": true
         },
-        {
-          "name": "bump",
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  98,
-                  117,
-                  109,
-                  112
-                ]
-              }
-            ]
-          }
-        },
-        {
-          "name": "config",
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  99,
-                  111,
-                  110,
-                  102,
-                  105,
-                  103
-                ]
-              }
-            ]
-          }
-        },
         {
           "name": "userId",
-          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -1069,36 +1032,6 @@ export type Registry = {
             ]
           }
         },
-        {
-          "name": "userAccount",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  117,
-                  115,
-                  101,
-                  114,
-                  95,
-                  97,
-                  99,
-                  99,
-                  111,
-                  117,
-                  110,
-                  116
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "user_id.id",
-                "account": "userId"
-              }
-            ]
-          }
-        },
         {
           "name": "userRotationState",
           "writable": true,
@@ -1137,24 +1070,22 @@ export type Registry = {
           }
         }
       ],
-      "args": [
-        {
-          "name": "maxDataSize",
-          "type": "u32"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "requestAccountRotation",
+      "name": "cancelAdminRotation",
+      "docs": [
+        "revokes pending or expired admin rotation"
+      ],
       "discriminator": [
-        135,
-        32,
-        126,
-        239,
-        45,
+        213,
+        54,
+        2,
+        41,
+        251,
+        11,
         205,
-        141,
-        221
+        110
       ],
       "accounts": [
         {
@@ -1196,40 +1127,18 @@ export type Registry = {
           }
         },
         {
-          "name": "userId",
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  117,
-                  115,
-                  101,
-                  114,
-                  95,
-                  105,
-                  100
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "sender"
-              }
-            ]
-          }
-        },
-        {
-          "name": "userRotationState",
+          "name": "adminRotationState",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  117,
-                  115,
-                  101,
-                  114,
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
                   95,
                   114,
                   111,
@@ -1246,88 +1155,111 @@ export type Registry = {
                   116,
                   101
                 ]
-              },
-              {
-                "kind": "account",
-                "path": "user_id.id",
-                "account": "userId"
               }
             ]
           }
         }
       ],
-      "args": [
-        {
-          "name": "newOwner",
-          "type": "pubkey"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "updateConfig",
+      "name": "closeAccount",
+      "docs": [
+        "1st step to to change allocated data space or just to redeem rent"
+      ],
       "discriminator": [
-        29,
-        158,
-        252,
-        191,
-        10,
-        83,
-        219,
-        99
+        125,
+        255,
+        149,
+        14,
+        110,
+        34,
+        72,
+        24
       ],
       "accounts": [
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
         {
           "name": "sender",
+          "writable": true,
           "signer": true
         },
         {
-          "name": "bump",
+          "name": "rentRecipient",
+          "docs": [
+            "receives the rent, it's the sponsor if it reclaims rent or the sender otherwise"
+          ],
+          "writable": true
+        },
+        {
+          "name": "userId",
+          "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  98,
                   117,
-                  109,
-                  112
+                  115,
+                  101,
+                  114,
+                  95,
+                  105,
+                  100
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "sender"
               }
             ]
           }
         },
         {
-          "name": "config",
+          "name": "userAccount",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  97,
+                  99,
                   99,
                   111,
+                  117,
                   110,
-                  102,
-                  105,
-                  103
+                  116
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "user_id.id",
+                "account": "userId"
               }
             ]
           }
         },
         {
-          "name": "adminRotationState",
+          "name": "userRotationState",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  97,
-                  100,
-                  109,
-                  105,
-                  110,
+                  117,
+                  115,
+                  101,
+                  114,
                   95,
                   114,
                   111,
@@ -1344,80 +1276,143 @@ export type Registry = {
                   116,
                   101
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "user_id.id",
+                "account": "userId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userWriteBuffer",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  119,
+                  114,
+                  105,
+                  116,
+                  101,
+                  95,
+                  98,
+                  117,
+                  102,
+                  102,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user_id.id",
+                "account": "userId"
               }
             ]
           }
         }
       ],
-      "args": [
+      "args": []
+    },
+    {
+      "name": "closePromoCode",
+      "discriminator": [
+        217,
+        43,
+        141,
+        91,
+        90,
+        235,
+        120,
+        241
+      ],
+      "accounts": [
         {
-          "name": "admin",
-          "type": {
-            "option": "pubkey"
-          }
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
         },
         {
-          "name": "isPaused",
-          "type": {
-            "option": "bool"
-          }
+          "name": "sender",
+          "writable": true,
+          "signer": true
         },
         {
-          "name": "rotationTimeout",
-          "type": {
-            "option": "u32"
+          "name": "bump",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  117,
+                  109,
+                  112
+                ]
+              }
+            ]
           }
         },
         {
-          "name": "registrationFeeAmount",
-          "type": {
-            "option": "u64"
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
           }
         },
         {
-          "name": "dataSizeRange",
-          "type": {
-            "option": {
-              "defined": {
-                "name": "range"
-              }
-            }
-          }
+          "name": "promoCode",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "code",
+          "type": "string"
         }
       ]
     },
     {
-      "name": "withdrawRevenue",
+      "name": "commitDataWrite",
+      "docs": [
+        "3rd step, moves buffered data to user account and redeems the write buffer rent \\",
+        "commit fails if expected_nonce is specified and doesn't match stored one"
+      ],
       "discriminator": [
-        58,
-        241,
-        152,
-        184,
-        104,
-        150,
-        169,
-        119
+        38,
+        166,
+        24,
+        160,
+        139,
+        135,
+        252,
+        180
       ],
       "accounts": [
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        },
-        {
-          "name": "tokenProgram"
-        },
-        {
-          "name": "associatedTokenProgram",
-          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
-        },
         {
           "name": "sender",
           "writable": true,
           "signer": true
         },
-        {
-          "name": "recipient"
-        },
         {
           "name": "bump",
           "pda": {
@@ -1453,33 +1448,2875 @@ export type Registry = {
           }
         },
         {
-          "name": "revenueMint"
+          "name": "userId",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  105,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "sender"
+              }
+            ]
+          }
         },
         {
-          "name": "revenueRecipientAta",
+          "name": "userAccount",
           "writable": true,
           "pda": {
             "seeds": [
-              {
-                "kind": "account",
-                "path": "recipient"
-              },
               {
                 "kind": "const",
                 "value": [
-                  6,
-                  221,
-                  246,
-                  225,
-                  215,
+                  117,
+                  115,
                   101,
-                  161,
-                  147,
-                  217,
-                  203,
-                  225,
-                  70,
-                  206,
+                  114,
+                  95,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user_id.id",
+                "account": "userId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userWriteBuffer",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  119,
+                  114,
+                  105,
+                  116,
+                  101,
+                  95,
+                  98,
+                  117,
+                  102,
+                  102,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user_id.id",
+                "account": "userId"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "nonce",
+          "type": "u64"
+        },
+        {
+          "name": "expectedNonce",
+          "type": {
+            "option": "u64"
+          }
+        },
+        {
+          "name": "header",
+          "type": {
+            "defined": {
+              "name": "dataHeader"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "confirmAccountRotation",
+      "docs": [
+        "updates address - id pair"
+      ],
+      "discriminator": [
+        46,
+        196,
+        252,
+        234,
+        140,
+        190,
+        55,
+        250
+      ],
+      "accounts": [
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "sender",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bump",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  117,
+                  109,
+                  112
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "userIdPre",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  105,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user_rotation_state.owner",
+                "account": "rotationState"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userId",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  105,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "sender"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userRotationState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  114,
+                  111,
+                  116,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user_id_pre.id",
+                "account": "userId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user_id_pre.id",
+                "account": "userId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userWriteBuffer",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  119,
+                  114,
+                  105,
+                  116,
+                  101,
+                  95,
+                  98,
+                  117,
+                  102,
+                  102,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user_id_pre.id",
+                "account": "userId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userIndexPage",
+          "writable": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "confirmAdminRotation",
+      "discriminator": [
+        35,
+        96,
+        147,
+        139,
+        128,
+        212,
+        60,
+        237
+      ],
+      "accounts": [
+        {
+          "name": "sender",
+          "signer": true
+        },
+        {
+          "name": "bump",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  117,
+                  109,
+                  112
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "adminRotationState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  114,
+                  111,
+                  116,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createAccount",
+      "docs": [
+        "creates user PDA account taking rent exempt in SOL from the payer \\",
+        "the payer distinct from the sender is a sponsor, it can reclaim rent on account closing"
+      ],
+      "discriminator": [
+        99,
+        20,
+        130,
+        119,
+        196,
+        235,
+        131,
+        149
+      ],
+      "accounts": [
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "sender",
+          "signer": true
+        },
+        {
+          "name": "payer",
+          "docs": [
+            "pays rent, it's recorded as sponsor if it differs from the sender"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bump",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  117,
+                  109,
+                  112
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "userCounter",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116,
+                  101,
+                  114
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "userId",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  105,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "sender"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userAccount",
+          "writable": true
+        },
+        {
+          "name": "userRotationState",
+          "writable": true
+        },
+        {
+          "name": "userIndexPage",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "maxDataSize",
+          "type": "u32"
+        },
+        {
+          "name": "isRentReclaimable",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "createPromoCode",
+      "docs": [
+        "creates activation fee discount code, a part of discounted fee can be paid to referrer"
+      ],
+      "discriminator": [
+        48,
+        144,
+        66,
+        211,
+        158,
+        175,
+        91,
+        46
+      ],
+      "accounts": [
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "sender",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bump",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  117,
+                  109,
+                  112
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "promoCode",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "code",
+          "type": "string"
+        },
+        {
+          "name": "discountBps",
+          "type": "u16"
+        },
+        {
+          "name": "referrer",
+          "type": {
+            "option": "pubkey"
+          }
+        },
+        {
+          "name": "referrerShareBps",
+          "type": "u16"
+        },
+        {
+          "name": "usageLimit",
+          "type": {
+            "option": "u32"
+          }
+        },
+        {
+          "name": "expirationDate",
+          "type": {
+            "option": "u64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "forceCloseAccount",
+      "docs": [
+        "closes the account refunding rent to its owner"
+      ],
+      "discriminator": [
+        147,
+        196,
+        210,
+        27,
+        245,
+        5,
+        112,
+        83
+      ],
+      "accounts": [
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "sender",
+          "signer": true
+        },
+        {
+          "name": "rentRecipient",
+          "docs": [
+            "receives the rent, it's the sponsor if it reclaims rent or the owner otherwise"
+          ],
+          "writable": true
+        },
+        {
+          "name": "owner",
+          "docs": [
+            "receives the write buffer rent as it's always paid by the owner"
+          ],
+          "writable": true
+        },
+        {
+          "name": "bump",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  117,
+                  109,
+                  112
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "userId",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  105,
+                  100
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user_id.id",
+                "account": "userId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userRotationState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  114,
+                  111,
+                  116,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user_id.id",
+                "account": "userId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userWriteBuffer",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  119,
+                  114,
+                  105,
+                  116,
+                  101,
+                  95,
+                  98,
+                  117,
+                  102,
+                  102,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user_id.id",
+                "account": "userId"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "user",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "grantDelegate",
+      "docs": [
+        "allows the delegate to write data, existing permission is overwritten"
+      ],
+      "discriminator": [
+        250,
+        169,
+        110,
+        217,
+        41,
+        160,
+        97,
+        184
+      ],
+      "accounts": [
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "sender",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "userId",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  105,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "sender"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userDelegate",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user_id.id",
+                "account": "userId"
+              },
+              {
+                "kind": "arg",
+                "path": "delegate"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "delegate",
+          "type": "pubkey"
+        },
+        {
+          "name": "expirationDate",
+          "type": {
+            "option": "u64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "init",
+      "discriminator": [
+        220,
+        59,
+        207,
+        236,
+        108,
+        250,
+        47,
+        100
+      ],
+      "accounts": [
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "sender",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bump",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  117,
+                  109,
+                  112
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "userCounter",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116,
+                  101,
+                  114
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "adminRotationState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  114,
+                  111,
+                  116,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeWhitelist",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  119,
+                  104,
+                  105,
+                  116,
+                  101,
+                  108,
+                  105,
+                  115,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "revenueMint"
+        },
+        {
+          "name": "revenueAppAta",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "revenueMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "rotationTimeout",
+          "type": {
+            "option": "u32"
+          }
+        },
+        {
+          "name": "accountRegistrationFee",
+          "type": {
+            "option": {
+              "defined": {
+                "name": "assetItem"
+              }
+            }
+          }
+        },
+        {
+          "name": "accountDataSizeRange",
+          "type": {
+            "option": {
+              "defined": {
+                "name": "range"
+              }
+            }
+          }
+        },
+        {
+          "name": "activationPeriod",
+          "type": {
+            "option": "u32"
+          }
+        }
+      ]
+    },
+    {
+      "name": "migrate",
+      "docs": [
+        "upgrades Bump, Config, admin RotationState and user accounts to the latest layout \\",
+        "UserId and user RotationState accounts are passed as remaining accounts \\",
+        "FeeWhitelist is created if it's missing"
+      ],
+      "discriminator": [
+        155,
+        234,
+        231,
+        146,
+        236,
+        158,
+        162,
+        30
+      ],
+      "accounts": [
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "sender",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bump",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  117,
+                  109,
+                  112
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "adminRotationState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  114,
+                  111,
+                  116,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeWhitelist",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  119,
+                  104,
+                  105,
+                  116,
+                  101,
+                  108,
+                  105,
+                  115,
+                  116
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateUserAccount",
+      "docs": [
+        "allocates space for data header in accounts created before it was introduced"
+      ],
+      "discriminator": [
+        198,
+        152,
+        90,
+        54,
+        134,
+        206,
+        92,
+        198
+      ],
+      "accounts": [
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "sender",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "userId",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  105,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "sender"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user_id.id",
+                "account": "userId"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "renewActivation",
+      "docs": [
+        "extends account activation for another activation period with fee asset payment"
+      ],
+      "discriminator": [
+        43,
+        67,
+        207,
+        215,
+        210,
+        33,
+        225,
+        232
+      ],
+      "accounts": [
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "sender",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bump",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  117,
+                  109,
+                  112
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeWhitelist",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  119,
+                  104,
+                  105,
+                  116,
+                  101,
+                  108,
+                  105,
+                  115,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "userId",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  105,
+                  100
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "promoCode",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "revenueMint"
+        },
+        {
+          "name": "revenueSenderAta",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "sender"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "revenueMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "revenueAppAta",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "revenueMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "referrerAta",
+          "writable": true,
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "user",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "renewActivationWithSol",
+      "docs": [
+        "extends account activation for another activation period with fee payment in SOL"
+      ],
+      "discriminator": [
+        182,
+        83,
+        229,
+        86,
+        8,
+        250,
+        195,
+        250
+      ],
+      "accounts": [
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "sender",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bump",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  117,
+                  109,
+                  112
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeWhitelist",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  119,
+                  104,
+                  105,
+                  116,
+                  101,
+                  108,
+                  105,
+                  115,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "userId",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  105,
+                  100
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "promoCode",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "referrer",
+          "writable": true,
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "user",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "reopenAccount",
+      "docs": [
+        "2nd step to to change allocated data space"
+      ],
+      "discriminator": [
+        5,
+        177,
+        67,
+        216,
+        10,
+        118,
+        229,
+        41
+      ],
+      "accounts": [
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "sender",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bump",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  117,
+                  109,
+                  112
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "userId",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  105,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "sender"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user_id.id",
+                "account": "userId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userRotationState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  114,
+                  111,
+                  116,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user_id.id",
+                "account": "userId"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "maxDataSize",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "requestAccountRotation",
+      "docs": [
+        "data is cleared on confirmation if clear_data is specified, it isn't handed off to the new owner"
+      ],
+      "discriminator": [
+        135,
+        32,
+        126,
+        239,
+        45,
+        205,
+        141,
+        221
+      ],
+      "accounts": [
+        {
+          "name": "sender",
+          "signer": true
+        },
+        {
+          "name": "bump",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  117,
+                  109,
+                  112
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "userId",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  105,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "sender"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userRotationState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  114,
+                  111,
+                  116,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user_id.id",
+                "account": "userId"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "newOwner",
+          "type": "pubkey"
+        },
+        {
+          "name": "clearData",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "resizeAccount",
+      "docs": [
+        "changes allocated data space keeping stored data, tops up or redeems rent \\",
+        "rent of sponsored account is paid and redeemed by the sponsor if it reclaims rent"
+      ],
+      "discriminator": [
+        89,
+        215,
+        202,
+        211,
+        3,
+        206,
+        175,
+        157
+      ],
+      "accounts": [
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "sender",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "rentPayer",
+          "docs": [
+            "pays rent on growing and receives redeemed rent on shrinking to keep the account rent \\",
+            "refundable to the party that paid it, it's the sponsor if it reclaims rent or the sender otherwise"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bump",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  117,
+                  109,
+                  112
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "userId",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  105,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "sender"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user_id.id",
+                "account": "userId"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "maxDataSize",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "revokeDelegate",
+      "docs": [
+        "removes the delegate permission and redeems its rent"
+      ],
+      "discriminator": [
+        142,
+        66,
+        98,
+        126,
+        102,
+        60,
+        92,
+        163
+      ],
+      "accounts": [
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "sender",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "userId",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  105,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "sender"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userDelegate",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user_id.id",
+                "account": "userId"
+              },
+              {
+                "kind": "arg",
+                "path": "delegate"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "delegate",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "suspendAccount",
+      "docs": [
+        "blocks data writes and rotations of the account"
+      ],
+      "discriminator": [
+        11,
+        212,
+        15,
+        209,
+        44,
+        72,
+        23,
+        50
+      ],
+      "accounts": [
+        {
+          "name": "sender",
+          "signer": true
+        },
+        {
+          "name": "bump",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  117,
+                  109,
+                  112
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "userId",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  105,
+                  100
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "user"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "user",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "unsuspendAccount",
+      "discriminator": [
+        118,
+        88,
+        231,
+        178,
+        205,
+        122,
+        221,
+        110
+      ],
+      "accounts": [
+        {
+          "name": "sender",
+          "signer": true
+        },
+        {
+          "name": "bump",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  117,
+                  109,
+                  112
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "userId",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  105,
+                  100
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "user"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "user",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "updateConfig",
+      "discriminator": [
+        29,
+        158,
+        252,
+        191,
+        10,
+        83,
+        219,
+        99
+      ],
+      "accounts": [
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "sender",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bump",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  117,
+                  109,
+                  112
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "adminRotationState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  114,
+                  111,
+                  116,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeWhitelist",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  119,
+                  104,
+                  105,
+                  116,
+                  101,
+                  108,
+                  105,
+                  115,
+                  116
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": {
+              "name": "updateConfigArgs"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "withdrawRevenue",
+      "docs": [
+        "withdraws any token held by the config to specified recipient"
+      ],
+      "discriminator": [
+        58,
+        241,
+        152,
+        184,
+        104,
+        150,
+        169,
+        119
+      ],
+      "accounts": [
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "sender",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "recipient"
+        },
+        {
+          "name": "bump",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  117,
+                  109,
+                  112
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "revenueMint"
+        },
+        {
+          "name": "revenueRecipientAta",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "recipient"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "revenueMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "revenueAppAta",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
                   235,
                   121,
                   172,
@@ -1503,150 +4340,314 @@ export type Registry = {
               },
               {
                 "kind": "account",
-                "path": "revenueMint"
+                "path": "revenueMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": {
+            "option": "u64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "withdrawRevenueWithSol",
+      "docs": [
+        "withdraws SOL held by the config above rent exempt to specified recipient"
+      ],
+      "discriminator": [
+        65,
+        117,
+        72,
+        45,
+        125,
+        38,
+        89,
+        252
+      ],
+      "accounts": [
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "sender",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "recipient",
+          "writable": true
+        },
+        {
+          "name": "bump",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  117,
+                  109,
+                  112
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": {
+            "option": "u64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "writeBytes",
+      "docs": [
+        "the same as write_data but stores raw bytes, it lets avoid encoding of encrypted data"
+      ],
+      "discriminator": [
+        156,
+        19,
+        172,
+        77,
+        215,
+        154,
+        204,
+        218
+      ],
+      "accounts": [
+        {
+          "name": "sender",
+          "docs": [
+            "the owner or its delegate"
+          ],
+          "signer": true
+        },
+        {
+          "name": "bump",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  117,
+                  109,
+                  112
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "userId",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  105,
+                  100
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user_id.id",
+                "account": "userId"
               }
-            ],
-            "program": {
-              "kind": "const",
-              "value": [
-                140,
-                151,
-                37,
-                143,
-                78,
-                36,
-                137,
-                241,
-                187,
-                61,
-                16,
-                41,
-                20,
-                142,
-                13,
-                131,
-                11,
-                90,
-                19,
-                153,
-                218,
-                255,
-                16,
-                132,
-                4,
-                142,
-                123,
-                216,
-                219,
-                233,
-                248,
-                89
-              ]
-            }
+            ]
           }
         },
         {
-          "name": "revenueAppAta",
-          "writable": true,
+          "name": "userDelegate",
+          "optional": true,
           "pda": {
             "seeds": [
-              {
-                "kind": "account",
-                "path": "config"
-              },
               {
                 "kind": "const",
                 "value": [
-                  6,
-                  221,
-                  246,
-                  225,
-                  215,
+                  117,
+                  115,
                   101,
-                  161,
-                  147,
-                  217,
-                  203,
-                  225,
-                  70,
-                  206,
-                  235,
-                  121,
-                  172,
-                  28,
-                  180,
-                  133,
-                  237,
+                  114,
                   95,
-                  91,
-                  55,
-                  145,
-                  58,
-                  140,
-                  245,
-                  133,
-                  126,
-                  255,
-                  0,
-                  169
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
                 ]
               },
               {
                 "kind": "account",
-                "path": "revenueMint"
+                "path": "user_id.id",
+                "account": "userId"
+              },
+              {
+                "kind": "account",
+                "path": "sender"
               }
-            ],
-            "program": {
-              "kind": "const",
-              "value": [
-                140,
-                151,
-                37,
-                143,
-                78,
-                36,
-                137,
-                241,
-                187,
-                61,
-                16,
-                41,
-                20,
-                142,
-                13,
-                131,
-                11,
-                90,
-                19,
-                153,
-                218,
-                255,
-                16,
-                132,
-                4,
-                142,
-                123,
-                216,
-                219,
-                233,
-                248,
-                89
-              ]
-            }
+            ]
           }
         }
       ],
       "args": [
         {
-          "name": "amount",
+          "name": "user",
+          "type": "pubkey"
+        },
+        {
+          "name": "data",
+          "type": "bytes"
+        },
+        {
+          "name": "nonce",
+          "type": "u64"
+        },
+        {
+          "name": "expectedNonce",
           "type": {
             "option": "u64"
           }
+        },
+        {
+          "name": "header",
+          "type": {
+            "defined": {
+              "name": "dataHeader"
+            }
+          }
         }
       ]
     },
     {
       "name": "writeData",
+      "docs": [
+        "can be called by the owner or its delegate \\",
+        "write fails if expected_nonce is specified and doesn't match stored one \\",
+        "header must describe the data, it's stored alongside to let clients decode it"
+      ],
       "discriminator": [
         211,
         152,
@@ -1660,8 +4661,45 @@ export type Registry = {
       "accounts": [
         {
           "name": "sender",
+          "docs": [
+            "the owner or its delegate"
+          ],
           "signer": true
         },
+        {
+          "name": "bump",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  117,
+                  109,
+                  112
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
         {
           "name": "userId",
           "pda": {
@@ -1679,8 +4717,8 @@ export type Registry = {
                 ]
               },
               {
-                "kind": "account",
-                "path": "sender"
+                "kind": "arg",
+                "path": "user"
               }
             ]
           }
@@ -1714,9 +4752,48 @@ export type Registry = {
               }
             ]
           }
+        },
+        {
+          "name": "userDelegate",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user_id.id",
+                "account": "userId"
+              },
+              {
+                "kind": "account",
+                "path": "sender"
+              }
+            ]
+          }
         }
       ],
       "args": [
+        {
+          "name": "user",
+          "type": "pubkey"
+        },
         {
           "name": "data",
           "type": "string"
@@ -1724,6 +4801,20 @@ export type Registry = {
         {
           "name": "nonce",
           "type": "u64"
+        },
+        {
+          "name": "expectedNonce",
+          "type": {
+            "option": "u64"
+          }
+        },
+        {
+          "name": "header",
+          "type": {
+            "defined": {
+              "name": "dataHeader"
+            }
+          }
         }
       ]
     }
@@ -1755,6 +4846,32 @@ export type Registry = {
         130
       ]
     },
+    {
+      "name": "feeWhitelist",
+      "discriminator": [
+        6,
+        209,
+        183,
+        73,
+        67,
+        24,
+        102,
+        82
+      ]
+    },
+    {
+      "name": "promoCode",
+      "discriminator": [
+        253,
+        157,
+        67,
+        176,
+        230,
+        182,
+        64,
+        61
+      ]
+    },
     {
       "name": "rotationState",
       "discriminator": [
@@ -1794,6 +4911,19 @@ export type Registry = {
         227
       ]
     },
+    {
+      "name": "userDelegate",
+      "discriminator": [
+        22,
+        175,
+        242,
+        4,
+        224,
+        165,
+        80,
+        187
+      ]
+    },
     {
       "name": "userId",
       "discriminator": [
@@ -1806,6 +4936,32 @@ export type Registry = {
         120,
         243
       ]
+    },
+    {
+      "name": "userIndexPage",
+      "discriminator": [
+        242,
+        121,
+        62,
+        81,
+        223,
+        133,
+        236,
+        124
+      ]
+    },
+    {
+      "name": "writeBuffer",
+      "discriminator": [
+        249,
+        21,
+        89,
+        172,
+        126,
+        191,
+        94,
+        220
+      ]
     }
   ],
   "types": [
@@ -1844,6 +5000,10 @@ export type Registry = {
           {
             "name": "rotationState",
             "type": "u8"
+          },
+          {
+            "name": "version",
+            "type": "u8"
           }
         ]
       }
@@ -1861,8 +5021,11 @@ export type Registry = {
             "type": "pubkey"
           },
           {
-            "name": "isPaused",
-            "type": "bool"
+            "name": "pausedOps",
+            "docs": [
+              "bit mask of paused operations, see base::pause"
+            ],
+            "type": "u8"
           },
           {
             "name": "rotationTimeout",
@@ -1883,6 +5046,130 @@ export type Registry = {
                 "name": "range"
               }
             }
+          },
+          {
+            "name": "activationPeriod",
+            "docs": [
+              "activation is extended by this period for each fee payment"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "dataHeader",
+      "docs": [
+        "describes UserAccount.data so client apps sharing the registry can decode it \\",
+        "schema and encryption scheme ids are defined by clients, the program only stores them"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "schemaId",
+            "type": "u16"
+          },
+          {
+            "name": "encryptionSchemeId",
+            "type": "u16"
+          },
+          {
+            "name": "contentLength",
+            "docs": [
+              "data length in bytes"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "checksum",
+            "docs": [
+              "sha256 of data"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "feeWhitelist",
+      "docs": [
+        "assets accepted for account activation in addition to Config.registration_fee"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "value",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "assetItem"
+                }
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "promoCode",
+      "docs": [
+        "get by code: String \\",
+        "discount on activation fee created by admin, a part of discounted fee can be paid to referrer"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "code",
+            "type": "string"
+          },
+          {
+            "name": "discountBps",
+            "type": "u16"
+          },
+          {
+            "name": "referrer",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "referrerShareBps",
+            "docs": [
+              "share of discounted fee paid to referrer"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "usageLimit",
+            "type": {
+              "option": "u32"
+            }
+          },
+          {
+            "name": "usageCount",
+            "type": "u32"
+          },
+          {
+            "name": "expirationDate",
+            "type": {
+              "option": "u64"
+            }
           }
         ]
       }
@@ -1925,6 +5212,83 @@ export type Registry = {
           {
             "name": "expirationDate",
             "type": "u64"
+          },
+          {
+            "name": "clearData",
+            "docs": [
+              "user data is cleared on confirmation to not expose it to the new owner"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "updateConfigArgs",
+      "docs": [
+        "config fields to update, unspecified ones are kept, at least one must be specified"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "docs": [
+              "new admin must confirm the rotation"
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "pausedOps",
+            "type": {
+              "option": "u8"
+            }
+          },
+          {
+            "name": "rotationTimeout",
+            "type": {
+              "option": "u32"
+            }
+          },
+          {
+            "name": "registrationFeeAmount",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "dataSizeRange",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "range"
+                }
+              }
+            }
+          },
+          {
+            "name": "feeWhitelist",
+            "type": {
+              "option": {
+                "vec": {
+                  "defined": {
+                    "name": "assetItem"
+                  }
+                }
+              }
+            }
+          },
+          {
+            "name": "activationPeriod",
+            "type": {
+              "option": "u32"
+            }
           }
         ]
       }
@@ -1942,7 +5306,7 @@ export type Registry = {
             "docs": [
               "encrypted user data"
             ],
-            "type": "string"
+            "type": "bytes"
           },
           {
             "name": "nonce",
@@ -1957,6 +5321,17 @@ export type Registry = {
               "allocated storage capacity"
             ],
             "type": "u32"
+          },
+          {
+            "name": "header",
+            "docs": [
+              "format of the data"
+            ],
+            "type": {
+              "defined": {
+                "name": "dataHeader"
+              }
+            }
           }
         ]
       }
@@ -1976,6 +5351,34 @@ export type Registry = {
         ]
       }
     },
+    {
+      "name": "userDelegate",
+      "docs": [
+        "get by user_id: u32, delegate: Pubkey \\",
+        "allows the delegate to write data on behalf of the owner"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "docs": [
+              "account owner granted the permission, it's reset by account rotation"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "expirationDate",
+            "docs": [
+              "permission is valid until this date if it's specified"
+            ],
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
+    },
     {
       "name": "userId",
       "docs": [
@@ -2003,6 +5406,84 @@ export type Registry = {
           {
             "name": "rotationStateBump",
             "type": "u8"
+          },
+          {
+            "name": "activatedUntil",
+            "docs": [
+              "activation expiration date"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "isSuspended",
+            "docs": [
+              "blocks data writes and rotations, set by admin"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "sponsor",
+            "docs": [
+              "paid rent on account creation instead of the owner"
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "isRentReclaimable",
+            "docs": [
+              "rent is refunded to the sponsor on account closing"
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "userIndexPage",
+      "docs": [
+        "get by page: u32 \\",
+        "reverse lookup (user_id -> owner), user_id = page * USER_INDEX_PAGE_SIZE + position + 1"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owners",
+            "type": {
+              "vec": "pubkey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "writeBuffer",
+      "docs": [
+        "get by user_id: u32 \\",
+        "stages data exceeding a single transaction until it's committed to UserAccount"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "data",
+            "docs": [
+              "encrypted user data received so far"
+            ],
+            "type": "bytes"
+          },
+          {
+            "name": "size",
+            "docs": [
+              "expected data length"
+            ],
+            "type": "u32"
           }
         ]
       }
@@ -2,16 +2,19 @@ use {
     crate::helpers::{
         extensions::clmm_mock::{calc_token_amount_for_pool, sort_tokens, ClmmMockExtension},
         suite::{
-            core::App,
+            core::{assert_error, App},
             types::{AppToken, AppUser},
         },
     },
     anchor_lang::Result,
+    clmm_mock::error::ErrorCode,
     pretty_assertions::assert_eq,
 };
 
 pub const AMM_CONFIG_INDEX_0: u16 = 0;
 pub const AMM_CONFIG_INDEX_1: u16 = 1;
+/// 20 bps in hundredths of a bip
+pub const TRADE_FEE_RATE: u32 = 2_000;
 
 pub fn prepare_dex(
     app: &mut App,
@@ -24,7 +27,7 @@ pub fn prepare_dex(
     for (amm_index, token_0, token_1) in config {
         let (token_0, token_1) = sort_tokens(*token_0, *token_1);
 
-        app.clmm_mock_try_create_amm_config(AppUser::Admin, *amm_index, 1, 1, 1, 1)?;
+        app.clmm_mock_try_create_amm_config(AppUser::Admin, *amm_index, 1, TRADE_FEE_RATE, 1, 1)?;
         app.clmm_mock_try_create_pool(
             AppUser::Admin,
             1,
@@ -61,7 +64,7 @@ fn swap_default() -> Result<()> {
     app.wait(1_000);
 
     app.clmm_mock_try_create_operation_account(AppUser::Admin)?;
-    app.clmm_mock_try_create_amm_config(AppUser::Admin, AMM_CONFIG_INDEX, 1, 1, 1, 1)?;
+    app.clmm_mock_try_create_amm_config(AppUser::Admin, AMM_CONFIG_INDEX, 1, TRADE_FEE_RATE, 1, 1)?;
     app.clmm_mock_try_create_pool(
         AppUser::Alice,
         1,
@@ -285,6 +288,57 @@ fn swap_multihop_reversed() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn swap_multihop_base_out() -> Result<()> {
+    const AMOUNT_OUT: u64 = 9_960_020;
+    const AMOUNT_IN_MAXIMUM: u64 = 1_000;
+
+    let mut app = App::new();
+    prepare_dex(
+        &mut app,
+        &[
+            (AMM_CONFIG_INDEX_0, AppToken::USDC, AppToken::PYTH),
+            (AMM_CONFIG_INDEX_1, AppToken::WBTC, AppToken::USDC),
+        ],
+        None,
+    )?;
+
+    let route_with_configs = [
+        (AppToken::WBTC, AMM_CONFIG_INDEX_1), // index is unused
+        (AppToken::USDC, AMM_CONFIG_INDEX_1), // WBTC -> USDC uses config_1
+        (AppToken::PYTH, AMM_CONFIG_INDEX_0), // USDC -> PYTH uses config_0
+    ];
+
+    // required input exceeds the limit
+    let res = app
+        .clmm_mock_try_swap_multihop_base_out(AppUser::Bob, AMOUNT_OUT, 900, &route_with_configs)
+        .unwrap_err();
+    assert_error(res, ErrorCode::TooMuchInputPaid);
+
+    // swap WBTC -> USDC -> PYTH receiving exact PYTH amount
+    let bob_wbtc_before = app.get_balance(AppUser::Bob, AppToken::WBTC);
+    let bob_usdc_before = app.get_balance(AppUser::Bob, AppToken::USDC);
+    let bob_pyth_before = app.get_balance(AppUser::Bob, AppToken::PYTH);
+
+    app.clmm_mock_try_swap_multihop_base_out(
+        AppUser::Bob,
+        AMOUNT_OUT,
+        AMOUNT_IN_MAXIMUM,
+        &route_with_configs,
+    )?;
+
+    let bob_wbtc_after = app.get_balance(AppUser::Bob, AppToken::WBTC);
+    let bob_usdc_after = app.get_balance(AppUser::Bob, AppToken::USDC);
+    let bob_pyth_after = app.get_balance(AppUser::Bob, AppToken::PYTH);
+
+    assert!(bob_wbtc_before - bob_wbtc_after <= AMOUNT_IN_MAXIMUM);
+    // intermediate token isn't left on the user account
+    assert_eq!(bob_usdc_after, bob_usdc_before);
+    assert_eq!(bob_pyth_after - bob_pyth_before, AMOUNT_OUT);
+
+    Ok(())
+}
+
 #[test]
 fn swap_multihop_single_pool() -> Result<()> {
     let mut app = App::new();
@@ -327,7 +381,14 @@ fn swap_multihop_same_config() -> Result<()> {
     let mut app = App::new();
     app.wait(1_000);
     app.clmm_mock_try_create_operation_account(AppUser::Admin)?;
-    app.clmm_mock_try_create_amm_config(AppUser::Admin, AMM_CONFIG_INDEX_0, 1, 1, 1, 1)?;
+    app.clmm_mock_try_create_amm_config(
+        AppUser::Admin,
+        AMM_CONFIG_INDEX_0,
+        1,
+        TRADE_FEE_RATE,
+        1,
+        1,
+    )?;
 
     for (amm_index, token_0, token_1) in [
         (AMM_CONFIG_INDEX_0, AppToken::USDC, AppToken::PYTH),
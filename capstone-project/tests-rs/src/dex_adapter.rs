@@ -3,18 +3,29 @@ use {
         clmm_mock::{prepare_dex, AMM_CONFIG_INDEX_0, AMM_CONFIG_INDEX_1},
         helpers::{
             extensions::{
+                amm::AmmExtension, clmm_mock::calc_token_amount_for_pool,
                 dex_adapter::DexAdapterExtension, registry::RegistryExtension, wsol::WsolExtension,
             },
             suite::{
-                core::App,
+                core::{assert_error, extension::get_events, App},
                 types::{AppCoin, AppToken, AppUser},
             },
         },
     },
+    amm_cpi::math::{calc_amount_out, calc_fee},
     anchor_lang::Result,
+    base::{
+        error::AuthError,
+        helpers::DISCRIMINATOR_SPACE,
+        migration::Versioned,
+        pause::{PAUSE_NONE, PAUSE_SWAP, PAUSE_SWAP_AND_ACTIVATE, PAUSE_UNWRAP},
+    },
+    clmm_mock::error::ErrorCode as ClmmErrorCode,
     dex_adapter_cpi::{
-        state::{DaConfig, ROTATION_TIMEOUT},
-        types::RouteItem,
+        error::CustomError,
+        events::AccountMigrated,
+        state::{DaConfig, Route, BPS_MAX, FEE_BPS_MAX, ROTATION_TIMEOUT, ROUTE_MAX_LEN},
+        types::{DexKind, FeeSide, RouteItem, UpdateConfigArgs},
     },
     pretty_assertions::assert_eq,
     registry_cpi::{state::ACCOUNT_REGISTRATION_FEE_AMOUNT, types::AssetItem},
@@ -22,28 +33,1101 @@ use {
     solana_pubkey::Pubkey,
 };
 
-#[test]
-fn init_default() -> Result<()> {
-    let mut app = App::new();
+#[test]
+fn init_default() -> Result<()> {
+    let mut app = App::new();
+
+    app.dex_adapter_try_init(AppUser::Admin, Pubkey::default(), None, None)?;
+
+    assert_eq!(
+        app.dex_adapter_query_config()?,
+        DaConfig {
+            admin: AppUser::Admin.pubkey(),
+            dex: Pubkey::default(),
+            registry: None,
+            paused_ops: PAUSE_NONE,
+            rotation_timeout: ROTATION_TIMEOUT,
+            fee_bps: 0,
+            amm: None,
+            fee_side: FeeSide::Input,
+            version: DaConfig::VERSION,
+        }
+    );
+
+    Ok(())
+}
+
+#[test]
+fn cancel_admin_rotation() -> Result<()> {
+    let mut app = App::new();
+
+    app.dex_adapter_try_init(AppUser::Admin, Pubkey::default(), None, None)?;
+
+    // nothing to cancel
+    let res = app
+        .dex_adapter_try_cancel_admin_rotation(AppUser::Admin)
+        .unwrap_err();
+    assert_error(res, AuthError::NoRotationToCancel);
+
+    app.dex_adapter_try_update_config(
+        AppUser::Admin,
+        UpdateConfigArgs {
+            admin: Some(AppUser::Alice.pubkey()),
+            ..Default::default()
+        },
+    )?;
+
+    // only admin can cancel admin rotation
+    let res = app
+        .dex_adapter_try_cancel_admin_rotation(AppUser::Alice)
+        .unwrap_err();
+    assert_error(res, AuthError::Unauthorized);
+
+    app.dex_adapter_try_cancel_admin_rotation(AppUser::Admin)?;
+    assert_eq!(
+        app.dex_adapter_query_admin_rotation_state()?.new_owner,
+        None
+    );
+
+    // cancelled rotation can't be confirmed
+    let res = app
+        .dex_adapter_try_confirm_admin_rotation(AppUser::Alice)
+        .unwrap_err();
+    assert_error(res, AuthError::NoNewOwner);
+
+    Ok(())
+}
+
+#[test]
+fn swap_multihop() -> Result<()> {
+    let mut app = App::new();
+    prepare_dex(
+        &mut app,
+        &[
+            (AMM_CONFIG_INDEX_0, AppToken::USDC, AppToken::PYTH),
+            (AMM_CONFIG_INDEX_1, AppToken::WBTC, AppToken::USDC),
+        ],
+        None,
+    )?;
+
+    app.dex_adapter_try_init(AppUser::Admin, app.program_id.clmm_mock, None, None)?;
+    app.dex_adapter_try_save_route(
+        AppUser::Admin,
+        AppToken::WBTC,
+        AppToken::PYTH,
+        &[
+            RouteItem {
+                amm_index: AMM_CONFIG_INDEX_1,
+                token_out: AppToken::USDC.pubkey(), // WBTC -> USDC (first hop output)
+                dex: DexKind::Clmm,
+                pool_id: 0,
+            },
+            RouteItem {
+                amm_index: AMM_CONFIG_INDEX_0,
+                token_out: AppToken::PYTH.pubkey(), // USDC -> PYTH (second hop output)
+                dex: DexKind::Clmm,
+                pool_id: 0,
+            },
+        ],
+        None,
+        None,
+    )?;
+
+    // swap WBTC -> USDC -> PYTH
+    let bob_wbtc_before = app.get_balance(AppUser::Bob, AppToken::WBTC);
+    let bob_pyth_before = app.get_balance(AppUser::Bob, AppToken::PYTH);
+
+    app.dex_adapter_try_swap_multihop(
+        AppUser::Bob,
+        AppToken::WBTC,
+        AppToken::PYTH,
+        1_000,
+        9_950_000,
+        None,
+    )?;
+
+    let bob_wbtc_after = app.get_balance(AppUser::Bob, AppToken::WBTC);
+    let bob_pyth_after = app.get_balance(AppUser::Bob, AppToken::PYTH);
+
+    assert_eq!(bob_wbtc_before - bob_wbtc_after, 1_000);
+    assert_eq!(bob_pyth_after - bob_pyth_before, 9_960_020);
+
+    Ok(())
+}
+
+#[test]
+fn swap_paused() -> Result<()> {
+    let mut app = App::new();
+    prepare_dex(
+        &mut app,
+        &[(AMM_CONFIG_INDEX_0, AppToken::USDC, AppToken::PYTH)],
+        None,
+    )?;
+
+    app.dex_adapter_try_init(AppUser::Admin, app.program_id.clmm_mock, None, None)?;
+    app.dex_adapter_try_save_route(
+        AppUser::Admin,
+        AppToken::USDC,
+        AppToken::PYTH,
+        &[RouteItem {
+            amm_index: AMM_CONFIG_INDEX_0,
+            token_out: AppToken::PYTH.pubkey(),
+            dex: DexKind::Clmm,
+            pool_id: 0,
+        }],
+        None,
+        None,
+    )?;
+
+    // pausing other operation doesn't affect swap
+    app.dex_adapter_try_update_config(
+        AppUser::Admin,
+        UpdateConfigArgs {
+            paused_ops: Some(PAUSE_UNWRAP),
+            ..Default::default()
+        },
+    )?;
+    app.dex_adapter_try_swap_multihop(
+        AppUser::Bob,
+        AppToken::USDC,
+        AppToken::PYTH,
+        1_000,
+        1,
+        None,
+    )?;
+
+    app.dex_adapter_try_update_config(
+        AppUser::Admin,
+        UpdateConfigArgs {
+            paused_ops: Some(PAUSE_SWAP | PAUSE_UNWRAP),
+            ..Default::default()
+        },
+    )?;
+    let res = app
+        .dex_adapter_try_swap_multihop(AppUser::Bob, AppToken::USDC, AppToken::PYTH, 1_000, 1, None)
+        .unwrap_err();
+    assert_error(res, CustomError::ContractPaused);
+
+    app.dex_adapter_try_update_config(
+        AppUser::Admin,
+        UpdateConfigArgs {
+            paused_ops: Some(PAUSE_NONE),
+            ..Default::default()
+        },
+    )?;
+    app.dex_adapter_try_swap_multihop(
+        AppUser::Bob,
+        AppToken::USDC,
+        AppToken::PYTH,
+        1_000,
+        1,
+        None,
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn swap_route_guards() -> Result<()> {
+    let mut app = App::new();
+    prepare_dex(
+        &mut app,
+        &[
+            (AMM_CONFIG_INDEX_0, AppToken::USDC, AppToken::PYTH),
+            (AMM_CONFIG_INDEX_1, AppToken::USDC, AppToken::PYTH),
+        ],
+        None,
+    )?;
+
+    app.dex_adapter_try_init(AppUser::Admin, app.program_id.clmm_mock, None, None)?;
+    app.dex_adapter_try_save_route(
+        AppUser::Admin,
+        AppToken::USDC,
+        AppToken::PYTH,
+        &[RouteItem {
+            amm_index: AMM_CONFIG_INDEX_0,
+            token_out: AppToken::PYTH.pubkey(),
+            dex: DexKind::Clmm,
+            pool_id: 0,
+        }],
+        None,
+        None,
+    )?;
+
+    // pool isn't approved by admin
+    let res = app
+        .dex_adapter_try_swap_multihop(
+            AppUser::Bob,
+            AppToken::USDC,
+            AppToken::PYTH,
+            1_000,
+            1,
+            Some(&[RouteItem {
+                amm_index: AMM_CONFIG_INDEX_1,
+                token_out: AppToken::PYTH.pubkey(),
+                dex: DexKind::Clmm,
+                pool_id: 0,
+            }]),
+        )
+        .unwrap_err();
+    assert_error(res, CustomError::RouteMismatch);
+
+    // extra hop isn't allowed
+    let res = app
+        .dex_adapter_try_swap_multihop(
+            AppUser::Bob,
+            AppToken::USDC,
+            AppToken::PYTH,
+            1_000,
+            1,
+            Some(&[
+                RouteItem {
+                    amm_index: AMM_CONFIG_INDEX_0,
+                    token_out: AppToken::PYTH.pubkey(),
+                    dex: DexKind::Clmm,
+                    pool_id: 0,
+                },
+                RouteItem {
+                    amm_index: AMM_CONFIG_INDEX_1,
+                    token_out: AppToken::USDC.pubkey(),
+                    dex: DexKind::Clmm,
+                    pool_id: 0,
+                },
+            ]),
+        )
+        .unwrap_err();
+    assert_error(res, CustomError::RouteMismatch);
+
+    // saved route works
+    app.dex_adapter_try_swap_multihop(
+        AppUser::Bob,
+        AppToken::USDC,
+        AppToken::PYTH,
+        1_000,
+        1,
+        None,
+    )?;
+
+    // updated route replaces the previous one
+    app.dex_adapter_try_save_route(
+        AppUser::Admin,
+        AppToken::USDC,
+        AppToken::PYTH,
+        &[RouteItem {
+            amm_index: AMM_CONFIG_INDEX_1,
+            token_out: AppToken::PYTH.pubkey(),
+            dex: DexKind::Clmm,
+            pool_id: 0,
+        }],
+        None,
+        None,
+    )?;
+    app.dex_adapter_try_swap_multihop(
+        AppUser::Bob,
+        AppToken::USDC,
+        AppToken::PYTH,
+        1_000,
+        1,
+        None,
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn save_route_guards() -> Result<()> {
+    let mut app = App::new();
+    prepare_dex(
+        &mut app,
+        &[(AMM_CONFIG_INDEX_0, AppToken::USDC, AppToken::PYTH)],
+        None,
+    )?;
+    app.dex_adapter_try_init(AppUser::Admin, app.program_id.clmm_mock, None, None)?;
+
+    let hop = RouteItem {
+        amm_index: AMM_CONFIG_INDEX_0,
+        token_out: AppToken::PYTH.pubkey(),
+        dex: DexKind::Clmm,
+        pool_id: 0,
+    };
+
+    // empty route
+    let res = app
+        .dex_adapter_try_save_route(
+            AppUser::Admin,
+            AppToken::USDC,
+            AppToken::PYTH,
+            &[],
+            None,
+            None,
+        )
+        .unwrap_err();
+    assert_error(res, CustomError::InvalidRouteLength);
+
+    // too long route
+    let res = app
+        .dex_adapter_try_save_route(
+            AppUser::Admin,
+            AppToken::USDC,
+            AppToken::PYTH,
+            &vec![hop.clone(); ROUTE_MAX_LEN + 1],
+            None,
+            None,
+        )
+        .unwrap_err();
+    assert_error(res, CustomError::InvalidRouteLength);
+
+    // route output isn't the last mint
+    let res = app
+        .dex_adapter_try_save_route(
+            AppUser::Admin,
+            AppToken::USDC,
+            AppToken::WBTC,
+            &[hop.clone()],
+            None,
+            None,
+        )
+        .unwrap_err();
+    assert_error(res, CustomError::RouteOutputMismatch);
+
+    // hop doesn't change the token
+    let res = app
+        .dex_adapter_try_save_route(
+            AppUser::Admin,
+            AppToken::USDC,
+            AppToken::PYTH,
+            &[hop.clone(), hop.clone()],
+            None,
+            None,
+        )
+        .unwrap_err();
+    assert_error(res, CustomError::SameHopTokens);
+
+    app.dex_adapter_try_save_route(
+        AppUser::Admin,
+        AppToken::USDC,
+        AppToken::PYTH,
+        &[hop],
+        None,
+        None,
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn swap_mixed_route_guards() -> Result<()> {
+    let mut app = App::new();
+    prepare_dex(
+        &mut app,
+        &[(AMM_CONFIG_INDEX_1, AppToken::WBTC, AppToken::USDC)],
+        None,
+    )?;
+
+    // WBTC -> USDC on clmm, USDC -> PYTH on amm
+    let route = [
+        RouteItem {
+            amm_index: AMM_CONFIG_INDEX_1,
+            token_out: AppToken::USDC.pubkey(),
+            dex: DexKind::Clmm,
+            pool_id: 0,
+        },
+        RouteItem {
+            amm_index: 0,
+            token_out: AppToken::PYTH.pubkey(),
+            dex: DexKind::Amm,
+            pool_id: AMM_POOL_ID,
+        },
+    ];
+
+    app.dex_adapter_try_init(AppUser::Admin, app.program_id.clmm_mock, None, None)?;
+    app.dex_adapter_try_save_route(
+        AppUser::Admin,
+        AppToken::WBTC,
+        AppToken::PYTH,
+        &route,
+        None,
+        None,
+    )?;
+    assert_eq!(
+        app.dex_adapter_query_route(&AppToken::WBTC.pubkey(), &AppToken::PYTH.pubkey())?
+            .value,
+        route
+    );
+
+    // amm program isn't specified
+    let res = app
+        .dex_adapter_try_swap_multihop(AppUser::Bob, AppToken::WBTC, AppToken::PYTH, 1_000, 0, None)
+        .unwrap_err();
+    assert_error(res, CustomError::DexNotConfigured);
+
+    app.dex_adapter_try_update_config(
+        AppUser::Admin,
+        UpdateConfigArgs {
+            amm: Some(app.program_id.amm),
+            ..Default::default()
+        },
+    )?;
+    assert_eq!(
+        app.dex_adapter_query_config()?.amm,
+        Some(app.program_id.amm)
+    );
+
+    // amm pool isn't approved by admin
+    let res = app
+        .dex_adapter_try_swap_multihop(
+            AppUser::Bob,
+            AppToken::WBTC,
+            AppToken::PYTH,
+            1_000,
+            0,
+            Some(&[
+                route[0].clone(),
+                RouteItem {
+                    pool_id: AMM_POOL_ID + 1,
+                    ..route[1].clone()
+                },
+            ]),
+        )
+        .unwrap_err();
+    assert_error(res, CustomError::RouteMismatch);
+
+    // exact output swap isn't supported by amm
+    let res = app
+        .dex_adapter_try_swap_base_out(AppUser::Bob, AppToken::WBTC, AppToken::PYTH, 1_000, 1_000)
+        .unwrap_err();
+    assert_error(res, CustomError::UnsupportedDexKind);
+
+    Ok(())
+}
+
+const AMM_POOL_ID: u64 = 0;
+const AMM_FEE_BPS: u16 = 30;
+
+/// WBTC -> USDC on clmm, USDC -> PYTH on amm
+fn prepare_mixed_route(app: &mut App) -> Result<[RouteItem; 2]> {
+    prepare_dex(
+        app,
+        &[(AMM_CONFIG_INDEX_1, AppToken::WBTC, AppToken::USDC)],
+        None,
+    )?;
+
+    app.amm_try_create_pool(
+        AppUser::Admin,
+        AMM_POOL_ID,
+        AppToken::USDC,
+        AppToken::PYTH,
+        AMM_FEE_BPS,
+    )?;
+    app.amm_try_provide_liquidity(
+        AppUser::Admin,
+        AMM_POOL_ID,
+        calc_token_amount_for_pool(AppToken::USDC, None),
+        calc_token_amount_for_pool(AppToken::PYTH, None),
+    )?;
+
+    let route = [
+        RouteItem {
+            amm_index: AMM_CONFIG_INDEX_1,
+            token_out: AppToken::USDC.pubkey(),
+            dex: DexKind::Clmm,
+            pool_id: 0,
+        },
+        RouteItem {
+            amm_index: 0,
+            token_out: AppToken::PYTH.pubkey(),
+            dex: DexKind::Amm,
+            pool_id: AMM_POOL_ID,
+        },
+    ];
+
+    app.dex_adapter_try_init(AppUser::Admin, app.program_id.clmm_mock, None, None)?;
+    app.dex_adapter_try_update_config(
+        AppUser::Admin,
+        UpdateConfigArgs {
+            amm: Some(app.program_id.amm),
+            ..Default::default()
+        },
+    )?;
+    app.dex_adapter_try_save_route(
+        AppUser::Admin,
+        AppToken::WBTC,
+        AppToken::PYTH,
+        &route,
+        None,
+        None,
+    )?;
+
+    Ok(route)
+}
+
+#[test]
+fn swap_mixed_route_default() -> Result<()> {
+    let mut app = App::new();
+    prepare_mixed_route(&mut app)?;
+
+    let quote = app.dex_adapter_try_quote(AppUser::Bob, AppToken::WBTC, AppToken::PYTH, 1_000)?;
+    let pool_balance_before = app.amm_query_pool_balance(AMM_POOL_ID)?;
+
+    // swap WBTC -> USDC -> PYTH
+    let bob_wbtc_before = app.get_balance(AppUser::Bob, AppToken::WBTC);
+    let bob_usdc_before = app.get_balance(AppUser::Bob, AppToken::USDC);
+    let bob_pyth_before = app.get_balance(AppUser::Bob, AppToken::PYTH);
+
+    app.dex_adapter_try_swap_multihop(
+        AppUser::Bob,
+        AppToken::WBTC,
+        AppToken::PYTH,
+        1_000,
+        quote.amount_out,
+        None,
+    )?;
+
+    let bob_wbtc_after = app.get_balance(AppUser::Bob, AppToken::WBTC);
+    let bob_usdc_after = app.get_balance(AppUser::Bob, AppToken::USDC);
+    let bob_pyth_after = app.get_balance(AppUser::Bob, AppToken::PYTH);
+    let pool_balance_after = app.amm_query_pool_balance(AMM_POOL_ID)?;
+
+    assert_eq!(bob_wbtc_before - bob_wbtc_after, 1_000);
+    // intermediate amount is passed from clmm to amm entirely
+    assert_eq!(bob_usdc_after, bob_usdc_before);
+    assert_eq!(bob_pyth_after - bob_pyth_before, quote.amount_out);
+    assert_eq!(
+        pool_balance_after.mint_x_amount - pool_balance_before.mint_x_amount,
+        quote.hops[0].amount_out
+    );
+
+    // slippage is checked by the final output amount
+    let res = app
+        .dex_adapter_try_swap_multihop(
+            AppUser::Bob,
+            AppToken::WBTC,
+            AppToken::PYTH,
+            1_000,
+            u64::MAX,
+            None,
+        )
+        .unwrap_err();
+    assert_error(res, CustomError::SlippageExceeded);
+
+    Ok(())
+}
+
+#[test]
+fn quote_mixed_route() -> Result<()> {
+    let mut app = App::new();
+    prepare_mixed_route(&mut app)?;
+
+    // quote WBTC -> USDC -> PYTH
+    let quote = app.dex_adapter_try_quote(AppUser::Bob, AppToken::WBTC, AppToken::PYTH, 1_000)?;
+    let quote_large =
+        app.dex_adapter_try_quote(AppUser::Bob, AppToken::WBTC, AppToken::PYTH, 100_000)?;
+
+    assert_eq!(quote.amount_in, 1_000);
+    assert_eq!(quote.fee_amount, 0);
+    assert_eq!(quote.hops.len(), 2);
+    assert_eq!(quote.hops[0].amount_in, 1_000);
+    assert_eq!(quote.hops[0].amount_out, quote.hops[1].amount_in);
+    assert_eq!(quote.hops[1].amount_out, quote.amount_out);
+    assert!(quote_large.price_impact_bps > quote.price_impact_bps);
+
+    // amm hop output is reduced by the amm fee
+    let pool_config = app.amm_query_pool_config(AMM_POOL_ID)?;
+    let pool_balance = app.amm_query_pool_balance(AMM_POOL_ID)?;
+    let amount_out = calc_amount_out(
+        quote.hops[1].amount_in,
+        true,
+        pool_balance.mint_x_amount,
+        pool_balance.mint_y_amount,
+    );
+
+    assert_eq!(
+        quote.hops[1].amount_out,
+        amount_out - calc_fee(amount_out, &pool_config)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn swap_multihop_2() -> Result<()> {
+    let mut app = App::new();
+    prepare_dex(
+        &mut app,
+        &[
+            (AMM_CONFIG_INDEX_0, AppToken::WBTC, AppToken::PYTH),
+            (AMM_CONFIG_INDEX_1, AppToken::PYTH, AppToken::USDC),
+        ],
+        None,
+    )?;
+
+    app.dex_adapter_try_init(AppUser::Admin, app.program_id.clmm_mock, None, None)?;
+    app.dex_adapter_try_save_route(
+        AppUser::Admin,
+        AppToken::WBTC,
+        AppToken::USDC,
+        &[
+            RouteItem {
+                amm_index: AMM_CONFIG_INDEX_0,
+                token_out: AppToken::PYTH.pubkey(),
+                dex: DexKind::Clmm,
+                pool_id: 0,
+            },
+            RouteItem {
+                amm_index: AMM_CONFIG_INDEX_1,
+                token_out: AppToken::USDC.pubkey(),
+                dex: DexKind::Clmm,
+                pool_id: 0,
+            },
+        ],
+        None,
+        None,
+    )?;
+
+    // swap WBTC -> PYTH -> USDC
+    let bob_wbtc_before = app.get_balance(AppUser::Bob, AppToken::WBTC);
+    let bob_usdc_before = app.get_balance(AppUser::Bob, AppToken::USDC);
+
+    app.dex_adapter_try_swap_multihop(
+        AppUser::Bob,
+        AppToken::WBTC,
+        AppToken::USDC,
+        1_000,
+        995_000,
+        None,
+    )?;
+
+    let bob_wbtc_after = app.get_balance(AppUser::Bob, AppToken::WBTC);
+    let bob_usdc_after = app.get_balance(AppUser::Bob, AppToken::USDC);
+
+    assert_eq!(bob_wbtc_before - bob_wbtc_after, 1_000);
+    assert_eq!(bob_usdc_after - bob_usdc_before, 996_002);
+
+    Ok(())
+}
+
+#[test]
+fn quote_default() -> Result<()> {
+    let mut app = App::new();
+    prepare_dex(
+        &mut app,
+        &[
+            (AMM_CONFIG_INDEX_0, AppToken::USDC, AppToken::PYTH),
+            (AMM_CONFIG_INDEX_1, AppToken::WBTC, AppToken::USDC),
+        ],
+        None,
+    )?;
+
+    app.dex_adapter_try_init(AppUser::Admin, app.program_id.clmm_mock, None, None)?;
+    app.dex_adapter_try_save_route(
+        AppUser::Admin,
+        AppToken::WBTC,
+        AppToken::PYTH,
+        &[
+            RouteItem {
+                amm_index: AMM_CONFIG_INDEX_1,
+                token_out: AppToken::USDC.pubkey(),
+                dex: DexKind::Clmm,
+                pool_id: 0,
+            },
+            RouteItem {
+                amm_index: AMM_CONFIG_INDEX_0,
+                token_out: AppToken::PYTH.pubkey(),
+                dex: DexKind::Clmm,
+                pool_id: 0,
+            },
+        ],
+        None,
+        None,
+    )?;
+
+    let res = app
+        .dex_adapter_try_quote(AppUser::Bob, AppToken::WBTC, AppToken::PYTH, 0)
+        .unwrap_err();
+    assert_error(res, CustomError::InvalidAmount);
+
+    // quote WBTC -> USDC -> PYTH
+    let quote = app.dex_adapter_try_quote(AppUser::Bob, AppToken::WBTC, AppToken::PYTH, 1_000)?;
+    let quote_large =
+        app.dex_adapter_try_quote(AppUser::Bob, AppToken::WBTC, AppToken::PYTH, 100_000)?;
+
+    assert_eq!(quote.amount_in, 1_000);
+    assert_eq!(quote.amount_out, 9_960_020);
+    assert_eq!(quote.hops.len(), 2);
+    assert_eq!(quote.hops[0].amount_in, 1_000);
+    assert_eq!(quote.hops[0].amount_out, quote.hops[1].amount_in);
+    assert_eq!(quote.hops[1].amount_out, quote.amount_out);
+    assert!(quote_large.price_impact_bps > quote.price_impact_bps);
+
+    // quote is read-only, swap result must match it
+    let bob_pyth_before = app.get_balance(AppUser::Bob, AppToken::PYTH);
+
+    app.dex_adapter_try_swap_multihop(
+        AppUser::Bob,
+        AppToken::WBTC,
+        AppToken::PYTH,
+        quote.amount_in,
+        quote.amount_out,
+        None,
+    )?;
+
+    let bob_pyth_after = app.get_balance(AppUser::Bob, AppToken::PYTH);
+    assert_eq!(bob_pyth_after - bob_pyth_before, quote.amount_out);
+
+    Ok(())
+}
+
+#[test]
+fn swap_protocol_fee() -> Result<()> {
+    const FEE_BPS: u16 = 100;
+
+    let mut app = App::new();
+    prepare_dex(
+        &mut app,
+        &[
+            (AMM_CONFIG_INDEX_0, AppToken::USDC, AppToken::PYTH),
+            (AMM_CONFIG_INDEX_1, AppToken::WBTC, AppToken::USDC),
+        ],
+        None,
+    )?;
+
+    let route = [
+        RouteItem {
+            amm_index: AMM_CONFIG_INDEX_1,
+            token_out: AppToken::USDC.pubkey(),
+            dex: DexKind::Clmm,
+            pool_id: 0,
+        },
+        RouteItem {
+            amm_index: AMM_CONFIG_INDEX_0,
+            token_out: AppToken::PYTH.pubkey(),
+            dex: DexKind::Clmm,
+            pool_id: 0,
+        },
+    ];
+
+    app.dex_adapter_try_init(AppUser::Admin, app.program_id.clmm_mock, None, None)?;
+    app.dex_adapter_try_save_route(
+        AppUser::Admin,
+        AppToken::WBTC,
+        AppToken::PYTH,
+        &route,
+        None,
+        None,
+    )?;
+
+    let res = app
+        .dex_adapter_try_update_config(
+            AppUser::Admin,
+            UpdateConfigArgs {
+                fee_bps: Some(FEE_BPS_MAX + 1),
+                ..Default::default()
+            },
+        )
+        .unwrap_err();
+    assert_error(res, CustomError::FeeTooHigh);
+
+    app.dex_adapter_try_update_config(
+        AppUser::Admin,
+        UpdateConfigArgs {
+            fee_bps: Some(FEE_BPS),
+            ..Default::default()
+        },
+    )?;
+    assert_eq!(app.dex_adapter_query_config()?.fee_bps, FEE_BPS);
+
+    // fee is taken from input amount
+    let config = app.pda.dex_adapter_config();
+    let quote = app.dex_adapter_try_quote(AppUser::Bob, AppToken::WBTC, AppToken::PYTH, 1_000)?;
+    assert_eq!(quote.fee_amount, 10);
+
+    let bob_wbtc_before = app.get_balance(AppUser::Bob, AppToken::WBTC);
+    let bob_pyth_before = app.get_balance(AppUser::Bob, AppToken::PYTH);
+
+    app.dex_adapter_try_swap_multihop(
+        AppUser::Bob,
+        AppToken::WBTC,
+        AppToken::PYTH,
+        1_000,
+        quote.amount_out,
+        None,
+    )?;
+
+    let bob_wbtc_after = app.get_balance(AppUser::Bob, AppToken::WBTC);
+    let bob_pyth_after = app.get_balance(AppUser::Bob, AppToken::PYTH);
+
+    assert_eq!(bob_wbtc_before - bob_wbtc_after, 1_000);
+    assert_eq!(bob_pyth_after - bob_pyth_before, quote.amount_out);
+    assert_eq!(
+        app.get_ata_token_balance(&config, &AppToken::WBTC.pubkey()),
+        quote.fee_amount
+    );
+    // only the fee side ATA is created
+    assert!(app
+        .litesvm
+        .get_account(&App::get_ata(&config, &AppToken::PYTH.pubkey()))
+        .is_none());
+
+    // route fee overrides the config one
+    app.dex_adapter_try_save_route(
+        AppUser::Admin,
+        AppToken::WBTC,
+        AppToken::PYTH,
+        &route,
+        Some(0),
+        None,
+    )?;
+    app.dex_adapter_try_swap_multihop(
+        AppUser::Bob,
+        AppToken::WBTC,
+        AppToken::PYTH,
+        1_000,
+        0,
+        None,
+    )?;
+    assert_eq!(
+        app.get_ata_token_balance(&config, &AppToken::WBTC.pubkey()),
+        quote.fee_amount
+    );
+
+    // withdraw fees
+    let res = app
+        .dex_adapter_try_withdraw_fees(AppUser::Bob, AppToken::WBTC, None, None)
+        .unwrap_err();
+    assert_error(res, AuthError::Unauthorized);
+
+    let res = app
+        .dex_adapter_try_withdraw_fees(
+            AppUser::Admin,
+            AppToken::WBTC,
+            Some(quote.fee_amount + 1),
+            None,
+        )
+        .unwrap_err();
+    assert_error(res, CustomError::ExceededAvailableAssetAmount);
+
+    let admin_wbtc_before = app.get_balance(AppUser::Admin, AppToken::WBTC);
+    app.dex_adapter_try_withdraw_fees(AppUser::Admin, AppToken::WBTC, None, None)?;
+    let admin_wbtc_after = app.get_balance(AppUser::Admin, AppToken::WBTC);
+
+    assert_eq!(admin_wbtc_after - admin_wbtc_before, quote.fee_amount);
+    assert_eq!(
+        app.get_ata_token_balance(&config, &AppToken::WBTC.pubkey()),
+        0
+    );
+
+    Ok(())
+}
+
+#[test]
+fn swap_protocol_fee_output_side() -> Result<()> {
+    const FEE_BPS: u16 = 100;
+
+    let mut app = App::new();
+    prepare_dex(
+        &mut app,
+        &[
+            (AMM_CONFIG_INDEX_0, AppToken::USDC, AppToken::PYTH),
+            (AMM_CONFIG_INDEX_1, AppToken::WBTC, AppToken::USDC),
+        ],
+        None,
+    )?;
+
+    let route = [
+        RouteItem {
+            amm_index: AMM_CONFIG_INDEX_1,
+            token_out: AppToken::USDC.pubkey(),
+            dex: DexKind::Clmm,
+            pool_id: 0,
+        },
+        RouteItem {
+            amm_index: AMM_CONFIG_INDEX_0,
+            token_out: AppToken::PYTH.pubkey(),
+            dex: DexKind::Clmm,
+            pool_id: 0,
+        },
+    ];
+
+    app.dex_adapter_try_init(AppUser::Admin, app.program_id.clmm_mock, None, None)?;
+    app.dex_adapter_try_save_route(
+        AppUser::Admin,
+        AppToken::WBTC,
+        AppToken::PYTH,
+        &route,
+        None,
+        None,
+    )?;
+    app.dex_adapter_try_update_config(
+        AppUser::Admin,
+        UpdateConfigArgs {
+            fee_bps: Some(FEE_BPS),
+            fee_side: Some(FeeSide::Output),
+            ..Default::default()
+        },
+    )?;
+    assert_eq!(app.dex_adapter_query_config()?.fee_side, FeeSide::Output);
+
+    // fee is taken from output amount
+    let config = app.pda.dex_adapter_config();
+    let quote = app.dex_adapter_try_quote(AppUser::Bob, AppToken::WBTC, AppToken::PYTH, 1_000)?;
+    let hops_amount_out = quote.hops.last().unwrap().amount_out;
+    assert_eq!(quote.fee_side, FeeSide::Output);
+    assert_eq!(quote.hops[0].amount_in, 1_000);
+    assert_eq!(
+        quote.fee_amount,
+        hops_amount_out * FEE_BPS as u64 / BPS_MAX as u64
+    );
+    assert_eq!(quote.amount_out, hops_amount_out - quote.fee_amount);
 
-    app.dex_adapter_try_init(AppUser::Admin, Pubkey::default(), None, None)?;
+    // the limit is applied to output amount after the fee
+    let res = app
+        .dex_adapter_try_swap_multihop(
+            AppUser::Bob,
+            AppToken::WBTC,
+            AppToken::PYTH,
+            1_000,
+            quote.amount_out + 1,
+            None,
+        )
+        .unwrap_err();
+    assert_error(res, CustomError::SlippageExceeded);
+
+    let bob_wbtc_before = app.get_balance(AppUser::Bob, AppToken::WBTC);
+    let bob_pyth_before = app.get_balance(AppUser::Bob, AppToken::PYTH);
+
+    app.dex_adapter_try_swap_multihop(
+        AppUser::Bob,
+        AppToken::WBTC,
+        AppToken::PYTH,
+        1_000,
+        quote.amount_out,
+        None,
+    )?;
+
+    let bob_wbtc_after = app.get_balance(AppUser::Bob, AppToken::WBTC);
+    let bob_pyth_after = app.get_balance(AppUser::Bob, AppToken::PYTH);
 
+    assert_eq!(bob_wbtc_before - bob_wbtc_after, 1_000);
+    assert_eq!(bob_pyth_after - bob_pyth_before, quote.amount_out);
     assert_eq!(
-        app.dex_adapter_query_config()?,
-        DaConfig {
-            admin: AppUser::Admin.pubkey(),
-            dex: Pubkey::default(),
-            registry: None,
-            is_paused: false,
-            rotation_timeout: ROTATION_TIMEOUT,
-        }
+        app.get_ata_token_balance(&config, &AppToken::PYTH.pubkey()),
+        quote.fee_amount
+    );
+    assert_eq!(
+        app.get_ata_token_balance(&config, &AppToken::WBTC.pubkey()),
+        0
+    );
+
+    // route fee side overrides the config one
+    app.dex_adapter_try_save_route(
+        AppUser::Admin,
+        AppToken::WBTC,
+        AppToken::PYTH,
+        &route,
+        None,
+        Some(FeeSide::Input),
+    )?;
+    app.dex_adapter_try_swap_multihop(
+        AppUser::Bob,
+        AppToken::WBTC,
+        AppToken::PYTH,
+        1_000,
+        0,
+        None,
+    )?;
+    assert_eq!(
+        app.get_ata_token_balance(&config, &AppToken::WBTC.pubkey()),
+        10
     );
 
+    // fees are withdrawn per token
+    let admin_pyth_before = app.get_balance(AppUser::Admin, AppToken::PYTH);
+    app.dex_adapter_try_withdraw_fees(AppUser::Admin, AppToken::PYTH, None, None)?;
+    let admin_pyth_after = app.get_balance(AppUser::Admin, AppToken::PYTH);
+
+    assert_eq!(admin_pyth_after - admin_pyth_before, quote.fee_amount);
+
     Ok(())
 }
 
 #[test]
-fn swap_multihop() -> Result<()> {
+fn swap_and_activate_default() -> Result<()> {
+    const MAX_DATA_SIZE_0: u32 = 1_000;
+
+    let mut app = App::new();
+    prepare_dex(
+        &mut app,
+        &[
+            (AMM_CONFIG_INDEX_0, AppToken::WBTC, AppToken::PYTH),
+            (AMM_CONFIG_INDEX_1, AppToken::PYTH, AppToken::USDC),
+        ],
+        None,
+    )?;
+
+    app.registry_try_init(
+        AppUser::Admin,
+        None,
+        Some(AssetItem {
+            amount: ACCOUNT_REGISTRATION_FEE_AMOUNT,
+            asset: AppToken::USDC.pubkey(),
+        }),
+        None,
+        None,
+    )?;
+
+    app.registry_try_create_account(AppUser::Bob, MAX_DATA_SIZE_0, None)?;
+
+    app.dex_adapter_try_init(AppUser::Admin, app.program_id.clmm_mock, None, None)?;
+    app.dex_adapter_try_save_route(
+        AppUser::Admin,
+        AppToken::WBTC,
+        AppToken::USDC,
+        &[
+            RouteItem {
+                amm_index: AMM_CONFIG_INDEX_0,
+                token_out: AppToken::PYTH.pubkey(),
+                dex: DexKind::Clmm,
+                pool_id: 0,
+            },
+            RouteItem {
+                amm_index: AMM_CONFIG_INDEX_1,
+                token_out: AppToken::USDC.pubkey(),
+                dex: DexKind::Clmm,
+                pool_id: 0,
+            },
+        ],
+        None,
+        None,
+    )?;
+
+    // swap WBTC -> PYTH -> USDC
+    let bob_wbtc_before = app.get_balance(AppUser::Bob, AppToken::WBTC);
+    let bob_usdc_before = app.get_balance(AppUser::Bob, AppToken::USDC);
+
+    app.dex_adapter_try_swap_and_activate(
+        AppUser::Bob,
+        AppToken::WBTC,
+        AppToken::USDC,
+        11_000,
+        app.registry_query_config()?.registration_fee.amount,
+    )?;
+
+    assert_eq!(app.registry_query_user_id(AppUser::Bob)?.is_activated, true);
+
+    let bob_wbtc_after = app.get_balance(AppUser::Bob, AppToken::WBTC);
+    let bob_usdc_after = app.get_balance(AppUser::Bob, AppToken::USDC);
+
+    assert_eq!(bob_wbtc_before - bob_wbtc_after, 11_000);
+    assert_eq!(bob_usdc_after - bob_usdc_before, 955_803);
+
+    Ok(())
+}
+
+#[test]
+fn swap_base_out_default() -> Result<()> {
+    const AMOUNT_OUT: u64 = 9_960_020;
+    const AMOUNT_IN_MAXIMUM: u64 = 1_000;
+
     let mut app = App::new();
     prepare_dex(
         &mut app,
@@ -62,44 +1146,70 @@ fn swap_multihop() -> Result<()> {
         &[
             RouteItem {
                 amm_index: AMM_CONFIG_INDEX_1,
-                token_out: AppToken::USDC.pubkey(), // WBTC -> USDC (first hop output)
+                token_out: AppToken::USDC.pubkey(),
+                dex: DexKind::Clmm,
+                pool_id: 0,
             },
             RouteItem {
                 amm_index: AMM_CONFIG_INDEX_0,
-                token_out: AppToken::PYTH.pubkey(), // USDC -> PYTH (second hop output)
+                token_out: AppToken::PYTH.pubkey(),
+                dex: DexKind::Clmm,
+                pool_id: 0,
             },
         ],
+        None,
+        None,
     )?;
 
-    // swap WBTC -> USDC -> PYTH
+    let res = app
+        .dex_adapter_try_swap_base_out(AppUser::Bob, AppToken::WBTC, AppToken::PYTH, 0, 1_000)
+        .unwrap_err();
+    assert_error(res, CustomError::InvalidAmount);
+
+    let res = app
+        .dex_adapter_try_swap_base_out(
+            AppUser::Bob,
+            AppToken::WBTC,
+            AppToken::PYTH,
+            AMOUNT_OUT,
+            900,
+        )
+        .unwrap_err();
+    assert_error(res, ClmmErrorCode::TooMuchInputPaid);
+
+    // swap WBTC -> USDC -> PYTH receiving exact PYTH amount
     let bob_wbtc_before = app.get_balance(AppUser::Bob, AppToken::WBTC);
     let bob_pyth_before = app.get_balance(AppUser::Bob, AppToken::PYTH);
 
-    app.dex_adapter_try_swap_multihop(
+    app.dex_adapter_try_swap_base_out(
         AppUser::Bob,
         AppToken::WBTC,
         AppToken::PYTH,
-        1_000,
-        9_950_000,
+        AMOUNT_OUT,
+        AMOUNT_IN_MAXIMUM,
     )?;
 
     let bob_wbtc_after = app.get_balance(AppUser::Bob, AppToken::WBTC);
     let bob_pyth_after = app.get_balance(AppUser::Bob, AppToken::PYTH);
 
-    assert_eq!(bob_wbtc_before - bob_wbtc_after, 1_000);
-    assert_eq!(bob_pyth_after - bob_pyth_before, 9_960_020);
+    assert!(bob_wbtc_before - bob_wbtc_after <= AMOUNT_IN_MAXIMUM);
+    assert_eq!(bob_pyth_after - bob_pyth_before, AMOUNT_OUT);
 
     Ok(())
 }
 
 #[test]
-fn swap_multihop_2() -> Result<()> {
+fn swap_base_out_protocol_fee() -> Result<()> {
+    const FEE_BPS: u16 = 100;
+    const AMOUNT_OUT: u64 = 9_960_020;
+    const AMOUNT_IN_MAXIMUM: u64 = 1_100;
+
     let mut app = App::new();
     prepare_dex(
         &mut app,
         &[
-            (AMM_CONFIG_INDEX_0, AppToken::WBTC, AppToken::PYTH),
-            (AMM_CONFIG_INDEX_1, AppToken::PYTH, AppToken::USDC),
+            (AMM_CONFIG_INDEX_0, AppToken::USDC, AppToken::PYTH),
+            (AMM_CONFIG_INDEX_1, AppToken::WBTC, AppToken::USDC),
         ],
         None,
     )?;
@@ -108,43 +1218,90 @@ fn swap_multihop_2() -> Result<()> {
     app.dex_adapter_try_save_route(
         AppUser::Admin,
         AppToken::WBTC,
-        AppToken::USDC,
+        AppToken::PYTH,
         &[
-            RouteItem {
-                amm_index: AMM_CONFIG_INDEX_0,
-                token_out: AppToken::PYTH.pubkey(),
-            },
             RouteItem {
                 amm_index: AMM_CONFIG_INDEX_1,
                 token_out: AppToken::USDC.pubkey(),
+                dex: DexKind::Clmm,
+                pool_id: 0,
+            },
+            RouteItem {
+                amm_index: AMM_CONFIG_INDEX_0,
+                token_out: AppToken::PYTH.pubkey(),
+                dex: DexKind::Clmm,
+                pool_id: 0,
             },
         ],
+        Some(FEE_BPS),
+        None,
     )?;
 
-    // swap WBTC -> PYTH -> USDC
+    let config = app.pda.dex_adapter_config();
+
+    // fee is taken from actually spent input amount
     let bob_wbtc_before = app.get_balance(AppUser::Bob, AppToken::WBTC);
-    let bob_usdc_before = app.get_balance(AppUser::Bob, AppToken::USDC);
+    let bob_pyth_before = app.get_balance(AppUser::Bob, AppToken::PYTH);
 
-    app.dex_adapter_try_swap_multihop(
+    app.dex_adapter_try_swap_base_out(
         AppUser::Bob,
         AppToken::WBTC,
-        AppToken::USDC,
-        1_000,
-        995_000,
+        AppToken::PYTH,
+        AMOUNT_OUT,
+        AMOUNT_IN_MAXIMUM,
     )?;
 
     let bob_wbtc_after = app.get_balance(AppUser::Bob, AppToken::WBTC);
-    let bob_usdc_after = app.get_balance(AppUser::Bob, AppToken::USDC);
+    let bob_pyth_after = app.get_balance(AppUser::Bob, AppToken::PYTH);
+    let fee_amount = app.get_ata_token_balance(&config, &AppToken::WBTC.pubkey());
+    let amount_in = bob_wbtc_before - bob_wbtc_after - fee_amount;
 
-    assert_eq!(bob_wbtc_before - bob_wbtc_after, 1_000);
-    assert_eq!(bob_usdc_after - bob_usdc_before, 996_002);
+    assert_eq!(bob_pyth_after - bob_pyth_before, AMOUNT_OUT);
+    assert_eq!(fee_amount, amount_in * FEE_BPS as u64 / BPS_MAX as u64);
+    assert_eq!(
+        app.get_ata_token_balance(&config, &AppToken::PYTH.pubkey()),
+        0
+    );
+
+    // fee is received on top of exact output amount
+    app.dex_adapter_try_update_config(
+        AppUser::Admin,
+        UpdateConfigArgs {
+            fee_side: Some(FeeSide::Output),
+            ..Default::default()
+        },
+    )?;
+
+    let bob_wbtc_before = app.get_balance(AppUser::Bob, AppToken::WBTC);
+    let bob_pyth_before = app.get_balance(AppUser::Bob, AppToken::PYTH);
+
+    app.dex_adapter_try_swap_base_out(
+        AppUser::Bob,
+        AppToken::WBTC,
+        AppToken::PYTH,
+        AMOUNT_OUT,
+        AMOUNT_IN_MAXIMUM,
+    )?;
+
+    let bob_wbtc_after = app.get_balance(AppUser::Bob, AppToken::WBTC);
+    let bob_pyth_after = app.get_balance(AppUser::Bob, AppToken::PYTH);
+    let fee_amount =
+        (AMOUNT_OUT * BPS_MAX as u64).div_ceil((BPS_MAX - FEE_BPS) as u64) - AMOUNT_OUT;
+
+    assert!(bob_wbtc_before - bob_wbtc_after <= AMOUNT_IN_MAXIMUM);
+    assert_eq!(bob_pyth_after - bob_pyth_before, AMOUNT_OUT);
+    assert_eq!(
+        app.get_ata_token_balance(&config, &AppToken::PYTH.pubkey()),
+        fee_amount
+    );
 
     Ok(())
 }
 
 #[test]
-fn swap_and_activate_default() -> Result<()> {
+fn swap_base_out_and_activate_default() -> Result<()> {
     const MAX_DATA_SIZE_0: u32 = 1_000;
+    const AMOUNT_IN_MAXIMUM: u64 = 11_000;
 
     let mut app = App::new();
     prepare_dex(
@@ -164,6 +1321,7 @@ fn swap_and_activate_default() -> Result<()> {
             asset: AppToken::USDC.pubkey(),
         }),
         None,
+        None,
     )?;
 
     app.registry_try_create_account(AppUser::Bob, MAX_DATA_SIZE_0, None)?;
@@ -177,24 +1335,29 @@ fn swap_and_activate_default() -> Result<()> {
             RouteItem {
                 amm_index: AMM_CONFIG_INDEX_0,
                 token_out: AppToken::PYTH.pubkey(),
+                dex: DexKind::Clmm,
+                pool_id: 0,
             },
             RouteItem {
                 amm_index: AMM_CONFIG_INDEX_1,
                 token_out: AppToken::USDC.pubkey(),
+                dex: DexKind::Clmm,
+                pool_id: 0,
             },
         ],
+        None,
+        None,
     )?;
 
-    // swap WBTC -> PYTH -> USDC
+    // swap WBTC -> PYTH -> USDC paying exactly the activation fee
     let bob_wbtc_before = app.get_balance(AppUser::Bob, AppToken::WBTC);
     let bob_usdc_before = app.get_balance(AppUser::Bob, AppToken::USDC);
 
-    app.dex_adapter_try_swap_and_activate(
+    app.dex_adapter_try_swap_base_out_and_activate(
         AppUser::Bob,
         AppToken::WBTC,
         AppToken::USDC,
-        11_000,
-        app.registry_query_config()?.registration_fee.amount,
+        AMOUNT_IN_MAXIMUM,
     )?;
 
     assert_eq!(app.registry_query_user_id(AppUser::Bob)?.is_activated, true);
@@ -202,8 +1365,9 @@ fn swap_and_activate_default() -> Result<()> {
     let bob_wbtc_after = app.get_balance(AppUser::Bob, AppToken::WBTC);
     let bob_usdc_after = app.get_balance(AppUser::Bob, AppToken::USDC);
 
-    assert_eq!(bob_wbtc_before - bob_wbtc_after, 11_000);
-    assert_eq!(bob_usdc_after - bob_usdc_before, 955_803);
+    assert!(bob_wbtc_before - bob_wbtc_after <= AMOUNT_IN_MAXIMUM);
+    // whole swap output is spent on activation
+    assert_eq!(bob_usdc_after, bob_usdc_before);
 
     Ok(())
 }
@@ -228,14 +1392,25 @@ fn swap_to_wsol_default() -> Result<()> {
         &[RouteItem {
             amm_index: AMM_CONFIG_INDEX_0,
             token_out: AppToken::WSOL.pubkey(),
+            dex: DexKind::Clmm,
+            pool_id: 0,
         }],
+        None,
+        None,
     )?;
 
     // swap USDC -> WSOL
     let bob_usdc_before = app.get_balance(AppUser::Bob, AppToken::USDC);
     let bob_wsol_before = app.get_balance(AppUser::Bob, AppToken::WSOL);
 
-    app.dex_adapter_try_swap_multihop(AppUser::Bob, AppToken::USDC, AppToken::WSOL, 1_000, 1)?;
+    app.dex_adapter_try_swap_multihop(
+        AppUser::Bob,
+        AppToken::USDC,
+        AppToken::WSOL,
+        1_000,
+        1,
+        None,
+    )?;
 
     let bob_usdc_after = app.get_balance(AppUser::Bob, AppToken::USDC);
     let bob_wsol_after = app.get_balance(AppUser::Bob, AppToken::WSOL);
@@ -266,7 +1441,11 @@ fn swap_and_unwrap_wsol_default() -> Result<()> {
         &[RouteItem {
             amm_index: AMM_CONFIG_INDEX_0,
             token_out: AppToken::WSOL.pubkey(),
+            dex: DexKind::Clmm,
+            pool_id: 0,
         }],
+        None,
+        None,
     )?;
 
     // swap USDC -> SOL
@@ -292,3 +1471,109 @@ fn swap_and_unwrap_wsol_default() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn migrate_default() -> Result<()> {
+    // baseline layouts: discriminator, admin, dex, registry, is_paused, rotation_timeout
+    const LEGACY_CONFIG_SPACE: usize = DISCRIMINATOR_SPACE + 32 + 32 + 33 + 1 + 4;
+    // discriminator, route items (amm_index, token_out)
+    const LEGACY_ROUTE_SPACE: usize = DISCRIMINATOR_SPACE + 4 + ROUTE_MAX_LEN * (2 + 32);
+
+    let mut app = App::new();
+    prepare_dex(
+        &mut app,
+        &[(AMM_CONFIG_INDEX_0, AppToken::USDC, AppToken::PYTH)],
+        None,
+    )?;
+
+    let dex = app.program_id.clmm_mock;
+    let registry = app.program_id.registry;
+    app.dex_adapter_try_init(AppUser::Admin, dex, Some(registry), None)?;
+    app.dex_adapter_try_save_route(
+        AppUser::Admin,
+        AppToken::USDC,
+        AppToken::PYTH,
+        &[RouteItem {
+            amm_index: AMM_CONFIG_INDEX_0,
+            token_out: AppToken::PYTH.pubkey(),
+            dex: DexKind::Clmm,
+            pool_id: 0,
+        }],
+        None,
+        None,
+    )?;
+
+    let route = app.dex_adapter_query_route(&AppToken::USDC.pubkey(), &AppToken::PYTH.pubkey())?;
+
+    // accounts are up to date
+    let tx = app.dex_adapter_try_migrate(AppUser::Admin, &[(AppToken::USDC, AppToken::PYTH)])?;
+    assert_eq!(get_events::<AccountMigrated>(&tx).len(), 0);
+
+    // emulate paused config created before versioning
+    let config = app.pda.dex_adapter_config();
+    let mut account = app.litesvm.get_account(&config).unwrap();
+    account.data.truncate(LEGACY_CONFIG_SPACE);
+    account.data[LEGACY_CONFIG_SPACE - 5] = true as u8;
+    app.litesvm.set_account(config, account).unwrap();
+    app.dex_adapter_query_config().unwrap_err();
+
+    // emulate route created before dex and pool_id were introduced
+    let route_address = app
+        .pda
+        .dex_adapter_route(AppToken::USDC.pubkey(), AppToken::PYTH.pubkey());
+    let mut account = app.litesvm.get_account(&route_address).unwrap();
+    let mut data = account.data[..DISCRIMINATOR_SPACE].to_vec();
+    data.extend((route.value.len() as u32).to_le_bytes());
+    for item in &route.value {
+        data.extend(item.amm_index.to_le_bytes());
+        data.extend(item.token_out.to_bytes());
+    }
+    data.resize(LEGACY_ROUTE_SPACE, 0);
+    account.data = data;
+    app.litesvm.set_account(route_address, account).unwrap();
+
+    // only admin can migrate
+    let res = app
+        .dex_adapter_try_migrate(AppUser::Alice, &[(AppToken::USDC, AppToken::PYTH)])
+        .unwrap_err();
+    assert_error(res, AuthError::Unauthorized);
+
+    let tx = app.dex_adapter_try_migrate(AppUser::Admin, &[(AppToken::USDC, AppToken::PYTH)])?;
+    let events = get_events::<AccountMigrated>(&tx);
+    assert_eq!(
+        events
+            .iter()
+            .map(|x| (x.address, x.version))
+            .collect::<Vec<_>>(),
+        vec![(config, DaConfig::VERSION), (route_address, Route::VERSION)]
+    );
+
+    assert_eq!(
+        app.dex_adapter_query_config()?,
+        DaConfig {
+            admin: AppUser::Admin.pubkey(),
+            dex,
+            registry: Some(registry),
+            paused_ops: PAUSE_SWAP | PAUSE_SWAP_AND_ACTIVATE | PAUSE_UNWRAP,
+            rotation_timeout: ROTATION_TIMEOUT,
+            fee_bps: 0,
+            amm: None,
+            fee_side: FeeSide::Input,
+            version: DaConfig::VERSION,
+        }
+    );
+
+    // hops of legacy routes are executed by clmm
+    assert_eq!(
+        app.dex_adapter_query_route(&AppToken::USDC.pubkey(), &AppToken::PYTH.pubkey())?,
+        route
+    );
+
+    // swaps stay paused until the admin unpauses them
+    let res = app
+        .dex_adapter_try_swap_multihop(AppUser::Bob, AppToken::USDC, AppToken::PYTH, 1_000, 1, None)
+        .unwrap_err();
+    assert_error(res, CustomError::ContractPaused);
+
+    Ok(())
+}
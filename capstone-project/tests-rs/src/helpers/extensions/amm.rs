@@ -0,0 +1,176 @@
+use {
+    crate::helpers::suite::{
+        core::{
+            extension::{get_data, send_tx},
+            App, ProgramId,
+        },
+        types::{AppToken, AppUser},
+    },
+    amm_cpi::{
+        state,
+        types::{CreatePoolData, ProvideLiquidityData},
+    },
+    anchor_lang::{AnchorSerialize, Result},
+    base::helpers::get_discriminator,
+    litesvm::types::TransactionMetadata,
+    solana_instruction::{AccountMeta, Instruction},
+};
+
+pub trait AmmExtension {
+    fn amm_try_create_pool(
+        &mut self,
+        sender: AppUser,
+        id: u64,
+        mint_x: AppToken,
+        mint_y: AppToken,
+        fee_bps: u16,
+    ) -> Result<TransactionMetadata>;
+
+    fn amm_try_provide_liquidity(
+        &mut self,
+        sender: AppUser,
+        id: u64,
+        mint_x_amount: u64,
+        mint_y_amount: u64,
+    ) -> Result<TransactionMetadata>;
+
+    fn amm_query_pool_config(&self, id: u64) -> Result<state::PoolConfig>;
+
+    fn amm_query_pool_balance(&self, id: u64) -> Result<state::PoolBalance>;
+}
+
+impl AmmExtension for App {
+    fn amm_try_create_pool(
+        &mut self,
+        sender: AppUser,
+        id: u64,
+        mint_x: AppToken,
+        mint_y: AppToken,
+        fee_bps: u16,
+    ) -> Result<TransactionMetadata> {
+        // programs
+        let ProgramId {
+            system_program,
+            token_program,
+            associated_token_program,
+            amm: program_id,
+            ..
+        } = self.program_id;
+
+        // signers
+        let payer = sender.pubkey();
+        let signers = [sender.keypair()];
+
+        // mints
+        let (mint_x, mint_y) = (mint_x.pubkey(), mint_y.pubkey());
+
+        // pda
+        let pool_config = self.pda.amm_pool_config(id);
+        let pool_balance = self.pda.amm_pool_balance(id);
+        let mint_lp = self.pda.amm_mint_lp(id);
+
+        // amm program isn't a part of the workspace, accounts are built manually
+        let accounts = vec![
+            AccountMeta::new_readonly(system_program, false),
+            AccountMeta::new_readonly(token_program, false),
+            AccountMeta::new_readonly(associated_token_program, false),
+            AccountMeta::new(payer, true), // pool_creator
+            AccountMeta::new(pool_config, false),
+            AccountMeta::new(pool_balance, false),
+            AccountMeta::new(mint_lp, false),
+            AccountMeta::new_readonly(mint_x, false),
+            AccountMeta::new_readonly(mint_y, false),
+            AccountMeta::new(App::get_ata(&pool_config, &mint_lp), false),
+            AccountMeta::new(App::get_ata(&pool_config, &mint_x), false),
+            AccountMeta::new(App::get_ata(&pool_config, &mint_y), false),
+        ];
+
+        let data = CreatePoolData {
+            discriminator: get_discriminator("create_pool"),
+            id,
+            mint_x,
+            mint_y,
+            fee_bps,
+        }
+        .try_to_vec()?;
+
+        let ix = Instruction {
+            program_id,
+            accounts,
+            data,
+        };
+
+        send_tx(&mut self.litesvm, &[ix], &payer, &signers)
+    }
+
+    fn amm_try_provide_liquidity(
+        &mut self,
+        sender: AppUser,
+        id: u64,
+        mint_x_amount: u64,
+        mint_y_amount: u64,
+    ) -> Result<TransactionMetadata> {
+        // programs
+        let ProgramId {
+            system_program,
+            token_program,
+            associated_token_program,
+            amm: program_id,
+            ..
+        } = self.program_id;
+
+        // signers
+        let payer = sender.pubkey();
+        let signers = [sender.keypair()];
+
+        // mints
+        let state::PoolConfig { mint_x, mint_y, .. } = self.amm_query_pool_config(id)?;
+
+        // pda
+        let pool_config = self.pda.amm_pool_config(id);
+        let pool_balance = self.pda.amm_pool_balance(id);
+        let mint_lp = self.pda.amm_mint_lp(id);
+
+        let accounts = vec![
+            AccountMeta::new_readonly(system_program, false),
+            AccountMeta::new_readonly(token_program, false),
+            AccountMeta::new_readonly(associated_token_program, false),
+            AccountMeta::new(payer, true), // liquidity_provider
+            AccountMeta::new_readonly(pool_config, false),
+            AccountMeta::new(pool_balance, false),
+            AccountMeta::new(mint_lp, false),
+            AccountMeta::new_readonly(mint_x, false),
+            AccountMeta::new_readonly(mint_y, false),
+            AccountMeta::new(App::get_ata(&payer, &mint_lp), false),
+            AccountMeta::new(App::get_ata(&payer, &mint_x), false),
+            AccountMeta::new(App::get_ata(&payer, &mint_y), false),
+            AccountMeta::new(App::get_ata(&pool_config, &mint_lp), false),
+            AccountMeta::new(App::get_ata(&pool_config, &mint_x), false),
+            AccountMeta::new(App::get_ata(&pool_config, &mint_y), false),
+        ];
+
+        let data = ProvideLiquidityData {
+            discriminator: get_discriminator("provide_liquidity"),
+            id,
+            mint_x_amount,
+            mint_y_amount,
+        }
+        .try_to_vec()?;
+
+        let ix = Instruction {
+            program_id,
+            accounts,
+            data,
+        };
+
+        send_tx(&mut self.litesvm, &[ix], &payer, &signers)
+    }
+
+    fn amm_query_pool_config(&self, id: u64) -> Result<state::PoolConfig> {
+        get_data(&self.litesvm, &self.pda.amm_pool_config(id))
+    }
+
+    fn amm_query_pool_balance(&self, id: u64) -> Result<state::PoolBalance> {
+        get_data(&self.litesvm, &self.pda.amm_pool_balance(id))
+    }
+}
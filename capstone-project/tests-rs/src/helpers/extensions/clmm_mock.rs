@@ -93,6 +93,14 @@ pub trait ClmmMockExtension {
         route_with_configs: &[(AppToken, u16)],
     ) -> Result<TransactionMetadata>;
 
+    fn clmm_mock_try_swap_multihop_base_out(
+        &mut self,
+        sender: AppUser,
+        amount_out: u64,
+        amount_in_maximum: u64,
+        route_with_configs: &[(AppToken, u16)],
+    ) -> Result<TransactionMetadata>;
+
     fn clmm_mock_query_operation_account(&self) -> Result<state::OperationState>;
 
     fn clmm_mock_query_amm_config(&self, index: u16)
@@ -618,6 +626,85 @@ impl ClmmMockExtension for App {
         )
     }
 
+    fn clmm_mock_try_swap_multihop_base_out(
+        &mut self,
+        sender: AppUser,
+        amount_out: u64,
+        amount_in_maximum: u64,
+        route_with_configs: &[(AppToken, u16)], // (token, config_for_pool_ending_at_this_token)
+    ) -> Result<TransactionMetadata> {
+        // programs
+        let ProgramId {
+            token_program_2022,
+            token_program,
+            memo,
+            clmm_mock: program_id,
+            ..
+        } = self.program_id;
+
+        // signers
+        let payer = sender.pubkey();
+        let signers = [sender.keypair()];
+
+        // mint
+        let input_vault_mint = route_with_configs[0].0.pubkey();
+        let input_token_account = self.get_or_create_ata(sender, &payer, &input_vault_mint)?;
+
+        let accounts = accounts::SwapRouterBaseOut {
+            payer,
+            input_token_account,
+            input_token_mint: input_vault_mint,
+            token_program,
+            token_program_2022,
+            memo_program: memo,
+        };
+
+        // build accounts for each pool in the route
+        let mut remaining_accounts = vec![];
+
+        for i in 0..route_with_configs.len() - 1 {
+            let (token_a, _) = route_with_configs[i];
+            let (token_b, amm_config_index) = route_with_configs[i + 1];
+            let (token_0_mint, token_1_mint) = (token_a.pubkey(), token_b.pubkey());
+
+            // use the config index from the destination token
+            let amm_config = self.pda.clmm_mock_amm_config(amm_config_index);
+            let pool_state = self
+                .pda
+                .clmm_mock_pool_state(amm_config, token_0_mint, token_1_mint);
+            let input_vault = self.pda.clmm_mock_token_vault_0(pool_state, token_0_mint);
+            let output_vault = self.pda.clmm_mock_token_vault_1(pool_state, token_1_mint);
+
+            let observation_state = self.pda.clmm_mock_observation_state(pool_state);
+            let output_token_account = self.get_or_create_ata(sender, &payer, &token_1_mint)?;
+
+            remaining_accounts.extend(vec![
+                AccountMeta::new_readonly(amm_config, false),
+                AccountMeta::new(pool_state, false),
+                AccountMeta::new(output_token_account, false),
+                AccountMeta::new(input_vault, false),
+                AccountMeta::new(output_vault, false),
+                AccountMeta::new_readonly(token_1_mint, false),
+                AccountMeta::new(observation_state, false),
+            ]);
+        }
+
+        let instruction_data = instruction::SwapRouterBaseOut {
+            amount_out,
+            amount_in_maximum,
+        };
+
+        send_tx_with_ix(
+            self,
+            &program_id,
+            &accounts,
+            &instruction_data,
+            &payer,
+            &signers,
+            &remaining_accounts,
+        )
+    }
+
     fn clmm_mock_query_operation_account(&self) -> Result<state::OperationState> {
         get_data_zero_copy(&self.litesvm, &self.pda.clmm_mock_operation_account())
     }
@@ -6,10 +6,13 @@ use {
         },
         types::{AppToken, AppUser},
     },
-    anchor_lang::Result,
+    anchor_lang::{AnchorDeserialize, Result},
     base::helpers::sort_mints,
     dex_adapter::{accounts, instruction},
-    dex_adapter_cpi::{state, types::RouteItem},
+    dex_adapter_cpi::{
+        state,
+        types::{DexKind, FeeSide, RouteItem, RouteQuote, UpdateConfigArgs},
+    },
     litesvm::types::TransactionMetadata,
     solana_instruction::AccountMeta,
     solana_pubkey::Pubkey,
@@ -27,11 +30,7 @@ pub trait DexAdapterExtension {
     fn dex_adapter_try_update_config(
         &mut self,
         sender: AppUser,
-        admin: Option<AppUser>,
-        is_paused: Option<bool>,
-        dex: Option<Pubkey>,
-        registry: Option<Pubkey>,
-        rotation_timeout: Option<u32>,
+        args: UpdateConfigArgs,
     ) -> Result<TransactionMetadata>;
 
     fn dex_adapter_try_confirm_admin_rotation(
@@ -39,12 +38,25 @@ pub trait DexAdapterExtension {
         sender: AppUser,
     ) -> Result<TransactionMetadata>;
 
+    fn dex_adapter_try_migrate(
+        &mut self,
+        sender: AppUser,
+        routes: &[(AppToken, AppToken)],
+    ) -> Result<TransactionMetadata>;
+
+    fn dex_adapter_try_cancel_admin_rotation(
+        &mut self,
+        sender: AppUser,
+    ) -> Result<TransactionMetadata>;
+
     fn dex_adapter_try_save_route(
         &mut self,
         sender: AppUser,
         token_first: AppToken,
         token_last: AppToken,
         route: &[RouteItem],
+        fee_bps: Option<u16>,
+        fee_side: Option<FeeSide>,
     ) -> Result<TransactionMetadata>;
 
     fn dex_adapter_try_swap_multihop(
@@ -54,6 +66,7 @@ pub trait DexAdapterExtension {
         token_out: AppToken,
         amount_in: u64,
         amount_out_minimum: u64,
+        route_items: Option<&[RouteItem]>, // to test guards
     ) -> Result<TransactionMetadata>;
 
     fn dex_adapter_try_swap_and_activate(
@@ -65,6 +78,23 @@ pub trait DexAdapterExtension {
         amount_out_minimum: u64,
     ) -> Result<TransactionMetadata>;
 
+    fn dex_adapter_try_swap_base_out(
+        &mut self,
+        sender: AppUser,
+        token_in: AppToken,
+        token_out: AppToken,
+        amount_out: u64,
+        amount_in_maximum: u64,
+    ) -> Result<TransactionMetadata>;
+
+    fn dex_adapter_try_swap_base_out_and_activate(
+        &mut self,
+        sender: AppUser,
+        token_in: AppToken,
+        token_out: AppToken,
+        amount_in_maximum: u64,
+    ) -> Result<TransactionMetadata>;
+
     fn dex_adapter_try_swap_and_unwrap_wsol(
         &mut self,
         sender: AppUser,
@@ -74,6 +104,22 @@ pub trait DexAdapterExtension {
         amount_out_minimum: u64,
     ) -> Result<TransactionMetadata>;
 
+    fn dex_adapter_try_quote(
+        &mut self,
+        sender: AppUser,
+        token_in: AppToken,
+        token_out: AppToken,
+        amount_in: u64,
+    ) -> Result<RouteQuote>;
+
+    fn dex_adapter_try_withdraw_fees(
+        &mut self,
+        sender: AppUser,
+        fee_asset: AppToken,
+        amount: Option<u64>,
+        recipient: Option<AppUser>,
+    ) -> Result<TransactionMetadata>;
+
     fn dex_adapter_query_config(&self) -> Result<state::DaConfig>;
 
     fn dex_adapter_query_admin_rotation_state(&self) -> Result<state::RotationState>;
@@ -137,11 +183,7 @@ impl DexAdapterExtension for App {
     fn dex_adapter_try_update_config(
         &mut self,
         sender: AppUser,
-        admin: Option<AppUser>,
-        is_paused: Option<bool>,
-        dex: Option<Pubkey>,
-        registry: Option<Pubkey>,
-        rotation_timeout: Option<u32>,
+        args: UpdateConfigArgs,
     ) -> Result<TransactionMetadata> {
         // programs
         let ProgramId {
@@ -165,13 +207,7 @@ impl DexAdapterExtension for App {
             admin_rotation_state,
         };
 
-        let instruction_data = instruction::UpdateConfig {
-            admin: admin.map(|x| x.pubkey()),
-            is_paused,
-            dex,
-            registry,
-            rotation_timeout,
-        };
+        let instruction_data = instruction::UpdateConfig { args };
 
         send_tx_with_ix(
             self,
@@ -223,12 +259,102 @@ impl DexAdapterExtension for App {
         )
     }
 
+    fn dex_adapter_try_migrate(
+        &mut self,
+        sender: AppUser,
+        routes: &[(AppToken, AppToken)],
+    ) -> Result<TransactionMetadata> {
+        // programs
+        let ProgramId {
+            system_program,
+            dex_adapter: program_id,
+            ..
+        } = self.program_id;
+
+        // signers
+        let payer = sender.pubkey();
+        let signers = [sender.keypair()];
+
+        // pda
+        let config = self.pda.dex_adapter_config();
+
+        let accounts = accounts::Migrate {
+            system_program,
+            sender: payer,
+            config,
+        };
+
+        let instruction_data = instruction::Migrate {};
+
+        let remaining_accounts: Vec<AccountMeta> = routes
+            .iter()
+            .map(|(token_first, token_last)| {
+                AccountMeta::new(
+                    self.pda
+                        .dex_adapter_route(token_first.pubkey(), token_last.pubkey()),
+                    false,
+                )
+            })
+            .collect();
+
+        send_tx_with_ix(
+            self,
+            &program_id,
+            &accounts,
+            &instruction_data,
+            &payer,
+            &signers,
+            &remaining_accounts,
+        )
+    }
+
+    fn dex_adapter_try_cancel_admin_rotation(
+        &mut self,
+        sender: AppUser,
+    ) -> Result<TransactionMetadata> {
+        // programs
+        let ProgramId {
+            dex_adapter: program_id,
+            ..
+        } = self.program_id;
+
+        // signers
+        let payer = sender.pubkey();
+        let signers = [sender.keypair()];
+
+        // pda
+        let bump = self.pda.dex_adapter_bump();
+        let config = self.pda.dex_adapter_config();
+        let admin_rotation_state = self.pda.dex_adapter_admin_rotation_state();
+
+        let accounts = accounts::CancelAdminRotation {
+            sender: payer,
+            bump,
+            config,
+            admin_rotation_state,
+        };
+
+        let instruction_data = instruction::CancelAdminRotation {};
+
+        send_tx_with_ix(
+            self,
+            &program_id,
+            &accounts,
+            &instruction_data,
+            &payer,
+            &signers,
+            &[],
+        )
+    }
+
     fn dex_adapter_try_save_route(
         &mut self,
         sender: AppUser,
         token_first: AppToken,
         token_last: AppToken,
         route: &[RouteItem],
+        fee_bps: Option<u16>,
+        fee_side: Option<FeeSide>,
     ) -> Result<TransactionMetadata> {
         // programs
         let ProgramId {
@@ -261,6 +387,8 @@ impl DexAdapterExtension for App {
             mint_first,
             mint_last,
             route: route.to_vec(),
+            fee_bps,
+            fee_side,
         };
 
         send_tx_with_ix(
@@ -281,6 +409,7 @@ impl DexAdapterExtension for App {
         token_out: AppToken,
         amount_in: u64,
         amount_out_minimum: u64,
+        route_items: Option<&[RouteItem]>, // to test guards
     ) -> Result<TransactionMetadata> {
         // programs
         let ProgramId {
@@ -310,6 +439,8 @@ impl DexAdapterExtension for App {
 
         // ata
         let input_token_sender_ata = self.get_or_create_ata(sender, &payer, &input_token_mint)?;
+        let (input_token_app_ata, output_token_app_ata) =
+            get_fee_app_atas(self, input_token_mint, output_token_mint);
         let output_token_sender_ata = self.get_or_create_ata(sender, &payer, &output_token_mint)?;
 
         let accounts = accounts::Swap {
@@ -326,6 +457,8 @@ impl DexAdapterExtension for App {
             input_token_mint,
             output_token_mint,
             input_token_sender_ata,
+            input_token_app_ata,
+            output_token_app_ata,
             output_token_sender_ata,
         };
 
@@ -336,6 +469,7 @@ impl DexAdapterExtension for App {
             &payer,
             input_token_mint,
             output_token_mint,
+            route_items,
         )?;
 
         let instruction_data = instruction::Swap {
@@ -391,10 +525,13 @@ impl DexAdapterExtension for App {
 
         let registry_bump = self.pda.registry_bump();
         let registry_config = self.pda.registry_config();
+        let registry_fee_whitelist = self.pda.registry_fee_whitelist();
         let registry_user_id = self.pda.registry_user_id(payer);
 
         // ata
         let input_token_sender_ata = self.get_or_create_ata(sender, &payer, &input_token_mint)?;
+        let (input_token_app_ata, output_token_app_ata) =
+            get_fee_app_atas(self, input_token_mint, output_token_mint);
         let output_token_sender_ata = self.get_or_create_ata(sender, &payer, &output_token_mint)?;
         let revenue_app_ata = App::get_ata(&registry_config, &output_token_mint);
 
@@ -412,10 +549,13 @@ impl DexAdapterExtension for App {
             registry_program: registry,
             registry_bump,
             registry_config,
+            registry_fee_whitelist,
             registry_user_id,
             input_token_mint,
             output_token_mint,
             input_token_sender_ata,
+            input_token_app_ata,
+            output_token_app_ata,
             output_token_sender_ata,
             revenue_app_ata,
         };
@@ -427,6 +567,7 @@ impl DexAdapterExtension for App {
             &payer,
             input_token_mint,
             output_token_mint,
+            None,
         )?;
 
         let instruction_data = instruction::SwapAndActivate {
@@ -445,6 +586,185 @@ impl DexAdapterExtension for App {
         )
     }
 
+    fn dex_adapter_try_swap_base_out(
+        &mut self,
+        sender: AppUser,
+        token_in: AppToken,
+        token_out: AppToken,
+        amount_out: u64,
+        amount_in_maximum: u64,
+    ) -> Result<TransactionMetadata> {
+        // programs
+        let ProgramId {
+            system_program,
+            token_program_2022,
+            token_program,
+            associated_token_program,
+            memo,
+            dex_adapter: program_id,
+            clmm_mock,
+            ..
+        } = self.program_id;
+
+        // signers
+        let payer = sender.pubkey();
+        let signers = [sender.keypair()];
+
+        // mints
+        let (input_token_mint, output_token_mint) = (token_in.pubkey(), token_out.pubkey());
+
+        // pda
+        let bump = self.pda.dex_adapter_bump();
+        let config = self.pda.dex_adapter_config();
+        let route = self
+            .pda
+            .dex_adapter_route(input_token_mint, output_token_mint);
+
+        // ata
+        let input_token_sender_ata = self.get_or_create_ata(sender, &payer, &input_token_mint)?;
+        let (input_token_app_ata, output_token_app_ata) =
+            get_fee_app_atas(self, input_token_mint, output_token_mint);
+        let output_token_sender_ata = self.get_or_create_ata(sender, &payer, &output_token_mint)?;
+
+        let accounts = accounts::Swap {
+            system_program,
+            token_program,
+            associated_token_program,
+            token_program_2022,
+            memo_program: memo,
+            clmm_mock_program: clmm_mock,
+            sender: payer,
+            bump,
+            config,
+            route,
+            input_token_mint,
+            output_token_mint,
+            input_token_sender_ata,
+            input_token_app_ata,
+            output_token_app_ata,
+            output_token_sender_ata,
+        };
+
+        // build remaining accounts based on the route loaded from PDA
+        let remaining_accounts = build_remaining_accounts_for_route(
+            self,
+            sender,
+            &payer,
+            input_token_mint,
+            output_token_mint,
+            None,
+        )?;
+
+        let instruction_data = instruction::SwapBaseOut {
+            amount_out,
+            amount_in_maximum,
+        };
+
+        send_tx_with_ix(
+            self,
+            &program_id,
+            &accounts,
+            &instruction_data,
+            &payer,
+            &signers,
+            &remaining_accounts,
+        )
+    }
+
+    fn dex_adapter_try_swap_base_out_and_activate(
+        &mut self,
+        sender: AppUser,
+        token_in: AppToken,
+        token_out: AppToken,
+        amount_in_maximum: u64,
+    ) -> Result<TransactionMetadata> {
+        // programs
+        let ProgramId {
+            system_program,
+            token_program_2022,
+            token_program,
+            associated_token_program,
+            memo,
+            registry,
+            dex_adapter: program_id,
+            clmm_mock,
+            ..
+        } = self.program_id;
+
+        // signers
+        let payer = sender.pubkey();
+        let signers = [sender.keypair()];
+
+        // mints
+        let (input_token_mint, output_token_mint) = (token_in.pubkey(), token_out.pubkey());
+
+        // pda
+        let bump = self.pda.dex_adapter_bump();
+        let config = self.pda.dex_adapter_config();
+        let route = self
+            .pda
+            .dex_adapter_route(input_token_mint, output_token_mint);
+
+        let registry_bump = self.pda.registry_bump();
+        let registry_config = self.pda.registry_config();
+        let registry_fee_whitelist = self.pda.registry_fee_whitelist();
+        let registry_user_id = self.pda.registry_user_id(payer);
+
+        // ata
+        let input_token_sender_ata = self.get_or_create_ata(sender, &payer, &input_token_mint)?;
+        let (input_token_app_ata, output_token_app_ata) =
+            get_fee_app_atas(self, input_token_mint, output_token_mint);
+        let output_token_sender_ata = self.get_or_create_ata(sender, &payer, &output_token_mint)?;
+        let revenue_app_ata = App::get_ata(&registry_config, &output_token_mint);
+
+        let accounts = accounts::SwapAndActivate {
+            system_program,
+            token_program,
+            associated_token_program,
+            token_program_2022,
+            memo_program: memo,
+            clmm_mock_program: clmm_mock,
+            sender: payer,
+            bump,
+            config,
+            route,
+            registry_program: registry,
+            registry_bump,
+            registry_config,
+            registry_fee_whitelist,
+            registry_user_id,
+            input_token_mint,
+            output_token_mint,
+            input_token_sender_ata,
+            input_token_app_ata,
+            output_token_app_ata,
+            output_token_sender_ata,
+            revenue_app_ata,
+        };
+
+        // build remaining accounts based on the route loaded from PDA
+        let remaining_accounts = build_remaining_accounts_for_route(
+            self,
+            sender,
+            &payer,
+            input_token_mint,
+            output_token_mint,
+            None,
+        )?;
+
+        let instruction_data = instruction::SwapBaseOutAndActivate { amount_in_maximum };
+
+        send_tx_with_ix(
+            self,
+            &program_id,
+            &accounts,
+            &instruction_data,
+            &payer,
+            &signers,
+            &remaining_accounts,
+        )
+    }
+
     fn dex_adapter_try_swap_and_unwrap_wsol(
         &mut self,
         sender: AppUser,
@@ -481,6 +801,8 @@ impl DexAdapterExtension for App {
 
         // ata
         let input_token_sender_ata = self.get_or_create_ata(sender, &payer, &input_token_mint)?;
+        let (input_token_app_ata, output_token_app_ata) =
+            get_fee_app_atas(self, input_token_mint, output_token_mint);
         let output_token_sender_ata = self.get_or_create_ata(sender, &payer, &output_token_mint)?;
 
         let accounts = accounts::SwapAndUnwrapWsol {
@@ -497,6 +819,8 @@ impl DexAdapterExtension for App {
             input_token_mint,
             output_token_mint,
             input_token_sender_ata,
+            input_token_app_ata,
+            output_token_app_ata,
             output_token_sender_ata,
         };
 
@@ -507,6 +831,7 @@ impl DexAdapterExtension for App {
             &payer,
             input_token_mint,
             output_token_mint,
+            None,
         )?;
 
         let instruction_data = instruction::SwapAndUnwrapWsol {
@@ -525,6 +850,125 @@ impl DexAdapterExtension for App {
         )
     }
 
+    fn dex_adapter_try_quote(
+        &mut self,
+        sender: AppUser,
+        token_in: AppToken,
+        token_out: AppToken,
+        amount_in: u64,
+    ) -> Result<RouteQuote> {
+        // programs
+        let ProgramId {
+            dex_adapter: program_id,
+            ..
+        } = self.program_id;
+
+        // signers
+        let payer = sender.pubkey();
+        let signers = [sender.keypair()];
+
+        // mints
+        let (input_token_mint, output_token_mint) = (token_in.pubkey(), token_out.pubkey());
+
+        // pda
+        let bump = self.pda.dex_adapter_bump();
+        let config = self.pda.dex_adapter_config();
+        let route = self
+            .pda
+            .dex_adapter_route(input_token_mint, output_token_mint);
+
+        let accounts = accounts::Quote {
+            bump,
+            config,
+            route,
+            input_token_mint,
+            output_token_mint,
+        };
+
+        // quote uses the same accounts as swap
+        let remaining_accounts = build_remaining_accounts_for_route(
+            self,
+            sender,
+            &payer,
+            input_token_mint,
+            output_token_mint,
+            None,
+        )?;
+
+        let instruction_data = instruction::Quote { amount_in };
+
+        let tx = send_tx_with_ix(
+            self,
+            &program_id,
+            &accounts,
+            &instruction_data,
+            &payer,
+            &signers,
+            &remaining_accounts,
+        )?;
+
+        Ok(RouteQuote::try_from_slice(&tx.return_data.data)?)
+    }
+
+    fn dex_adapter_try_withdraw_fees(
+        &mut self,
+        sender: AppUser,
+        fee_asset: AppToken,
+        amount: Option<u64>,
+        recipient: Option<AppUser>,
+    ) -> Result<TransactionMetadata> {
+        // programs
+        let ProgramId {
+            system_program,
+            token_program,
+            associated_token_program,
+            dex_adapter: program_id,
+            ..
+        } = self.program_id;
+
+        let recipient = recipient.unwrap_or(sender).pubkey();
+
+        // signers
+        let payer = sender.pubkey();
+        let signers = [sender.keypair()];
+
+        // mint
+        let fee_mint = fee_asset.pubkey();
+
+        // pda
+        let bump = self.pda.dex_adapter_bump();
+        let config = self.pda.dex_adapter_config();
+
+        // ata
+        let fee_recipient_ata = App::get_ata(&recipient, &fee_mint);
+        let fee_app_ata = App::get_ata(&config, &fee_mint);
+
+        let accounts = accounts::WithdrawFees {
+            system_program,
+            token_program,
+            associated_token_program,
+            sender: payer,
+            recipient,
+            bump,
+            config,
+            fee_mint,
+            fee_recipient_ata,
+            fee_app_ata,
+        };
+
+        let instruction_data = instruction::WithdrawFees { amount };
+
+        send_tx_with_ix(
+            self,
+            &program_id,
+            &accounts,
+            &instruction_data,
+            &payer,
+            &signers,
+            &[],
+        )
+    }
+
     fn dex_adapter_query_config(&self) -> Result<state::DaConfig> {
         get_data(&self.litesvm, &self.pda.dex_adapter_config())
     }
@@ -545,14 +989,45 @@ impl DexAdapterExtension for App {
     }
 }
 
+/// only the fee side app ATA is passed and only if the fee isn't zero
+fn get_fee_app_atas(
+    app: &App,
+    input_token_mint: Pubkey,
+    output_token_mint: Pubkey,
+) -> (Option<Pubkey>, Option<Pubkey>) {
+    let (Ok(config), Ok(route)) = (
+        app.dex_adapter_query_config(),
+        app.dex_adapter_query_route(&input_token_mint, &output_token_mint),
+    ) else {
+        return (None, None);
+    };
+
+    if config.get_fee_bps(&route) == 0 {
+        return (None, None);
+    }
+
+    let config_address = app.pda.dex_adapter_config();
+    match config.get_fee_side(&route) {
+        FeeSide::Input => (Some(App::get_ata(&config_address, &input_token_mint)), None),
+        FeeSide::Output => (
+            None,
+            Some(App::get_ata(&config_address, &output_token_mint)),
+        ),
+    }
+}
+
 fn build_remaining_accounts_for_route(
     app: &mut App,
     sender: AppUser,
     payer: &Pubkey,
     mint_in: Pubkey,
     mint_out: Pubkey,
+    route_items: Option<&[RouteItem]>,
 ) -> Result<Vec<AccountMeta>> {
-    let route_items = app.dex_adapter_query_route(&mint_in, &mint_out)?.value;
+    let route_items = match route_items {
+        Some(x) => x.to_vec(),
+        None => app.dex_adapter_query_route(&mint_in, &mint_out)?.value,
+    };
 
     // build token sequence correctly
     let token_sequence = route_items.iter().fold(vec![mint_in], |mut acc, cur| {
@@ -567,6 +1042,18 @@ fn build_remaining_accounts_for_route(
         let token_a = token_sequence[i]; // input token for this hop
         let token_b = token_sequence[i + 1]; // output token for this hop
 
+        if route_items[i].dex == DexKind::Amm {
+            remaining_accounts.extend(build_amm_hop_accounts(
+                app,
+                sender,
+                payer,
+                route_items[i].pool_id,
+                token_a,
+                token_b,
+            )?);
+            continue;
+        }
+
         // use the AMM config index from the current route item
         let amm_config_index = route_items[i].amm_index;
 
@@ -611,3 +1098,39 @@ fn build_remaining_accounts_for_route(
 
     Ok(remaining_accounts)
 }
+
+/// accounts of amm swap: program, pool_config, pool_balance, mints and ATAs
+fn build_amm_hop_accounts(
+    app: &mut App,
+    sender: AppUser,
+    payer: &Pubkey,
+    pool_id: u64,
+    mint_in: Pubkey,
+    mint_out: Pubkey,
+) -> Result<Vec<AccountMeta>> {
+    let amm = app.program_id.amm;
+    let pool_config = app.pda.amm_pool_config(pool_id);
+    let pool_balance = app.pda.amm_pool_balance(pool_id);
+
+    // use the pool mints order if the pool exists
+    let (mint_x, mint_y) = match get_data::<amm_cpi::state::PoolConfig>(&app.litesvm, &pool_config)
+    {
+        Ok(x) => (x.mint_x, x.mint_y),
+        _ => (mint_in, mint_out),
+    };
+
+    let trader_mint_x_ata = app.get_or_create_ata(sender, payer, &mint_x)?;
+    let trader_mint_y_ata = app.get_or_create_ata(sender, payer, &mint_y)?;
+
+    Ok(vec![
+        AccountMeta::new_readonly(amm, false),
+        AccountMeta::new_readonly(pool_config, false),
+        AccountMeta::new(pool_balance, false),
+        AccountMeta::new_readonly(mint_x, false),
+        AccountMeta::new_readonly(mint_y, false),
+        AccountMeta::new(trader_mint_x_ata, false),
+        AccountMeta::new(trader_mint_y_ata, false),
+        AccountMeta::new(App::get_ata(&pool_config, &mint_x), false),
+        AccountMeta::new(App::get_ata(&pool_config, &mint_y), false),
+    ])
+}
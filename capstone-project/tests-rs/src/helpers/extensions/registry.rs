@@ -11,8 +11,9 @@ use {
     registry::{accounts, instruction},
     registry_cpi::{
         state::{self, ACCOUNT_REGISTRATION_FEE_ASSET},
-        types::{AssetItem, Range},
+        types::{AssetItem, DataHeader, Range, UpdateConfigArgs},
     },
+    solana_instruction::AccountMeta,
 };
 
 pub trait RegistryExtension {
@@ -22,16 +23,13 @@ pub trait RegistryExtension {
         rotation_timeout: Option<u32>,
         account_registration_fee: Option<AssetItem>,
         account_data_size_range: Option<Range>,
+        activation_period: Option<u32>,
     ) -> Result<TransactionMetadata>;
 
     fn registry_try_update_config(
         &mut self,
         sender: AppUser,
-        admin: Option<AppUser>,
-        is_paused: Option<bool>,
-        rotation_timeout: Option<u32>,
-        registration_fee_amount: Option<u64>,
-        data_size_range: Option<Range>,
+        args: UpdateConfigArgs,
     ) -> Result<TransactionMetadata>;
 
     fn registry_try_confirm_admin_rotation(
@@ -39,6 +37,11 @@ pub trait RegistryExtension {
         sender: AppUser,
     ) -> Result<TransactionMetadata>;
 
+    fn registry_try_cancel_admin_rotation(
+        &mut self,
+        sender: AppUser,
+    ) -> Result<TransactionMetadata>;
+
     fn registry_try_withdraw_revenue(
         &mut self,
         sender: AppUser,
@@ -47,6 +50,13 @@ pub trait RegistryExtension {
         revenue_asset: Option<AppToken>, // to test guards
     ) -> Result<TransactionMetadata>;
 
+    fn registry_try_withdraw_revenue_with_sol(
+        &mut self,
+        sender: AppUser,
+        amount: Option<u64>,
+        recipient: Option<AppUser>,
+    ) -> Result<TransactionMetadata>;
+
     fn registry_try_create_account(
         &mut self,
         sender: AppUser,
@@ -54,6 +64,14 @@ pub trait RegistryExtension {
         expected_user_id: Option<u32>, // to test guards
     ) -> Result<TransactionMetadata>;
 
+    fn registry_try_create_account_sponsored(
+        &mut self,
+        sender: AppUser,
+        sponsor: AppUser,
+        max_data_size: u32,
+        is_rent_reclaimable: bool,
+    ) -> Result<TransactionMetadata>;
+
     fn registry_try_close_account(
         &mut self,
         sender: AppUser,
@@ -66,24 +84,167 @@ pub trait RegistryExtension {
         max_data_size: u32,
     ) -> Result<TransactionMetadata>;
 
+    /// the sponsor pays or redeems rent instead of the sender if it's specified
+    fn registry_try_resize_account(
+        &mut self,
+        sender: AppUser,
+        sponsor: Option<AppUser>,
+        max_data_size: u32,
+    ) -> Result<TransactionMetadata>;
+
     fn registry_try_activate_account(
         &mut self,
         sender: AppUser,
         user: Option<AppUser>,
         revenue_asset: Option<AppToken>, // to test guards
+        promo_code: Option<&str>,
+    ) -> Result<TransactionMetadata>;
+
+    fn registry_try_activate_account_with_sol(
+        &mut self,
+        sender: AppUser,
+        user: Option<AppUser>,
+        promo_code: Option<&str>,
+    ) -> Result<TransactionMetadata>;
+
+    fn registry_try_renew_activation(
+        &mut self,
+        sender: AppUser,
+        user: Option<AppUser>,
+        revenue_asset: Option<AppToken>,
+    ) -> Result<TransactionMetadata>;
+
+    fn registry_try_renew_activation_with_sol(
+        &mut self,
+        sender: AppUser,
+        user: Option<AppUser>,
     ) -> Result<TransactionMetadata>;
 
     fn registry_try_write_data(
         &mut self,
         sender: AppUser,
+        user: Option<AppUser>,
         data: &str,
         nonce: u64,
+        expected_nonce: Option<u64>,
+        header: Option<DataHeader>,
+    ) -> Result<TransactionMetadata>;
+
+    fn registry_try_write_bytes(
+        &mut self,
+        sender: AppUser,
+        user: Option<AppUser>,
+        data: &[u8],
+        nonce: u64,
+        expected_nonce: Option<u64>,
+        header: Option<DataHeader>,
+    ) -> Result<TransactionMetadata>;
+
+    fn registry_try_migrate_user_account(&mut self, sender: AppUser)
+        -> Result<TransactionMetadata>;
+
+    fn registry_try_migrate(
+        &mut self,
+        sender: AppUser,
+        users: &[AppUser],
+        user_ids: &[u32],
+    ) -> Result<TransactionMetadata>;
+
+    fn registry_try_batch_activate_accounts(
+        &mut self,
+        sender: AppUser,
+        users: &[AppUser],
+    ) -> Result<TransactionMetadata>;
+
+    fn registry_try_batch_suspend_accounts(
+        &mut self,
+        sender: AppUser,
+        users: &[AppUser],
+    ) -> Result<TransactionMetadata>;
+
+    fn registry_try_batch_close_accounts(
+        &mut self,
+        sender: AppUser,
+        users: &[AppUser],
+    ) -> Result<TransactionMetadata>;
+
+    fn registry_try_grant_delegate(
+        &mut self,
+        sender: AppUser,
+        delegate: AppUser,
+        expiration_date: Option<u64>,
+    ) -> Result<TransactionMetadata>;
+
+    fn registry_try_revoke_delegate(
+        &mut self,
+        sender: AppUser,
+        delegate: AppUser,
+    ) -> Result<TransactionMetadata>;
+
+    fn registry_try_begin_data_write(
+        &mut self,
+        sender: AppUser,
+        data_size: u32,
+    ) -> Result<TransactionMetadata>;
+
+    fn registry_try_append_data_chunk(
+        &mut self,
+        sender: AppUser,
+        offset: u32,
+        chunk: &[u8],
+    ) -> Result<TransactionMetadata>;
+
+    fn registry_try_commit_data_write(
+        &mut self,
+        sender: AppUser,
+        nonce: u64,
+        expected_nonce: Option<u64>,
+        header: Option<DataHeader>,
+    ) -> Result<TransactionMetadata>;
+
+    fn registry_try_abort_data_write(&mut self, sender: AppUser) -> Result<TransactionMetadata>;
+
+    fn registry_try_suspend_account(
+        &mut self,
+        sender: AppUser,
+        user: AppUser,
+    ) -> Result<TransactionMetadata>;
+
+    fn registry_try_unsuspend_account(
+        &mut self,
+        sender: AppUser,
+        user: AppUser,
+    ) -> Result<TransactionMetadata>;
+
+    fn registry_try_force_close_account(
+        &mut self,
+        sender: AppUser,
+        user: AppUser,
+    ) -> Result<TransactionMetadata>;
+
+    #[allow(clippy::too_many_arguments)]
+    fn registry_try_create_promo_code(
+        &mut self,
+        sender: AppUser,
+        code: &str,
+        discount_bps: u16,
+        referrer: Option<AppUser>,
+        referrer_share_bps: u16,
+        usage_limit: Option<u32>,
+        expiration_date: Option<u64>,
+    ) -> Result<TransactionMetadata>;
+
+    fn registry_try_close_promo_code(
+        &mut self,
+        sender: AppUser,
+        code: &str,
     ) -> Result<TransactionMetadata>;
 
     fn registry_try_request_account_rotation(
         &mut self,
         sender: AppUser,
         new_owner: AppUser,
+        clear_data: bool,
     ) -> Result<TransactionMetadata>;
 
     fn registry_try_confirm_account_rotation(
@@ -92,8 +253,15 @@ pub trait RegistryExtension {
         prev_owner: AppUser,
     ) -> Result<TransactionMetadata>;
 
+    fn registry_try_cancel_account_rotation(
+        &mut self,
+        sender: AppUser,
+    ) -> Result<TransactionMetadata>;
+
     fn registry_query_config(&self) -> Result<state::Config>;
 
+    fn registry_query_fee_whitelist(&self) -> Result<state::FeeWhitelist>;
+
     fn registry_query_user_counter(&self) -> Result<state::UserCounter>;
 
     fn registry_query_admin_rotation_state(&self) -> Result<state::RotationState>;
@@ -103,6 +271,18 @@ pub trait RegistryExtension {
     fn registry_query_user_account(&self, user: AppUser) -> Result<state::UserAccount>;
 
     fn registry_query_user_rotation_state(&self, user: AppUser) -> Result<state::RotationState>;
+
+    fn registry_query_user_write_buffer(&self, user: AppUser) -> Result<state::WriteBuffer>;
+
+    fn registry_query_user_index_page(&self, page: u32) -> Result<state::UserIndexPage>;
+
+    fn registry_query_promo_code(&self, code: &str) -> Result<state::PromoCode>;
+
+    fn registry_query_user_delegate(
+        &self,
+        user: AppUser,
+        delegate: AppUser,
+    ) -> Result<state::UserDelegate>;
 }
 
 impl RegistryExtension for App {
@@ -112,6 +292,7 @@ impl RegistryExtension for App {
         rotation_timeout: Option<u32>,
         account_registration_fee: Option<AssetItem>,
         account_data_size_range: Option<Range>,
+        activation_period: Option<u32>,
     ) -> Result<TransactionMetadata> {
         // programs
         let ProgramId {
@@ -137,6 +318,7 @@ impl RegistryExtension for App {
         let config = self.pda.registry_config();
         let user_counter = self.pda.registry_user_counter();
         let admin_rotation_state = self.pda.registry_admin_rotation_state();
+        let fee_whitelist = self.pda.registry_fee_whitelist();
 
         // ata
         let revenue_app_ata = App::get_ata(&config, &revenue_mint);
@@ -150,6 +332,7 @@ impl RegistryExtension for App {
             config,
             user_counter,
             admin_rotation_state,
+            fee_whitelist,
             revenue_mint,
             revenue_app_ata,
         };
@@ -158,6 +341,7 @@ impl RegistryExtension for App {
             rotation_timeout,
             account_registration_fee,
             account_data_size_range,
+            activation_period,
         };
 
         send_tx_with_ix(
@@ -174,14 +358,11 @@ impl RegistryExtension for App {
     fn registry_try_update_config(
         &mut self,
         sender: AppUser,
-        admin: Option<AppUser>,
-        is_paused: Option<bool>,
-        rotation_timeout: Option<u32>,
-        registration_fee_amount: Option<u64>,
-        data_size_range: Option<Range>,
+        args: UpdateConfigArgs,
     ) -> Result<TransactionMetadata> {
         // programs
         let ProgramId {
+            system_program,
             registry: program_id,
             ..
         } = self.program_id;
@@ -194,21 +375,18 @@ impl RegistryExtension for App {
         let bump = self.pda.registry_bump();
         let config = self.pda.registry_config();
         let admin_rotation_state = self.pda.registry_admin_rotation_state();
+        let fee_whitelist = self.pda.registry_fee_whitelist();
 
         let accounts = accounts::UpdateConfig {
+            system_program,
             sender: payer,
             bump,
             config,
             admin_rotation_state,
+            fee_whitelist,
         };
 
-        let instruction_data = instruction::UpdateConfig {
-            admin: admin.map(|x| x.pubkey()),
-            is_paused,
-            rotation_timeout,
-            registration_fee_amount,
-            data_size_range,
-        };
+        let instruction_data = instruction::UpdateConfig { args };
 
         send_tx_with_ix(
             self,
@@ -260,6 +438,45 @@ impl RegistryExtension for App {
         )
     }
 
+    fn registry_try_cancel_admin_rotation(
+        &mut self,
+        sender: AppUser,
+    ) -> Result<TransactionMetadata> {
+        // programs
+        let ProgramId {
+            registry: program_id,
+            ..
+        } = self.program_id;
+
+        // signers
+        let payer = sender.pubkey();
+        let signers = [sender.keypair()];
+
+        // pda
+        let bump = self.pda.registry_bump();
+        let config = self.pda.registry_config();
+        let admin_rotation_state = self.pda.registry_admin_rotation_state();
+
+        let accounts = accounts::CancelAdminRotation {
+            sender: payer,
+            bump,
+            config,
+            admin_rotation_state,
+        };
+
+        let instruction_data = instruction::CancelAdminRotation {};
+
+        send_tx_with_ix(
+            self,
+            &program_id,
+            &accounts,
+            &instruction_data,
+            &payer,
+            &signers,
+            &[],
+        )
+    }
+
     fn registry_try_withdraw_revenue(
         &mut self,
         sender: AppUser,
@@ -322,6 +539,50 @@ impl RegistryExtension for App {
         )
     }
 
+    fn registry_try_withdraw_revenue_with_sol(
+        &mut self,
+        sender: AppUser,
+        amount: Option<u64>,
+        recipient: Option<AppUser>,
+    ) -> Result<TransactionMetadata> {
+        // programs
+        let ProgramId {
+            system_program,
+            registry: program_id,
+            ..
+        } = self.program_id;
+
+        let recipient = recipient.unwrap_or(sender).pubkey();
+
+        // signers
+        let payer = sender.pubkey();
+        let signers = [sender.keypair()];
+
+        // pda
+        let bump = self.pda.registry_bump();
+        let config = self.pda.registry_config();
+
+        let accounts = accounts::WithdrawRevenueWithSol {
+            system_program,
+            sender: payer,
+            recipient,
+            bump,
+            config,
+        };
+
+        let instruction_data = instruction::WithdrawRevenueWithSol { amount };
+
+        send_tx_with_ix(
+            self,
+            &program_id,
+            &accounts,
+            &instruction_data,
+            &payer,
+            &signers,
+            &[],
+        )
+    }
+
     fn registry_try_create_account(
         &mut self,
         sender: AppUser,
@@ -349,19 +610,87 @@ impl RegistryExtension for App {
             expected_user_id.unwrap_or(self.registry_query_user_counter()?.last_user_id + 1);
         let user_account = self.pda.registry_user_account(expected_user_id);
         let user_rotation_state = self.pda.registry_user_rotation_state(expected_user_id);
+        let user_index_page = self
+            .pda
+            .registry_user_index_page(state::UserIndexPage::get_page(expected_user_id));
 
         let accounts = accounts::CreateAccount {
             system_program,
             sender: payer,
+            payer,
+            bump,
+            config,
+            user_counter,
+            user_id,
+            user_account,
+            user_rotation_state,
+            user_index_page,
+        };
+
+        let instruction_data = instruction::CreateAccount {
+            max_data_size,
+            is_rent_reclaimable: false,
+        };
+
+        send_tx_with_ix(
+            self,
+            &program_id,
+            &accounts,
+            &instruction_data,
+            &payer,
+            &signers,
+            &[],
+        )
+    }
+
+    fn registry_try_create_account_sponsored(
+        &mut self,
+        sender: AppUser,
+        sponsor: AppUser,
+        max_data_size: u32,
+        is_rent_reclaimable: bool,
+    ) -> Result<TransactionMetadata> {
+        // programs
+        let ProgramId {
+            system_program,
+            registry: program_id,
+            ..
+        } = self.program_id;
+
+        // signers
+        let payer = sponsor.pubkey();
+        let signers = [sender.keypair(), sponsor.keypair()];
+
+        // pda
+        let bump = self.pda.registry_bump();
+        let config = self.pda.registry_config();
+        let user_counter = self.pda.registry_user_counter();
+
+        let user_id = self.pda.registry_user_id(sender.pubkey());
+        let expected_user_id = self.registry_query_user_counter()?.last_user_id + 1;
+        let user_account = self.pda.registry_user_account(expected_user_id);
+        let user_rotation_state = self.pda.registry_user_rotation_state(expected_user_id);
+        let user_index_page = self
+            .pda
+            .registry_user_index_page(state::UserIndexPage::get_page(expected_user_id));
+
+        let accounts = accounts::CreateAccount {
+            system_program,
+            sender: sender.pubkey(),
+            payer,
             bump,
             config,
             user_counter,
             user_id,
             user_account,
             user_rotation_state,
+            user_index_page,
         };
 
-        let instruction_data = instruction::CreateAccount { max_data_size };
+        let instruction_data = instruction::CreateAccount {
+            max_data_size,
+            is_rent_reclaimable,
+        };
 
         send_tx_with_ix(
             self,
@@ -394,16 +723,20 @@ impl RegistryExtension for App {
 
         // pda
         let user_id = self.pda.registry_user_id(user.pubkey());
-        let id = self.registry_query_user_id(user)?.id;
+        let user_id_value = self.registry_query_user_id(user)?;
+        let id = user_id_value.id;
         let user_account = self.pda.registry_user_account(id);
         let user_rotation_state = self.pda.registry_user_rotation_state(id);
+        let user_write_buffer = self.pda.registry_user_write_buffer(id);
 
         let accounts = accounts::CloseAccount {
             system_program,
             sender: payer,
+            rent_recipient: user_id_value.get_rent_recipient(payer),
             user_id,
             user_account,
             user_rotation_state,
+            user_write_buffer,
         };
 
         let instruction_data = instruction::CloseAccount {};
@@ -467,56 +800,1014 @@ impl RegistryExtension for App {
         )
     }
 
-    fn registry_try_activate_account(
+    fn registry_try_resize_account(
         &mut self,
         sender: AppUser,
-        user: Option<AppUser>,
-        revenue_asset: Option<AppToken>, // to test asset guard
+        sponsor: Option<AppUser>,
+        max_data_size: u32,
     ) -> Result<TransactionMetadata> {
         // programs
         let ProgramId {
             system_program,
-            token_program,
-            associated_token_program,
             registry: program_id,
             ..
         } = self.program_id;
 
-        let user = user.unwrap_or(sender).pubkey();
-
         // signers
-        let payer = sender.pubkey();
-        let signers = [sender.keypair()];
-
-        // mint
-        let revenue_mint = match revenue_asset {
-            Some(x) => x.pubkey(),
-            _ => self.registry_query_config()?.registration_fee.asset,
-        };
+        let payer = sponsor.unwrap_or(sender).pubkey();
+        let signers: Vec<_> = std::iter::once(sender)
+            .chain(sponsor)
+            .map(|x| x.keypair())
+            .collect();
+
+        // pda
+        let bump = self.pda.registry_bump();
+        let config = self.pda.registry_config();
+
+        let user_id = self.pda.registry_user_id(sender.pubkey());
+        let id = self.registry_query_user_id(sender)?.id;
+        let user_account = self.pda.registry_user_account(id);
+
+        let accounts = accounts::ResizeAccount {
+            system_program,
+            sender: sender.pubkey(),
+            rent_payer: payer,
+            bump,
+            config,
+            user_id,
+            user_account,
+        };
+
+        let instruction_data = instruction::ResizeAccount { max_data_size };
+
+        send_tx_with_ix(
+            self,
+            &program_id,
+            &accounts,
+            &instruction_data,
+            &payer,
+            &signers,
+            &[],
+        )
+    }
+
+    fn registry_try_activate_account(
+        &mut self,
+        sender: AppUser,
+        user: Option<AppUser>,
+        revenue_asset: Option<AppToken>, // to test asset guard
+        promo_code: Option<&str>,
+    ) -> Result<TransactionMetadata> {
+        // programs
+        let ProgramId {
+            system_program,
+            token_program,
+            associated_token_program,
+            registry: program_id,
+            ..
+        } = self.program_id;
+
+        let user = user.unwrap_or(sender).pubkey();
+
+        // signers
+        let payer = sender.pubkey();
+        let signers = [sender.keypair()];
+
+        // mint
+        let revenue_mint = match revenue_asset {
+            Some(x) => x.pubkey(),
+            _ => self.registry_query_config()?.registration_fee.asset,
+        };
+
+        // pda
+        let bump = self.pda.registry_bump();
+        let config = self.pda.registry_config();
+        let fee_whitelist = self.pda.registry_fee_whitelist();
+        let user_id = self.pda.registry_user_id(user);
+
+        let (promo_code, referrer) = match promo_code {
+            Some(x) => (
+                Some(self.pda.registry_promo_code(x)),
+                self.registry_query_promo_code(x)?.referrer,
+            ),
+            None => (None, None),
+        };
+
+        // ata
+        let revenue_sender_ata = App::get_ata(&payer, &revenue_mint);
+        let revenue_app_ata = App::get_ata(&config, &revenue_mint);
+        let referrer_ata = referrer.map(|x| App::get_ata(&x, &revenue_mint));
+
+        let accounts = accounts::ActivateAccount {
+            system_program,
+            token_program,
+            associated_token_program,
+            sender: payer,
+            bump,
+            config,
+            fee_whitelist,
+            user_id,
+            promo_code,
+            revenue_mint,
+            revenue_sender_ata,
+            revenue_app_ata,
+            referrer_ata,
+        };
+
+        let instruction_data = instruction::ActivateAccount { user };
+
+        send_tx_with_ix(
+            self,
+            &program_id,
+            &accounts,
+            &instruction_data,
+            &payer,
+            &signers,
+            &[],
+        )
+    }
+
+    fn registry_try_activate_account_with_sol(
+        &mut self,
+        sender: AppUser,
+        user: Option<AppUser>,
+        promo_code: Option<&str>,
+    ) -> Result<TransactionMetadata> {
+        // programs
+        let ProgramId {
+            system_program,
+            registry: program_id,
+            ..
+        } = self.program_id;
+
+        let user = user.unwrap_or(sender).pubkey();
+
+        // signers
+        let payer = sender.pubkey();
+        let signers = [sender.keypair()];
+
+        // pda
+        let bump = self.pda.registry_bump();
+        let config = self.pda.registry_config();
+        let fee_whitelist = self.pda.registry_fee_whitelist();
+        let user_id = self.pda.registry_user_id(user);
+        let (promo_code, referrer) = match promo_code {
+            Some(x) => (
+                Some(self.pda.registry_promo_code(x)),
+                self.registry_query_promo_code(x)?.referrer,
+            ),
+            None => (None, None),
+        };
+
+        let accounts = accounts::ActivateAccountWithSol {
+            system_program,
+            sender: payer,
+            bump,
+            config,
+            fee_whitelist,
+            user_id,
+            promo_code,
+            referrer,
+        };
+
+        let instruction_data = instruction::ActivateAccountWithSol { user };
+
+        send_tx_with_ix(
+            self,
+            &program_id,
+            &accounts,
+            &instruction_data,
+            &payer,
+            &signers,
+            &[],
+        )
+    }
+
+    fn registry_try_renew_activation(
+        &mut self,
+        sender: AppUser,
+        user: Option<AppUser>,
+        revenue_asset: Option<AppToken>,
+    ) -> Result<TransactionMetadata> {
+        // programs
+        let ProgramId {
+            system_program,
+            token_program,
+            associated_token_program,
+            registry: program_id,
+            ..
+        } = self.program_id;
+
+        let user = user.unwrap_or(sender).pubkey();
+
+        // signers
+        let payer = sender.pubkey();
+        let signers = [sender.keypair()];
+
+        // mint
+        let revenue_mint = match revenue_asset {
+            Some(x) => x.pubkey(),
+            _ => self.registry_query_config()?.registration_fee.asset,
+        };
+
+        // pda
+        let bump = self.pda.registry_bump();
+        let config = self.pda.registry_config();
+        let fee_whitelist = self.pda.registry_fee_whitelist();
+        let user_id = self.pda.registry_user_id(user);
+
+        // ata
+        let revenue_sender_ata = App::get_ata(&payer, &revenue_mint);
+        let revenue_app_ata = App::get_ata(&config, &revenue_mint);
+
+        let accounts = accounts::ActivateAccount {
+            system_program,
+            token_program,
+            associated_token_program,
+            sender: payer,
+            bump,
+            config,
+            fee_whitelist,
+            user_id,
+            promo_code: None,
+            revenue_mint,
+            revenue_sender_ata,
+            revenue_app_ata,
+            referrer_ata: None,
+        };
+
+        let instruction_data = instruction::RenewActivation { user };
+
+        send_tx_with_ix(
+            self,
+            &program_id,
+            &accounts,
+            &instruction_data,
+            &payer,
+            &signers,
+            &[],
+        )
+    }
+
+    fn registry_try_renew_activation_with_sol(
+        &mut self,
+        sender: AppUser,
+        user: Option<AppUser>,
+    ) -> Result<TransactionMetadata> {
+        // programs
+        let ProgramId {
+            system_program,
+            registry: program_id,
+            ..
+        } = self.program_id;
+
+        let user = user.unwrap_or(sender).pubkey();
+
+        // signers
+        let payer = sender.pubkey();
+        let signers = [sender.keypair()];
+
+        // pda
+        let bump = self.pda.registry_bump();
+        let config = self.pda.registry_config();
+        let fee_whitelist = self.pda.registry_fee_whitelist();
+        let user_id = self.pda.registry_user_id(user);
+
+        let accounts = accounts::ActivateAccountWithSol {
+            system_program,
+            sender: payer,
+            bump,
+            config,
+            fee_whitelist,
+            user_id,
+            promo_code: None,
+            referrer: None,
+        };
+
+        let instruction_data = instruction::RenewActivationWithSol { user };
+
+        send_tx_with_ix(
+            self,
+            &program_id,
+            &accounts,
+            &instruction_data,
+            &payer,
+            &signers,
+            &[],
+        )
+    }
+
+    fn registry_try_write_data(
+        &mut self,
+        sender: AppUser,
+        user: Option<AppUser>,
+        data: &str,
+        nonce: u64,
+        expected_nonce: Option<u64>,
+        header: Option<DataHeader>,
+    ) -> Result<TransactionMetadata> {
+        // programs
+        let ProgramId {
+            registry: program_id,
+            ..
+        } = self.program_id;
+
+        // signers
+        let payer = sender.pubkey();
+        let signers = [sender.keypair()];
+
+        let user = user.unwrap_or(sender);
+
+        // pda
+        let bump = self.pda.registry_bump();
+        let config = self.pda.registry_config();
+        let user_id = self.pda.registry_user_id(user.pubkey());
+        let id = self.registry_query_user_id(user)?.id;
+        let user_account = self.pda.registry_user_account(id);
+        let user_delegate = if user == sender {
+            None
+        } else {
+            Some(self.pda.registry_user_delegate(id, payer))
+        };
+
+        let accounts = accounts::WriteData {
+            sender: payer,
+            bump,
+            config,
+            user_id,
+            user_account,
+            user_delegate,
+        };
+
+        let instruction_data = instruction::WriteData {
+            user: user.pubkey(),
+            data: data.to_string(),
+            nonce,
+            expected_nonce,
+            header: header.unwrap_or_else(|| DataHeader::new(0, 0, data.as_bytes())),
+        };
+
+        send_tx_with_ix(
+            self,
+            &program_id,
+            &accounts,
+            &instruction_data,
+            &payer,
+            &signers,
+            &[],
+        )
+    }
+
+    fn registry_try_write_bytes(
+        &mut self,
+        sender: AppUser,
+        user: Option<AppUser>,
+        data: &[u8],
+        nonce: u64,
+        expected_nonce: Option<u64>,
+        header: Option<DataHeader>,
+    ) -> Result<TransactionMetadata> {
+        // programs
+        let ProgramId {
+            registry: program_id,
+            ..
+        } = self.program_id;
+
+        // signers
+        let payer = sender.pubkey();
+        let signers = [sender.keypair()];
+
+        let user = user.unwrap_or(sender);
+
+        // pda
+        let bump = self.pda.registry_bump();
+        let config = self.pda.registry_config();
+        let user_id = self.pda.registry_user_id(user.pubkey());
+        let id = self.registry_query_user_id(user)?.id;
+        let user_account = self.pda.registry_user_account(id);
+        let user_delegate = if user == sender {
+            None
+        } else {
+            Some(self.pda.registry_user_delegate(id, payer))
+        };
+
+        let accounts = accounts::WriteData {
+            sender: payer,
+            bump,
+            config,
+            user_id,
+            user_account,
+            user_delegate,
+        };
+
+        let instruction_data = instruction::WriteBytes {
+            user: user.pubkey(),
+            data: data.to_vec(),
+            nonce,
+            expected_nonce,
+            header: header.unwrap_or_else(|| DataHeader::new(0, 0, data)),
+        };
+
+        send_tx_with_ix(
+            self,
+            &program_id,
+            &accounts,
+            &instruction_data,
+            &payer,
+            &signers,
+            &[],
+        )
+    }
+
+    fn registry_try_migrate_user_account(
+        &mut self,
+        sender: AppUser,
+    ) -> Result<TransactionMetadata> {
+        // programs
+        let ProgramId {
+            system_program,
+            registry: program_id,
+            ..
+        } = self.program_id;
+
+        // signers
+        let payer = sender.pubkey();
+        let signers = [sender.keypair()];
+
+        // pda
+        let user_id = self.pda.registry_user_id(payer);
+        let id = self.registry_query_user_id(sender)?.id;
+        let user_account = self.pda.registry_user_account(id);
+
+        let accounts = accounts::MigrateUserAccount {
+            system_program,
+            sender: payer,
+            user_id,
+            user_account,
+        };
+
+        let instruction_data = instruction::MigrateUserAccount {};
+
+        send_tx_with_ix(
+            self,
+            &program_id,
+            &accounts,
+            &instruction_data,
+            &payer,
+            &signers,
+            &[],
+        )
+    }
+
+    fn registry_try_migrate(
+        &mut self,
+        sender: AppUser,
+        users: &[AppUser],
+        user_ids: &[u32],
+    ) -> Result<TransactionMetadata> {
+        // programs
+        let ProgramId {
+            system_program,
+            registry: program_id,
+            ..
+        } = self.program_id;
+
+        // signers
+        let payer = sender.pubkey();
+        let signers = [sender.keypair()];
+
+        // pda
+        let bump = self.pda.registry_bump();
+        let config = self.pda.registry_config();
+        let admin_rotation_state = self.pda.registry_admin_rotation_state();
+        let fee_whitelist = self.pda.registry_fee_whitelist();
+
+        let accounts = accounts::Migrate {
+            system_program,
+            sender: payer,
+            bump,
+            config,
+            admin_rotation_state,
+            fee_whitelist,
+        };
+
+        let instruction_data = instruction::Migrate {};
+
+        let remaining_accounts: Vec<AccountMeta> = users
+            .iter()
+            .map(|x| AccountMeta::new(self.pda.registry_user_id(x.pubkey()), false))
+            .chain(
+                user_ids
+                    .iter()
+                    .map(|x| AccountMeta::new(self.pda.registry_user_rotation_state(*x), false)),
+            )
+            .collect();
+
+        send_tx_with_ix(
+            self,
+            &program_id,
+            &accounts,
+            &instruction_data,
+            &payer,
+            &signers,
+            &remaining_accounts,
+        )
+    }
+
+    fn registry_try_batch_activate_accounts(
+        &mut self,
+        sender: AppUser,
+        users: &[AppUser],
+    ) -> Result<TransactionMetadata> {
+        // programs
+        let ProgramId {
+            registry: program_id,
+            ..
+        } = self.program_id;
+
+        // signers
+        let payer = sender.pubkey();
+        let signers = [sender.keypair()];
+
+        // pda
+        let bump = self.pda.registry_bump();
+        let config = self.pda.registry_config();
+
+        let accounts = accounts::BatchUpdateAccounts {
+            sender: payer,
+            bump,
+            config,
+        };
+
+        let instruction_data = instruction::BatchActivateAccounts {};
+
+        let remaining_accounts: Vec<AccountMeta> = users
+            .iter()
+            .flat_map(|x| {
+                [
+                    AccountMeta::new_readonly(x.pubkey(), false),
+                    AccountMeta::new(self.pda.registry_user_id(x.pubkey()), false),
+                ]
+            })
+            .collect();
+
+        send_tx_with_ix(
+            self,
+            &program_id,
+            &accounts,
+            &instruction_data,
+            &payer,
+            &signers,
+            &remaining_accounts,
+        )
+    }
+
+    fn registry_try_batch_suspend_accounts(
+        &mut self,
+        sender: AppUser,
+        users: &[AppUser],
+    ) -> Result<TransactionMetadata> {
+        // programs
+        let ProgramId {
+            registry: program_id,
+            ..
+        } = self.program_id;
+
+        // signers
+        let payer = sender.pubkey();
+        let signers = [sender.keypair()];
+
+        // pda
+        let bump = self.pda.registry_bump();
+        let config = self.pda.registry_config();
+
+        let accounts = accounts::BatchUpdateAccounts {
+            sender: payer,
+            bump,
+            config,
+        };
+
+        let instruction_data = instruction::BatchSuspendAccounts {};
+
+        let remaining_accounts: Vec<AccountMeta> = users
+            .iter()
+            .flat_map(|x| {
+                [
+                    AccountMeta::new_readonly(x.pubkey(), false),
+                    AccountMeta::new(self.pda.registry_user_id(x.pubkey()), false),
+                ]
+            })
+            .collect();
+
+        send_tx_with_ix(
+            self,
+            &program_id,
+            &accounts,
+            &instruction_data,
+            &payer,
+            &signers,
+            &remaining_accounts,
+        )
+    }
+
+    fn registry_try_batch_close_accounts(
+        &mut self,
+        sender: AppUser,
+        users: &[AppUser],
+    ) -> Result<TransactionMetadata> {
+        // programs
+        let ProgramId {
+            registry: program_id,
+            ..
+        } = self.program_id;
+
+        // signers
+        let payer = sender.pubkey();
+        let signers = [sender.keypair()];
+
+        // pda
+        let bump = self.pda.registry_bump();
+        let config = self.pda.registry_config();
+
+        let accounts = accounts::BatchUpdateAccounts {
+            sender: payer,
+            bump,
+            config,
+        };
+
+        let instruction_data = instruction::BatchCloseAccounts {};
+
+        let mut remaining_accounts: Vec<AccountMeta> = vec![];
+        for user in users {
+            let user_id = self.registry_query_user_id(*user)?;
+
+            remaining_accounts.extend([
+                AccountMeta::new(user.pubkey(), false),
+                AccountMeta::new(self.pda.registry_user_id(user.pubkey()), false),
+                AccountMeta::new(self.pda.registry_user_account(user_id.id), false),
+                AccountMeta::new(self.pda.registry_user_rotation_state(user_id.id), false),
+                AccountMeta::new(self.pda.registry_user_write_buffer(user_id.id), false),
+                AccountMeta::new(user_id.get_rent_recipient(user.pubkey()), false),
+            ]);
+        }
+
+        send_tx_with_ix(
+            self,
+            &program_id,
+            &accounts,
+            &instruction_data,
+            &payer,
+            &signers,
+            &remaining_accounts,
+        )
+    }
+
+    fn registry_try_grant_delegate(
+        &mut self,
+        sender: AppUser,
+        delegate: AppUser,
+        expiration_date: Option<u64>,
+    ) -> Result<TransactionMetadata> {
+        // programs
+        let ProgramId {
+            system_program,
+            registry: program_id,
+            ..
+        } = self.program_id;
+
+        // signers
+        let payer = sender.pubkey();
+        let signers = [sender.keypair()];
+
+        // pda
+        let user_id = self.pda.registry_user_id(payer);
+        let id = self.registry_query_user_id(sender)?.id;
+        let user_delegate = self.pda.registry_user_delegate(id, delegate.pubkey());
+
+        let accounts = accounts::GrantDelegate {
+            system_program,
+            sender: payer,
+            user_id,
+            user_delegate,
+        };
+
+        let instruction_data = instruction::GrantDelegate {
+            delegate: delegate.pubkey(),
+            expiration_date,
+        };
+
+        send_tx_with_ix(
+            self,
+            &program_id,
+            &accounts,
+            &instruction_data,
+            &payer,
+            &signers,
+            &[],
+        )
+    }
+
+    fn registry_try_revoke_delegate(
+        &mut self,
+        sender: AppUser,
+        delegate: AppUser,
+    ) -> Result<TransactionMetadata> {
+        // programs
+        let ProgramId {
+            system_program,
+            registry: program_id,
+            ..
+        } = self.program_id;
+
+        // signers
+        let payer = sender.pubkey();
+        let signers = [sender.keypair()];
+
+        // pda
+        let user_id = self.pda.registry_user_id(payer);
+        let id = self.registry_query_user_id(sender)?.id;
+        let user_delegate = self.pda.registry_user_delegate(id, delegate.pubkey());
+
+        let accounts = accounts::RevokeDelegate {
+            system_program,
+            sender: payer,
+            user_id,
+            user_delegate,
+        };
+
+        let instruction_data = instruction::RevokeDelegate {
+            delegate: delegate.pubkey(),
+        };
+
+        send_tx_with_ix(
+            self,
+            &program_id,
+            &accounts,
+            &instruction_data,
+            &payer,
+            &signers,
+            &[],
+        )
+    }
+
+    fn registry_try_begin_data_write(
+        &mut self,
+        sender: AppUser,
+        data_size: u32,
+    ) -> Result<TransactionMetadata> {
+        // programs
+        let ProgramId {
+            system_program,
+            registry: program_id,
+            ..
+        } = self.program_id;
+
+        // signers
+        let payer = sender.pubkey();
+        let signers = [sender.keypair()];
+
+        // pda
+        let bump = self.pda.registry_bump();
+        let config = self.pda.registry_config();
+        let user_id = self.pda.registry_user_id(payer);
+        let id = self.registry_query_user_id(sender)?.id;
+        let user_account = self.pda.registry_user_account(id);
+        let user_write_buffer = self.pda.registry_user_write_buffer(id);
+
+        let accounts = accounts::BeginDataWrite {
+            system_program,
+            sender: payer,
+            bump,
+            config,
+            user_id,
+            user_account,
+            user_write_buffer,
+        };
+
+        let instruction_data = instruction::BeginDataWrite { data_size };
+
+        send_tx_with_ix(
+            self,
+            &program_id,
+            &accounts,
+            &instruction_data,
+            &payer,
+            &signers,
+            &[],
+        )
+    }
+
+    fn registry_try_append_data_chunk(
+        &mut self,
+        sender: AppUser,
+        offset: u32,
+        chunk: &[u8],
+    ) -> Result<TransactionMetadata> {
+        // programs
+        let ProgramId {
+            registry: program_id,
+            ..
+        } = self.program_id;
+
+        // signers
+        let payer = sender.pubkey();
+        let signers = [sender.keypair()];
+
+        // pda
+        let bump = self.pda.registry_bump();
+        let config = self.pda.registry_config();
+        let user_id = self.pda.registry_user_id(payer);
+        let id = self.registry_query_user_id(sender)?.id;
+        let user_write_buffer = self.pda.registry_user_write_buffer(id);
+
+        let accounts = accounts::AppendDataChunk {
+            sender: payer,
+            bump,
+            config,
+            user_id,
+            user_write_buffer,
+        };
+
+        let instruction_data = instruction::AppendDataChunk {
+            offset,
+            chunk: chunk.to_vec(),
+        };
+
+        send_tx_with_ix(
+            self,
+            &program_id,
+            &accounts,
+            &instruction_data,
+            &payer,
+            &signers,
+            &[],
+        )
+    }
+
+    fn registry_try_commit_data_write(
+        &mut self,
+        sender: AppUser,
+        nonce: u64,
+        expected_nonce: Option<u64>,
+        header: Option<DataHeader>,
+    ) -> Result<TransactionMetadata> {
+        // programs
+        let ProgramId {
+            registry: program_id,
+            ..
+        } = self.program_id;
+
+        // signers
+        let payer = sender.pubkey();
+        let signers = [sender.keypair()];
+
+        // pda
+        let bump = self.pda.registry_bump();
+        let config = self.pda.registry_config();
+        let user_id = self.pda.registry_user_id(payer);
+        let id = self.registry_query_user_id(sender)?.id;
+        let user_account = self.pda.registry_user_account(id);
+        let user_write_buffer = self.pda.registry_user_write_buffer(id);
+
+        let accounts = accounts::CommitDataWrite {
+            sender: payer,
+            bump,
+            config,
+            user_id,
+            user_account,
+            user_write_buffer,
+        };
+
+        let header = header.unwrap_or_else(|| {
+            self.registry_query_user_write_buffer(sender)
+                .map(|x| DataHeader::new(0, 0, &x.data))
+                .unwrap_or_default()
+        });
+
+        let instruction_data = instruction::CommitDataWrite {
+            nonce,
+            expected_nonce,
+            header,
+        };
+
+        send_tx_with_ix(
+            self,
+            &program_id,
+            &accounts,
+            &instruction_data,
+            &payer,
+            &signers,
+            &[],
+        )
+    }
+
+    fn registry_try_abort_data_write(&mut self, sender: AppUser) -> Result<TransactionMetadata> {
+        // programs
+        let ProgramId {
+            registry: program_id,
+            ..
+        } = self.program_id;
+
+        // signers
+        let payer = sender.pubkey();
+        let signers = [sender.keypair()];
+
+        // pda
+        let user_id = self.pda.registry_user_id(payer);
+        let id = self.registry_query_user_id(sender)?.id;
+        let user_write_buffer = self.pda.registry_user_write_buffer(id);
+
+        let accounts = accounts::AbortDataWrite {
+            sender: payer,
+            user_id,
+            user_write_buffer,
+        };
+
+        let instruction_data = instruction::AbortDataWrite {};
+
+        send_tx_with_ix(
+            self,
+            &program_id,
+            &accounts,
+            &instruction_data,
+            &payer,
+            &signers,
+            &[],
+        )
+    }
+
+    fn registry_try_suspend_account(
+        &mut self,
+        sender: AppUser,
+        user: AppUser,
+    ) -> Result<TransactionMetadata> {
+        // programs
+        let ProgramId {
+            registry: program_id,
+            ..
+        } = self.program_id;
+
+        let user = user.pubkey();
+
+        // signers
+        let payer = sender.pubkey();
+        let signers = [sender.keypair()];
 
         // pda
         let bump = self.pda.registry_bump();
         let config = self.pda.registry_config();
         let user_id = self.pda.registry_user_id(user);
 
-        // ata
-        let revenue_sender_ata = App::get_ata(&payer, &revenue_mint);
-        let revenue_app_ata = App::get_ata(&config, &revenue_mint);
+        let accounts = accounts::SuspendAccount {
+            sender: payer,
+            bump,
+            config,
+            user_id,
+        };
 
-        let accounts = accounts::ActivateAccount {
-            system_program,
-            token_program,
-            associated_token_program,
+        let instruction_data = instruction::SuspendAccount { user };
+
+        send_tx_with_ix(
+            self,
+            &program_id,
+            &accounts,
+            &instruction_data,
+            &payer,
+            &signers,
+            &[],
+        )
+    }
+
+    fn registry_try_unsuspend_account(
+        &mut self,
+        sender: AppUser,
+        user: AppUser,
+    ) -> Result<TransactionMetadata> {
+        // programs
+        let ProgramId {
+            registry: program_id,
+            ..
+        } = self.program_id;
+
+        let user = user.pubkey();
+
+        // signers
+        let payer = sender.pubkey();
+        let signers = [sender.keypair()];
+
+        // pda
+        let bump = self.pda.registry_bump();
+        let config = self.pda.registry_config();
+        let user_id = self.pda.registry_user_id(user);
+
+        let accounts = accounts::SuspendAccount {
             sender: payer,
             bump,
             config,
             user_id,
-            revenue_mint,
-            revenue_sender_ata,
-            revenue_app_ata,
         };
 
-        let instruction_data = instruction::ActivateAccount { user };
+        let instruction_data = instruction::UnsuspendAccount { user };
 
         send_tx_with_ix(
             self,
@@ -529,14 +1820,14 @@ impl RegistryExtension for App {
         )
     }
 
-    fn registry_try_write_data(
+    fn registry_try_force_close_account(
         &mut self,
         sender: AppUser,
-        data: &str,
-        nonce: u64,
+        user: AppUser,
     ) -> Result<TransactionMetadata> {
         // programs
         let ProgramId {
+            system_program,
             registry: program_id,
             ..
         } = self.program_id;
@@ -546,19 +1837,128 @@ impl RegistryExtension for App {
         let signers = [sender.keypair()];
 
         // pda
-        let user_id = self.pda.registry_user_id(payer);
-        let id = self.registry_query_user_id(sender)?.id;
+        let bump = self.pda.registry_bump();
+        let config = self.pda.registry_config();
+        let user_id = self.pda.registry_user_id(user.pubkey());
+        let user_id_value = self.registry_query_user_id(user)?;
+        let id = user_id_value.id;
         let user_account = self.pda.registry_user_account(id);
+        let user_rotation_state = self.pda.registry_user_rotation_state(id);
+        let user_write_buffer = self.pda.registry_user_write_buffer(id);
 
-        let accounts = accounts::WriteData {
+        let accounts = accounts::ForceCloseAccount {
+            system_program,
             sender: payer,
+            rent_recipient: user_id_value.get_rent_recipient(user.pubkey()),
+            owner: user.pubkey(),
+            bump,
+            config,
             user_id,
             user_account,
+            user_rotation_state,
+            user_write_buffer,
         };
 
-        let instruction_data = instruction::WriteData {
-            data: data.to_string(),
-            nonce,
+        let instruction_data = instruction::ForceCloseAccount {
+            user: user.pubkey(),
+        };
+
+        send_tx_with_ix(
+            self,
+            &program_id,
+            &accounts,
+            &instruction_data,
+            &payer,
+            &signers,
+            &[],
+        )
+    }
+
+    fn registry_try_create_promo_code(
+        &mut self,
+        sender: AppUser,
+        code: &str,
+        discount_bps: u16,
+        referrer: Option<AppUser>,
+        referrer_share_bps: u16,
+        usage_limit: Option<u32>,
+        expiration_date: Option<u64>,
+    ) -> Result<TransactionMetadata> {
+        // programs
+        let ProgramId {
+            system_program,
+            registry: program_id,
+            ..
+        } = self.program_id;
+
+        // signers
+        let payer = sender.pubkey();
+        let signers = [sender.keypair()];
+
+        // pda
+        let bump = self.pda.registry_bump();
+        let config = self.pda.registry_config();
+        let promo_code = self.pda.registry_promo_code(code);
+
+        let accounts = accounts::CreatePromoCode {
+            system_program,
+            sender: payer,
+            bump,
+            config,
+            promo_code,
+        };
+
+        let instruction_data = instruction::CreatePromoCode {
+            code: code.to_string(),
+            discount_bps,
+            referrer: referrer.map(|x| x.pubkey()),
+            referrer_share_bps,
+            usage_limit,
+            expiration_date,
+        };
+
+        send_tx_with_ix(
+            self,
+            &program_id,
+            &accounts,
+            &instruction_data,
+            &payer,
+            &signers,
+            &[],
+        )
+    }
+
+    fn registry_try_close_promo_code(
+        &mut self,
+        sender: AppUser,
+        code: &str,
+    ) -> Result<TransactionMetadata> {
+        // programs
+        let ProgramId {
+            system_program,
+            registry: program_id,
+            ..
+        } = self.program_id;
+
+        // signers
+        let payer = sender.pubkey();
+        let signers = [sender.keypair()];
+
+        // pda
+        let bump = self.pda.registry_bump();
+        let config = self.pda.registry_config();
+        let promo_code = self.pda.registry_promo_code(code);
+
+        let accounts = accounts::ClosePromoCode {
+            system_program,
+            sender: payer,
+            bump,
+            config,
+            promo_code,
+        };
+
+        let instruction_data = instruction::ClosePromoCode {
+            code: code.to_string(),
         };
 
         send_tx_with_ix(
@@ -576,6 +1976,7 @@ impl RegistryExtension for App {
         &mut self,
         sender: AppUser,
         new_owner: AppUser,
+        clear_data: bool,
     ) -> Result<TransactionMetadata> {
         // programs
         let ProgramId {
@@ -605,6 +2006,7 @@ impl RegistryExtension for App {
 
         let instruction_data = instruction::RequestAccountRotation {
             new_owner: new_owner.pubkey(),
+            clear_data,
         };
 
         send_tx_with_ix(
@@ -635,17 +2037,29 @@ impl RegistryExtension for App {
         let signers = [sender.keypair()];
 
         // pda
+        let bump = self.pda.registry_bump();
+        let config = self.pda.registry_config();
         let user_id_pre = self.pda.registry_user_id(prev_owner.pubkey());
         let user_id = self.pda.registry_user_id(payer);
         let user_id_value_pre = self.registry_query_user_id(prev_owner)?.id;
         let user_rotation_state = self.pda.registry_user_rotation_state(user_id_value_pre);
+        let user_account = self.pda.registry_user_account(user_id_value_pre);
+        let user_write_buffer = self.pda.registry_user_write_buffer(user_id_value_pre);
+        let user_index_page = self
+            .pda
+            .registry_user_index_page(state::UserIndexPage::get_page(user_id_value_pre));
 
         let accounts = accounts::ConfirmAccountRotation {
             system_program,
             sender: payer,
+            bump,
+            config,
             user_id_pre,
             user_id,
             user_rotation_state,
+            user_account,
+            user_write_buffer,
+            user_index_page,
         };
 
         let instruction_data = instruction::ConfirmAccountRotation {};
@@ -661,10 +2075,52 @@ impl RegistryExtension for App {
         )
     }
 
+    fn registry_try_cancel_account_rotation(
+        &mut self,
+        sender: AppUser,
+    ) -> Result<TransactionMetadata> {
+        // programs
+        let ProgramId {
+            registry: program_id,
+            ..
+        } = self.program_id;
+
+        // signers
+        let payer = sender.pubkey();
+        let signers = [sender.keypair()];
+
+        // pda
+        let user_id = self.pda.registry_user_id(payer);
+        let id = self.registry_query_user_id(sender)?.id;
+        let user_rotation_state = self.pda.registry_user_rotation_state(id);
+
+        let accounts = accounts::CancelAccountRotation {
+            sender: payer,
+            user_id,
+            user_rotation_state,
+        };
+
+        let instruction_data = instruction::CancelAccountRotation {};
+
+        send_tx_with_ix(
+            self,
+            &program_id,
+            &accounts,
+            &instruction_data,
+            &payer,
+            &signers,
+            &[],
+        )
+    }
+
     fn registry_query_config(&self) -> Result<state::Config> {
         get_data(&self.litesvm, &self.pda.registry_config())
     }
 
+    fn registry_query_fee_whitelist(&self) -> Result<state::FeeWhitelist> {
+        get_data(&self.litesvm, &self.pda.registry_fee_whitelist())
+    }
+
     fn registry_query_user_counter(&self) -> Result<state::UserCounter> {
         get_data(&self.litesvm, &self.pda.registry_user_counter())
     }
@@ -689,4 +2145,34 @@ impl RegistryExtension for App {
             &self.pda.registry_user_rotation_state(user_id.id),
         )
     }
+
+    fn registry_query_user_write_buffer(&self, user: AppUser) -> Result<state::WriteBuffer> {
+        let user_id = self.registry_query_user_id(user)?;
+        get_data(
+            &self.litesvm,
+            &self.pda.registry_user_write_buffer(user_id.id),
+        )
+    }
+
+    fn registry_query_user_index_page(&self, page: u32) -> Result<state::UserIndexPage> {
+        get_data(&self.litesvm, &self.pda.registry_user_index_page(page))
+    }
+
+    fn registry_query_promo_code(&self, code: &str) -> Result<state::PromoCode> {
+        get_data(&self.litesvm, &self.pda.registry_promo_code(code))
+    }
+
+    fn registry_query_user_delegate(
+        &self,
+        user: AppUser,
+        delegate: AppUser,
+    ) -> Result<state::UserDelegate> {
+        let user_id = self.registry_query_user_id(user)?;
+        get_data(
+            &self.litesvm,
+            &self
+                .pda
+                .registry_user_delegate(user_id.id, delegate.pubkey()),
+        )
+    }
 }
@@ -11,8 +11,10 @@ use {
         associated_token::AssociatedToken, memo, token::Mint, token_2022::spl_token_2022,
     },
     base::helpers::sort_mints,
+    base64::{prelude::BASE64_STANDARD, Engine},
     clmm_mock,
     litesvm::{types::TransactionMetadata, LiteSVM},
+    registry_cpi::client::pda as registry_pda,
     solana_compute_budget::compute_budget::ComputeBudget,
     solana_instruction::{AccountMeta, Instruction},
     solana_keypair::Keypair,
@@ -133,6 +135,7 @@ pub struct ProgramId {
 
     // 3rd party
     pub clmm_mock: Pubkey,
+    pub amm: Pubkey,
 
     // custom
     pub registry: Pubkey,
@@ -141,8 +144,8 @@ pub struct ProgramId {
 
 pub struct Pda {
     clmm_mock_program_id: Pubkey,
+    amm_program_id: Pubkey,
 
-    registry_program_id: Pubkey,
     dex_adapter_program_id: Pubkey,
 }
 
@@ -272,68 +275,81 @@ impl Pda {
         .0
     }
 
-    // registry
+    // amm
     //
-    pub fn registry_bump(&self) -> Pubkey {
+    pub fn amm_pool_config(&self, id: u64) -> Pubkey {
         get_pda_and_bump(
-            &seeds![registry_cpi::state::SEED_BUMP],
-            &self.registry_program_id,
+            &seeds![amm_cpi::state::SEED_CONFIG, id.to_le_bytes().as_ref()],
+            &self.amm_program_id,
         )
         .0
     }
 
-    pub fn registry_config(&self) -> Pubkey {
+    pub fn amm_pool_balance(&self, id: u64) -> Pubkey {
         get_pda_and_bump(
-            &seeds![registry_cpi::state::SEED_CONFIG],
-            &self.registry_program_id,
+            &seeds![amm_cpi::state::SEED_BALANCE, id.to_le_bytes().as_ref()],
+            &self.amm_program_id,
         )
         .0
     }
 
-    pub fn registry_user_counter(&self) -> Pubkey {
+    pub fn amm_mint_lp(&self, id: u64) -> Pubkey {
         get_pda_and_bump(
-            &seeds![registry_cpi::state::SEED_USER_COUNTER],
-            &self.registry_program_id,
+            &seeds![amm_cpi::state::SEED_LP, id.to_le_bytes().as_ref()],
+            &self.amm_program_id,
         )
         .0
     }
 
+    // registry
+    //
+    pub fn registry_bump(&self) -> Pubkey {
+        registry_pda::get_bump_address()
+    }
+
+    pub fn registry_config(&self) -> Pubkey {
+        registry_pda::get_config_address()
+    }
+
+    pub fn registry_user_counter(&self) -> Pubkey {
+        registry_pda::get_user_counter_address()
+    }
+
     pub fn registry_admin_rotation_state(&self) -> Pubkey {
-        get_pda_and_bump(
-            &seeds![registry_cpi::state::SEED_ADMIN_ROTATION_STATE],
-            &self.registry_program_id,
-        )
-        .0
+        registry_pda::get_admin_rotation_state_address()
+    }
+
+    pub fn registry_fee_whitelist(&self) -> Pubkey {
+        registry_pda::get_fee_whitelist_address()
+    }
+
+    /// address of too long code can't be derived, default one lets test the program guard
+    pub fn registry_promo_code(&self, code: &str) -> Pubkey {
+        registry_pda::get_promo_code_address(code).unwrap_or_default()
     }
 
     pub fn registry_user_id(&self, user: Pubkey) -> Pubkey {
-        get_pda_and_bump(
-            &seeds![registry_cpi::state::SEED_USER_ID, user],
-            &self.registry_program_id,
-        )
-        .0
+        registry_pda::get_user_id_address(&user)
     }
 
     pub fn registry_user_account(&self, user_id: u32) -> Pubkey {
-        get_pda_and_bump(
-            &seeds![
-                registry_cpi::state::SEED_USER_ACCOUNT,
-                user_id.to_le_bytes().as_ref()
-            ],
-            &self.registry_program_id,
-        )
-        .0
+        registry_pda::get_user_account_address(user_id)
     }
 
     pub fn registry_user_rotation_state(&self, user_id: u32) -> Pubkey {
-        get_pda_and_bump(
-            &seeds![
-                registry_cpi::state::SEED_USER_ROTATION_STATE,
-                user_id.to_le_bytes().as_ref()
-            ],
-            &self.registry_program_id,
-        )
-        .0
+        registry_pda::get_user_rotation_state_address(user_id)
+    }
+
+    pub fn registry_user_write_buffer(&self, user_id: u32) -> Pubkey {
+        registry_pda::get_user_write_buffer_address(user_id)
+    }
+
+    pub fn registry_user_delegate(&self, user_id: u32, delegate: Pubkey) -> Pubkey {
+        registry_pda::get_user_delegate_address(user_id, &delegate)
+    }
+
+    pub fn registry_user_index_page(&self, page: u32) -> Pubkey {
+        registry_pda::get_user_index_page_address(page)
     }
 
     // dex-adapter
@@ -395,6 +411,7 @@ impl App {
 
             // 3rd party
             clmm_mock: clmm_mock::ID,
+            amm: amm_cpi::ID,
 
             // custom
             registry: registry_cpi::ID,
@@ -404,13 +421,14 @@ impl App {
         // specify PDA
         let pda = Pda {
             clmm_mock_program_id: program_id.clmm_mock,
+            amm_program_id: program_id.amm,
 
-            registry_program_id: program_id.registry,
             dex_adapter_program_id: program_id.dex_adapter,
         };
 
         // upload 3rd party programs
         upload_program(&mut litesvm, "clmm_mock", &program_id.clmm_mock);
+        upload_program(&mut litesvm, "amm", &program_id.amm);
 
         // upload custom programs
         upload_program(&mut litesvm, "registry", &program_id.registry);
@@ -692,6 +710,24 @@ pub mod extension {
         }
     }
 
+    /// decodes anchor events of specified type from transaction logs
+    pub fn get_events<T>(tx: &TransactionMetadata) -> Vec<T>
+    where
+        T: anchor_lang::Event,
+    {
+        const LOG_PREFIX: &str = "Program data: ";
+
+        tx.logs
+            .iter()
+            .filter_map(|log| log.strip_prefix(LOG_PREFIX))
+            .filter_map(|x| BASE64_STANDARD.decode(x).ok())
+            .filter_map(|data| {
+                data.strip_prefix(T::DISCRIMINATOR)
+                    .and_then(|mut x| T::deserialize(&mut x).ok())
+            })
+            .collect()
+    }
+
     pub fn get_data_zero_copy<T>(litesvm: &LiteSVM, pda: &Pubkey) -> Result<T>
     where
         T: anchor_lang::Owner + anchor_lang::ZeroCopy,
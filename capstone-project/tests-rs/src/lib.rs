@@ -9,6 +9,7 @@ pub mod wsol;
 
 pub mod helpers {
     pub mod extensions {
+        pub mod amm;
         pub mod clmm_mock;
         pub mod dex_adapter;
         pub mod registry;
@@ -2,21 +2,40 @@ use {
     crate::helpers::{
         extensions::registry::RegistryExtension,
         suite::{
-            core::{assert_error, App},
+            core::{assert_error, extension::get_events, App},
             types::{AppToken, AppUser},
         },
     },
-    anchor_lang::Result,
-    base::error::AuthError,
+    anchor_lang::{
+        system_program, AccountSerialize, InstructionData, Result, Space, ToAccountMetas,
+    },
+    anchor_spl::associated_token,
+    base::{
+        error::AuthError,
+        helpers::DISCRIMINATOR_SPACE,
+        migration::Versioned,
+        pause::{PAUSE_ALL, PAUSE_CREATE, PAUSE_NONE, PAUSE_WRITE},
+    },
     pretty_assertions::assert_eq,
     registry_cpi::{
+        client::{decode, instruction, pda},
         error::CustomError,
+        events::{
+            AccountActivated, AccountCreated, AccountForceClosed, AccountMigrated,
+            AccountRotationCancelled, AccountRotationConfirmed, AccountSuspended,
+            AdminRotationCancelled, ConfigUpdated, DataWritten,
+        },
+        helpers::{decode_user_index_page, get_user_index_page_address},
         state::{
-            Config, UserAccount, ACCOUNT_DATA_SIZE_MAX, ACCOUNT_DATA_SIZE_MIN,
-            ACCOUNT_REGISTRATION_FEE_AMOUNT, CLOCK_TIME_MIN, ROTATION_TIMEOUT,
+            Config, FeeWhitelist, RotationState, UserAccount, UserDelegate, UserId, UserIndexPage,
+            ACCOUNT_DATA_SIZE_MAX, ACCOUNT_DATA_SIZE_MIN, ACCOUNT_REGISTRATION_FEE_AMOUNT,
+            ACTIVATION_PERIOD, BPS_MAX, CLOCK_TIME_MIN, FEE_WHITELIST_MAX_LEN, NATIVE_SOL_ASSET,
+            PROMO_CODE_MAX_LEN, ROTATION_TIMEOUT, USER_INDEX_PAGE_SIZE,
         },
-        types::{AssetItem, Range},
+        types::{AssetItem, DataHeader, Range, UpdateConfigArgs},
     },
+    solana_instruction::{AccountMeta, Instruction},
+    solana_pubkey::Pubkey,
 };
 
 fn init_app() -> Result<App> {
@@ -30,6 +49,7 @@ fn init_app() -> Result<App> {
             asset: AppToken::USDC.pubkey(),
         }),
         None,
+        None,
     )?;
 
     Ok(app)
@@ -43,7 +63,7 @@ fn init_default() -> Result<()> {
         app.registry_query_config()?,
         Config {
             admin: AppUser::Admin.pubkey(),
-            is_paused: false,
+            paused_ops: PAUSE_NONE,
             rotation_timeout: ROTATION_TIMEOUT,
             registration_fee: AssetItem {
                 amount: ACCOUNT_REGISTRATION_FEE_AMOUNT,
@@ -52,7 +72,9 @@ fn init_default() -> Result<()> {
             data_size_range: Range {
                 min: ACCOUNT_DATA_SIZE_MIN,
                 max: ACCOUNT_DATA_SIZE_MAX,
-            }
+            },
+            activation_period: ACTIVATION_PERIOD,
+            version: Config::VERSION,
         }
     );
 
@@ -74,6 +96,7 @@ fn init_admin_guard() -> Result<()> {
                 asset: AppToken::USDC.pubkey(),
             }),
             None,
+            None,
         )
         .unwrap_err();
     assert_error(res, AuthError::Unauthorized);
@@ -87,7 +110,13 @@ fn transfer_admin() -> Result<()> {
 
     // only admin can rotate admin
     let res = app
-        .registry_try_update_config(AppUser::Alice, Some(AppUser::Alice), None, None, None, None)
+        .registry_try_update_config(
+            AppUser::Alice,
+            UpdateConfigArgs {
+                admin: Some(AppUser::Alice.pubkey()),
+                ..Default::default()
+            },
+        )
         .unwrap_err();
     assert_error(res, AuthError::Unauthorized);
 
@@ -99,12 +128,24 @@ fn transfer_admin() -> Result<()> {
 
     // the admin can't be new admin
     let res = app
-        .registry_try_update_config(AppUser::Admin, Some(AppUser::Admin), None, None, None, None)
+        .registry_try_update_config(
+            AppUser::Admin,
+            UpdateConfigArgs {
+                admin: Some(AppUser::Admin.pubkey()),
+                ..Default::default()
+            },
+        )
         .unwrap_err();
     assert_error(res, AuthError::UselessRotation);
 
     // too late to confirm admin rotation
-    app.registry_try_update_config(AppUser::Admin, Some(AppUser::Alice), None, None, None, None)?;
+    app.registry_try_update_config(
+        AppUser::Admin,
+        UpdateConfigArgs {
+            admin: Some(AppUser::Alice.pubkey()),
+            ..Default::default()
+        },
+    )?;
     app.wait(ROTATION_TIMEOUT as u64);
     let res = app
         .registry_try_confirm_admin_rotation(AppUser::Alice)
@@ -112,7 +153,13 @@ fn transfer_admin() -> Result<()> {
     assert_error(res, AuthError::TransferOwnerDeadline);
 
     // only new admin can confirm admin rotation
-    app.registry_try_update_config(AppUser::Admin, Some(AppUser::Alice), None, None, None, None)?;
+    app.registry_try_update_config(
+        AppUser::Admin,
+        UpdateConfigArgs {
+            admin: Some(AppUser::Alice.pubkey()),
+            ..Default::default()
+        },
+    )?;
     let res = app
         .registry_try_confirm_admin_rotation(AppUser::Bob)
         .unwrap_err();
@@ -131,6 +178,46 @@ fn transfer_admin() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn cancel_admin_rotation() -> Result<()> {
+    let mut app = init_app()?;
+
+    // nothing to cancel
+    let res = app
+        .registry_try_cancel_admin_rotation(AppUser::Admin)
+        .unwrap_err();
+    assert_error(res, AuthError::NoRotationToCancel);
+
+    app.registry_try_update_config(
+        AppUser::Admin,
+        UpdateConfigArgs {
+            admin: Some(AppUser::Alice.pubkey()),
+            ..Default::default()
+        },
+    )?;
+
+    // only admin can cancel admin rotation
+    let res = app
+        .registry_try_cancel_admin_rotation(AppUser::Alice)
+        .unwrap_err();
+    assert_error(res, AuthError::Unauthorized);
+
+    let tx = app.registry_try_cancel_admin_rotation(AppUser::Admin)?;
+    let [ref event] = get_events::<AdminRotationCancelled>(&tx)[..] else {
+        panic!("AdminRotationCancelled event isn't found!");
+    };
+    assert_eq!(event.new_admin, AppUser::Alice.pubkey());
+    assert_eq!(app.registry_query_admin_rotation_state()?.new_owner, None);
+
+    // cancelled rotation can't be confirmed
+    let res = app
+        .registry_try_confirm_admin_rotation(AppUser::Alice)
+        .unwrap_err();
+    assert_error(res, AuthError::NoNewOwner);
+
+    Ok(())
+}
+
 #[test]
 fn create_account_guards() -> Result<()> {
     const MAX_DATA_SIZE: u32 = 1_000;
@@ -154,11 +241,23 @@ fn create_account_guards() -> Result<()> {
     assert_error(res, CustomError::MaxDataSizeIsOutOfRange);
 
     // user can't create account when program is paused
-    app.registry_try_update_config(AppUser::Admin, None, Some(true), None, None, None)?;
+    app.registry_try_update_config(
+        AppUser::Admin,
+        UpdateConfigArgs {
+            paused_ops: Some(PAUSE_CREATE),
+            ..Default::default()
+        },
+    )?;
     let res = app
         .registry_try_create_account(AppUser::Alice, MAX_DATA_SIZE, None)
         .unwrap_err();
-    app.registry_try_update_config(AppUser::Admin, None, Some(false), None, None, None)?;
+    app.registry_try_update_config(
+        AppUser::Admin,
+        UpdateConfigArgs {
+            paused_ops: Some(PAUSE_NONE),
+            ..Default::default()
+        },
+    )?;
     assert_error(res, CustomError::ContractIsPaused);
 
     // user can't create account twice
@@ -200,14 +299,15 @@ fn create_and_activate_account_default() -> Result<()> {
     assert_eq!(
         app.registry_query_user_account(AppUser::Alice)?,
         UserAccount {
-            data: String::default(),
+            data: Vec::default(),
             nonce: 0,
-            max_size: MAX_DATA_SIZE
+            max_size: MAX_DATA_SIZE,
+            header: DataHeader::default()
         }
     );
 
     let alice_usdc_before = app.get_balance(AppUser::Alice, AppToken::USDC);
-    app.registry_try_activate_account(AppUser::Alice, None, None)?;
+    app.registry_try_activate_account(AppUser::Alice, None, None, None)?;
 
     let alice_usdc_after = app.get_balance(AppUser::Alice, AppToken::USDC);
     assert_eq!(
@@ -230,14 +330,14 @@ fn activate_account_guards() -> Result<()> {
     let mut app = init_app()?;
 
     // user can't activate nonexistent account
-    app.registry_try_activate_account(AppUser::Alice, None, None)
+    app.registry_try_activate_account(AppUser::Alice, None, None, None)
         .unwrap_err();
 
     // user can't activate closed account
     app.registry_try_create_account(AppUser::Alice, MAX_DATA_SIZE, None)?;
     app.registry_try_close_account(AppUser::Alice, None)?;
     let res = app
-        .registry_try_activate_account(AppUser::Alice, None, None)
+        .registry_try_activate_account(AppUser::Alice, None, None, None)
         .unwrap_err();
     assert_error(res, CustomError::AccountIsNotOpened);
 
@@ -249,14 +349,14 @@ fn activate_account_guards() -> Result<()> {
         &AppToken::PYTH.pubkey(),
     )?;
     let res = app
-        .registry_try_activate_account(AppUser::Alice, None, Some(AppToken::PYTH))
+        .registry_try_activate_account(AppUser::Alice, None, Some(AppToken::PYTH), None)
         .unwrap_err();
     assert_error(res, CustomError::WrongAssetType);
 
     // user can't activate account twice
-    app.registry_try_activate_account(AppUser::Alice, None, None)?;
+    app.registry_try_activate_account(AppUser::Alice, None, None, None)?;
     let res = app
-        .registry_try_activate_account(AppUser::Alice, None, None)
+        .registry_try_activate_account(AppUser::Alice, None, None, None)
         .unwrap_err();
     assert_error(res, CustomError::ActivateAccountTwice);
 
@@ -270,7 +370,7 @@ fn activate_account_for_other_user() -> Result<()> {
     let mut app = init_app()?;
 
     app.registry_try_create_account(AppUser::Alice, MAX_DATA_SIZE, None)?;
-    app.registry_try_activate_account(AppUser::Bob, Some(AppUser::Alice), None)?;
+    app.registry_try_activate_account(AppUser::Bob, Some(AppUser::Alice), None, None)?;
 
     assert_eq!(
         app.registry_query_user_account(AppUser::Alice)?.max_size,
@@ -281,237 +381,1440 @@ fn activate_account_for_other_user() -> Result<()> {
 }
 
 #[test]
-fn withdraw_revenue_default() -> Result<()> {
+fn activate_account_with_whitelisted_assets() -> Result<()> {
     const MAX_DATA_SIZE: u32 = 1_000;
+    const FEE_AMOUNT_PYTH: u64 = 100_000_000;
+    const FEE_AMOUNT_SOL: u64 = 100_000_000;
 
     let mut app = init_app()?;
 
-    app.registry_try_create_account(AppUser::Alice, MAX_DATA_SIZE, None)?;
-    app.registry_try_activate_account(AppUser::Alice, None, None)?;
-
-    let admin_usdc_before = app.get_balance(AppUser::Admin, AppToken::USDC);
+    app.registry_try_update_config(
+        AppUser::Admin,
+        UpdateConfigArgs {
+            fee_whitelist: Some(vec![
+                AssetItem {
+                    amount: FEE_AMOUNT_PYTH,
+                    asset: AppToken::PYTH.pubkey(),
+                },
+                AssetItem {
+                    amount: FEE_AMOUNT_SOL,
+                    asset: NATIVE_SOL_ASSET,
+                },
+            ]),
+            ..Default::default()
+        },
+    )?;
 
-    app.registry_try_withdraw_revenue(AppUser::Admin, None, None, None)?;
+    // pay with whitelisted token
+    app.registry_try_create_account(AppUser::Alice, MAX_DATA_SIZE, None)?;
+    let alice_pyth_before = app.get_balance(AppUser::Alice, AppToken::PYTH);
+    app.registry_try_activate_account(AppUser::Alice, None, Some(AppToken::PYTH), None)?;
+    let alice_pyth_after = app.get_balance(AppUser::Alice, AppToken::PYTH);
 
-    let admin_usdc_after = app.get_balance(AppUser::Admin, AppToken::USDC);
+    assert_eq!(alice_pyth_before - alice_pyth_after, FEE_AMOUNT_PYTH);
     assert_eq!(
-        admin_usdc_after - admin_usdc_before,
-        ACCOUNT_REGISTRATION_FEE_AMOUNT
+        app.registry_query_user_id(AppUser::Alice)?.is_activated,
+        true
     );
 
+    // pay with SOL
+    app.registry_try_create_account(AppUser::Bob, MAX_DATA_SIZE, None)?;
+    let config_sol_before = app.get_coin_balance(&app.pda.registry_config());
+    app.registry_try_activate_account_with_sol(AppUser::Bob, None, None)?;
+    let config_sol_after = app.get_coin_balance(&app.pda.registry_config());
+
+    assert_eq!(config_sol_after - config_sol_before, FEE_AMOUNT_SOL);
+    assert_eq!(app.registry_query_user_id(AppUser::Bob)?.is_activated, true);
+
+    // whitelisted revenue can be withdrawn
+    let admin_pyth_before = app.get_balance(AppUser::Admin, AppToken::PYTH);
+    app.registry_try_withdraw_revenue(AppUser::Admin, None, None, Some(AppToken::PYTH))?;
+    let admin_pyth_after = app.get_balance(AppUser::Admin, AppToken::PYTH);
+
+    assert_eq!(admin_pyth_after - admin_pyth_before, FEE_AMOUNT_PYTH);
+
     Ok(())
 }
 
 #[test]
-fn withdraw_revenue_by_amount_to_recipient() -> Result<()> {
+fn activate_account_with_promo_code() -> Result<()> {
     const MAX_DATA_SIZE: u32 = 1_000;
+    const CODE: &str = "summer_campaign";
+    const DISCOUNT_BPS: u16 = 2_000;
+    const REFERRER_SHARE_BPS: u16 = 1_000;
+    const FEE_AMOUNT_SOL: u64 = 100_000_000;
 
     let mut app = init_app()?;
 
-    app.registry_try_create_account(AppUser::Alice, MAX_DATA_SIZE, None)?;
-    app.registry_try_activate_account(AppUser::Alice, None, None)?;
-
-    let revenue = app.get_ata_token_balance(&app.pda.registry_config(), &AppToken::USDC.pubkey());
-    assert_eq!(revenue, ACCOUNT_REGISTRATION_FEE_AMOUNT);
-
-    let bob_usdc_before = app.get_balance(AppUser::Bob, AppToken::USDC);
-    app.registry_try_withdraw_revenue(
+    app.registry_try_update_config(
         AppUser::Admin,
-        Some(revenue / 2),
+        UpdateConfigArgs {
+            fee_whitelist: Some(vec![AssetItem {
+                amount: FEE_AMOUNT_SOL,
+                asset: NATIVE_SOL_ASSET,
+            }]),
+            ..Default::default()
+        },
+    )?;
+    app.registry_try_create_promo_code(
+        AppUser::Admin,
+        CODE,
+        DISCOUNT_BPS,
         Some(AppUser::Bob),
-        Some(AppToken::USDC),
+        REFERRER_SHARE_BPS,
+        None,
+        None,
     )?;
 
+    // 20 % discount, 10 % of discounted fee is paid to referrer
+    let discounted_fee = ACCOUNT_REGISTRATION_FEE_AMOUNT * 8 / 10;
+    let referrer_amount = discounted_fee / 10;
+
+    app.registry_try_create_account(AppUser::Alice, MAX_DATA_SIZE, None)?;
+    let alice_usdc_before = app.get_balance(AppUser::Alice, AppToken::USDC);
+    let bob_usdc_before = app.get_balance(AppUser::Bob, AppToken::USDC);
+    app.registry_try_activate_account(AppUser::Alice, None, None, Some(CODE))?;
+    let alice_usdc_after = app.get_balance(AppUser::Alice, AppToken::USDC);
     let bob_usdc_after = app.get_balance(AppUser::Bob, AppToken::USDC);
+
+    assert_eq!(alice_usdc_before - alice_usdc_after, discounted_fee);
+    assert_eq!(bob_usdc_after - bob_usdc_before, referrer_amount);
     assert_eq!(
-        bob_usdc_after - bob_usdc_before,
-        ACCOUNT_REGISTRATION_FEE_AMOUNT / 2
+        app.get_ata_token_balance(&app.pda.registry_config(), &AppToken::USDC.pubkey()),
+        discounted_fee - referrer_amount
+    );
+
+    // promo code can be applied to SOL payments
+    let discounted_fee = FEE_AMOUNT_SOL * 8 / 10;
+    let referrer_amount = discounted_fee / 10;
+
+    app.registry_try_create_account(AppUser::Admin, MAX_DATA_SIZE, None)?;
+    let config_sol_before = app.get_coin_balance(&app.pda.registry_config());
+    let bob_sol_before = app.get_coin_balance(&AppUser::Bob.pubkey());
+    app.registry_try_activate_account_with_sol(AppUser::Admin, None, Some(CODE))?;
+    let config_sol_after = app.get_coin_balance(&app.pda.registry_config());
+    let bob_sol_after = app.get_coin_balance(&AppUser::Bob.pubkey());
+
+    assert_eq!(
+        config_sol_after - config_sol_before,
+        discounted_fee - referrer_amount
     );
+    assert_eq!(bob_sol_after - bob_sol_before, referrer_amount);
+    assert_eq!(app.registry_query_promo_code(CODE)?.usage_count, 2);
 
     Ok(())
 }
 
 #[test]
-fn withdraw_revenue_guards() -> Result<()> {
+fn promo_code_guards() -> Result<()> {
     const MAX_DATA_SIZE: u32 = 1_000;
+    const CODE: &str = "limited";
+    const VALIDITY_PERIOD: u64 = 3_600;
 
     let mut app = init_app()?;
 
-    app.registry_try_create_account(AppUser::Alice, MAX_DATA_SIZE, None)?;
-    app.registry_try_activate_account(AppUser::Alice, None, None)?;
-
-    // only admin can withdraw
+    // only admin can create promo code
     let res = app
-        .registry_try_withdraw_revenue(AppUser::Alice, None, None, None)
+        .registry_try_create_promo_code(AppUser::Alice, CODE, 1_000, None, 0, None, None)
         .unwrap_err();
     assert_error(res, AuthError::Unauthorized);
 
-    // lower amount limit
     let res = app
-        .registry_try_withdraw_revenue(AppUser::Admin, Some(0), None, None)
+        .registry_try_create_promo_code(AppUser::Admin, CODE, BPS_MAX + 1, None, 0, None, None)
         .unwrap_err();
-    assert_error(res, CustomError::ZeroAmount);
+    assert_error(res, CustomError::BpsIsOutOfRange);
 
-    // higher amount limit
     let res = app
-        .registry_try_withdraw_revenue(
-            AppUser::Admin,
-            Some(ACCOUNT_REGISTRATION_FEE_AMOUNT + 1),
-            None,
-            None,
-        )
+        .registry_try_create_promo_code(AppUser::Admin, CODE, 1_000, None, 1_000, None, None)
         .unwrap_err();
-    assert_error(res, CustomError::ExceededAvailableAssetAmount);
+    assert_error(res, CustomError::NoReferrer);
+
+    // code length is limited by PDA seed length
+    for code in ["", &"x".repeat(PROMO_CODE_MAX_LEN + 1)] {
+        let res = app
+            .registry_try_create_promo_code(AppUser::Admin, code, 1_000, None, 0, None, None)
+            .unwrap_err();
+        assert_error(res, CustomError::InvalidPromoCodeLength);
+    }
 
-    app.get_or_create_ata(
+    // promo code can't be used more than specified
+    let expiration_date = app.get_clock_time() + VALIDITY_PERIOD;
+    app.registry_try_create_promo_code(
         AppUser::Admin,
-        &app.pda.registry_config(),
-        &AppToken::PYTH.pubkey(),
+        CODE,
+        1_000,
+        None,
+        0,
+        Some(1),
+        Some(expiration_date),
     )?;
+    app.registry_try_create_account(AppUser::Alice, MAX_DATA_SIZE, None)?;
+    app.registry_try_activate_account(AppUser::Alice, None, None, Some(CODE))?;
+
+    app.registry_try_create_account(AppUser::Bob, MAX_DATA_SIZE, None)?;
     let res = app
-        .registry_try_withdraw_revenue(AppUser::Admin, None, None, Some(AppToken::PYTH))
+        .registry_try_activate_account(AppUser::Bob, None, None, Some(CODE))
         .unwrap_err();
-    assert_error(res, CustomError::WrongAssetType);
+    assert_error(res, CustomError::PromoCodeUsageLimitIsReached);
+
+    // expired promo code can't be used
+    app.registry_try_close_promo_code(AppUser::Admin, CODE)?;
+    app.registry_try_create_promo_code(
+        AppUser::Admin,
+        CODE,
+        1_000,
+        None,
+        0,
+        None,
+        Some(expiration_date),
+    )?;
+    app.wait(VALIDITY_PERIOD);
+    let res = app
+        .registry_try_activate_account(AppUser::Bob, None, None, Some(CODE))
+        .unwrap_err();
+    assert_error(res, CustomError::PromoCodeIsExpired);
+
+    // only admin can close promo code
+    let res = app
+        .registry_try_close_promo_code(AppUser::Alice, CODE)
+        .unwrap_err();
+    assert_error(res, AuthError::Unauthorized);
 
     Ok(())
 }
 
 #[test]
-fn close_account_guards() -> Result<()> {
-    const MAX_DATA_SIZE_0: u32 = 1_000;
+fn fee_whitelist_guards() -> Result<()> {
+    const MAX_DATA_SIZE: u32 = 1_000;
 
     let mut app = init_app()?;
 
-    // account must be created first
-    app.registry_try_close_account(AppUser::Alice, None)
+    // SOL isn't accepted by default
+    app.registry_try_create_account(AppUser::Alice, MAX_DATA_SIZE, None)?;
+    let res = app
+        .registry_try_activate_account_with_sol(AppUser::Alice, None, None)
         .unwrap_err();
+    assert_error(res, CustomError::WrongAssetType);
 
-    // close account of other user
-    app.registry_try_create_account(AppUser::Alice, MAX_DATA_SIZE_0, None)?;
-    app.registry_try_close_account(AppUser::Bob, Some(AppUser::Alice))
+    // only admin can update the whitelist
+    let res = app
+        .registry_try_update_config(
+            AppUser::Alice,
+            UpdateConfigArgs {
+                fee_whitelist: Some(vec![]),
+                ..Default::default()
+            },
+        )
         .unwrap_err();
+    assert_error(res, AuthError::Unauthorized);
 
-    // close account twice
-    app.registry_try_close_account(AppUser::Alice, None)?;
-    app.registry_try_close_account(AppUser::Alice, None)
+    // registration fee asset can't be whitelisted
+    let res = app
+        .registry_try_update_config(
+            AppUser::Admin,
+            UpdateConfigArgs {
+                fee_whitelist: Some(vec![AssetItem {
+                    amount: 1,
+                    asset: AppToken::USDC.pubkey(),
+                }]),
+                ..Default::default()
+            },
+        )
+        .unwrap_err();
+    assert_error(res, CustomError::FeeAssetDuplication);
+
+    // assets can't be duplicated
+    let res = app
+        .registry_try_update_config(
+            AppUser::Admin,
+            UpdateConfigArgs {
+                fee_whitelist: Some(vec![
+                    AssetItem {
+                        amount: 1,
+                        asset: AppToken::PYTH.pubkey(),
+                    },
+                    AssetItem {
+                        amount: 2,
+                        asset: AppToken::PYTH.pubkey(),
+                    },
+                ]),
+                ..Default::default()
+            },
+        )
+        .unwrap_err();
+    assert_error(res, CustomError::FeeAssetDuplication);
+
+    // whitelist length is limited
+    let res = app
+        .registry_try_update_config(
+            AppUser::Admin,
+            UpdateConfigArgs {
+                fee_whitelist: Some(
+                    (0..=FEE_WHITELIST_MAX_LEN)
+                        .map(|_| AssetItem {
+                            amount: 1,
+                            asset: Pubkey::new_unique(),
+                        })
+                        .collect(),
+                ),
+                ..Default::default()
+            },
+        )
         .unwrap_err();
+    assert_error(res, CustomError::FeeWhitelistIsTooLong);
 
     Ok(())
 }
 
 #[test]
-fn reopen_account_default() -> Result<()> {
-    const MAX_DATA_SIZE_0: u32 = 1_000;
-    const MAX_DATA_SIZE_1: u32 = 1_000;
+fn renew_activation_default() -> Result<()> {
+    const MAX_DATA_SIZE: u32 = 1_000;
+    const DATA: &str = "encrypted_secrets";
 
     let mut app = init_app()?;
 
-    app.registry_try_create_account(AppUser::Alice, MAX_DATA_SIZE_0, None)?;
-    app.registry_try_activate_account(AppUser::Alice, None, None)?;
-    app.registry_try_close_account(AppUser::Alice, None)?;
+    app.registry_try_create_account(AppUser::Alice, MAX_DATA_SIZE, None)?;
+    app.registry_try_activate_account(AppUser::Alice, None, None, None)?;
 
-    let user_id = app.registry_query_user_id(AppUser::Alice)?;
-    assert_eq!(user_id.id, 1);
-    assert_eq!(user_id.is_open, false);
-    assert_eq!(user_id.is_activated, true);
+    let activated_until = app.registry_query_user_id(AppUser::Alice)?.activated_until;
+    assert_eq!(
+        activated_until,
+        app.get_clock_time() + ACTIVATION_PERIOD as u64
+    );
 
-    app.registry_try_reopen_account(AppUser::Alice, MAX_DATA_SIZE_1)?;
+    // renewal before expiration extends current period
+    let alice_usdc_before = app.get_balance(AppUser::Alice, AppToken::USDC);
+    app.registry_try_renew_activation(AppUser::Alice, None, None)?;
 
-    let user_id = app.registry_query_user_id(AppUser::Alice)?;
-    assert_eq!(user_id.id, 1);
-    assert_eq!(user_id.is_open, true);
-    assert_eq!(user_id.is_activated, true);
+    let alice_usdc_after = app.get_balance(AppUser::Alice, AppToken::USDC);
+    assert_eq!(
+        alice_usdc_before - alice_usdc_after,
+        ACCOUNT_REGISTRATION_FEE_AMOUNT
+    );
+    assert_eq!(
+        app.registry_query_user_id(AppUser::Alice)?.activated_until,
+        activated_until + ACTIVATION_PERIOD as u64
+    );
+
+    // user can't write data when activation is expired
+    app.wait(2 * ACTIVATION_PERIOD as u64);
+    let res = app
+        .registry_try_write_data(AppUser::Alice, None, DATA, 1, None, None)
+        .unwrap_err();
+    assert_error(res, CustomError::ActivationIsExpired);
+
+    // SOL isn't whitelisted by default
+    let res = app
+        .registry_try_renew_activation_with_sol(AppUser::Bob, Some(AppUser::Alice))
+        .unwrap_err();
+    assert_error(res, CustomError::WrongAssetType);
 
+    // renewal after expiration starts new period from current time
+    app.registry_try_renew_activation(AppUser::Bob, Some(AppUser::Alice), None)?;
     assert_eq!(
-        app.registry_query_user_account(AppUser::Alice)?.max_size,
-        MAX_DATA_SIZE_1
+        app.registry_query_user_id(AppUser::Alice)?.activated_until,
+        app.get_clock_time() + ACTIVATION_PERIOD as u64
     );
+    app.registry_try_write_data(AppUser::Alice, None, DATA, 1, None, None)?;
 
     Ok(())
 }
 
 #[test]
-fn reopen_account_guards() -> Result<()> {
-    const MAX_DATA_SIZE_0: u32 = 1_000;
+fn renew_activation_guards() -> Result<()> {
+    const MAX_DATA_SIZE: u32 = 1_000;
 
     let mut app = init_app()?;
 
-    // account must be created first
-    app.registry_try_reopen_account(AppUser::Alice, MAX_DATA_SIZE_0)
+    // user can't renew not activated account
+    app.registry_try_create_account(AppUser::Alice, MAX_DATA_SIZE, None)?;
+    let res = app
+        .registry_try_renew_activation(AppUser::Alice, None, None)
         .unwrap_err();
+    assert_error(res, CustomError::AccountIsNotActivated);
 
-    // account can't be open twice
-    app.registry_try_create_account(AppUser::Alice, MAX_DATA_SIZE_0, None)?;
-    app.registry_try_reopen_account(AppUser::Alice, MAX_DATA_SIZE_0)
+    // user can't renew closed account
+    app.registry_try_activate_account(AppUser::Alice, None, None, None)?;
+    app.registry_try_close_account(AppUser::Alice, None)?;
+    let res = app
+        .registry_try_renew_activation(AppUser::Alice, None, None)
         .unwrap_err();
+    assert_error(res, CustomError::AccountIsNotOpened);
 
     Ok(())
 }
 
 #[test]
-fn write_data_default() -> Result<()> {
+fn withdraw_revenue_default() -> Result<()> {
     const MAX_DATA_SIZE: u32 = 1_000;
-    const DATA_0: &str = "encrypted_secrets_0";
-    const DATA_1: &str = "encrypted_secrets_1";
-    const NONCE_0: u64 = 1;
-    const NONCE_1: u64 = 2;
 
     let mut app = init_app()?;
 
     app.registry_try_create_account(AppUser::Alice, MAX_DATA_SIZE, None)?;
-    app.registry_try_activate_account(AppUser::Alice, None, None)?;
+    app.registry_try_activate_account(AppUser::Alice, None, None, None)?;
 
-    for (data, nonce) in [(DATA_0, NONCE_0), (DATA_1, NONCE_1)] {
-        app.registry_try_write_data(AppUser::Alice, data, nonce)?;
+    let admin_usdc_before = app.get_balance(AppUser::Admin, AppToken::USDC);
 
-        assert_eq!(
-            app.registry_query_user_account(AppUser::Alice)?,
-            UserAccount {
-                data: data.to_string(),
-                nonce,
-                max_size: MAX_DATA_SIZE
-            }
-        );
-    }
+    app.registry_try_withdraw_revenue(AppUser::Admin, None, None, None)?;
+
+    let admin_usdc_after = app.get_balance(AppUser::Admin, AppToken::USDC);
+    assert_eq!(
+        admin_usdc_after - admin_usdc_before,
+        ACCOUNT_REGISTRATION_FEE_AMOUNT
+    );
 
     Ok(())
 }
 
 #[test]
-fn write_data_multiple_users() -> Result<()> {
+fn withdraw_revenue_by_amount_to_recipient() -> Result<()> {
     const MAX_DATA_SIZE: u32 = 1_000;
-    const DATA_0: &str = "encrypted_secrets_0";
-    const DATA_1: &str = "encrypted_secrets_1";
-    const NONCE_0: u64 = 1;
-    const NONCE_1: u64 = 2;
 
     let mut app = init_app()?;
 
-    for user in [AppUser::Alice, AppUser::Bob] {
-        app.registry_try_create_account(user, MAX_DATA_SIZE, None)?;
-        app.registry_try_activate_account(user, None, None)?;
-    }
+    app.registry_try_create_account(AppUser::Alice, MAX_DATA_SIZE, None)?;
+    app.registry_try_activate_account(AppUser::Alice, None, None, None)?;
+
+    let revenue = app.get_ata_token_balance(&app.pda.registry_config(), &AppToken::USDC.pubkey());
+    assert_eq!(revenue, ACCOUNT_REGISTRATION_FEE_AMOUNT);
 
-    app.registry_try_write_data(AppUser::Alice, DATA_0, NONCE_0)?;
-    app.registry_try_write_data(AppUser::Bob, DATA_1, NONCE_1)?;
+    let bob_usdc_before = app.get_balance(AppUser::Bob, AppToken::USDC);
+    app.registry_try_withdraw_revenue(
+        AppUser::Admin,
+        Some(revenue / 2),
+        Some(AppUser::Bob),
+        Some(AppToken::USDC),
+    )?;
 
+    let bob_usdc_after = app.get_balance(AppUser::Bob, AppToken::USDC);
     assert_eq!(
-        app.registry_query_user_account(AppUser::Alice)?,
-        UserAccount {
-            data: DATA_0.to_string(),
-            nonce: NONCE_0,
-            max_size: MAX_DATA_SIZE
+        bob_usdc_after - bob_usdc_before,
+        ACCOUNT_REGISTRATION_FEE_AMOUNT / 2
+    );
+
+    Ok(())
+}
+
+#[test]
+fn withdraw_revenue_guards() -> Result<()> {
+    const MAX_DATA_SIZE: u32 = 1_000;
+
+    let mut app = init_app()?;
+
+    app.registry_try_create_account(AppUser::Alice, MAX_DATA_SIZE, None)?;
+    app.registry_try_activate_account(AppUser::Alice, None, None, None)?;
+
+    // only admin can withdraw
+    let res = app
+        .registry_try_withdraw_revenue(AppUser::Alice, None, None, None)
+        .unwrap_err();
+    assert_error(res, AuthError::Unauthorized);
+
+    // lower amount limit
+    let res = app
+        .registry_try_withdraw_revenue(AppUser::Admin, Some(0), None, None)
+        .unwrap_err();
+    assert_error(res, CustomError::ZeroAmount);
+
+    // higher amount limit
+    let res = app
+        .registry_try_withdraw_revenue(
+            AppUser::Admin,
+            Some(ACCOUNT_REGISTRATION_FEE_AMOUNT + 1),
+            None,
+            None,
+        )
+        .unwrap_err();
+    assert_error(res, CustomError::ExceededAvailableAssetAmount);
+
+    // nothing to withdraw
+    app.get_or_create_ata(
+        AppUser::Admin,
+        &app.pda.registry_config(),
+        &AppToken::PYTH.pubkey(),
+    )?;
+    let res = app
+        .registry_try_withdraw_revenue(AppUser::Admin, None, None, Some(AppToken::PYTH))
+        .unwrap_err();
+    assert_error(res, CustomError::ZeroAmount);
+
+    // only admin can withdraw SOL
+    let res = app
+        .registry_try_withdraw_revenue_with_sol(AppUser::Alice, None, None)
+        .unwrap_err();
+    assert_error(res, AuthError::Unauthorized);
+
+    // rent exempt can't be withdrawn
+    let res = app
+        .registry_try_withdraw_revenue_with_sol(AppUser::Admin, None, None)
+        .unwrap_err();
+    assert_error(res, CustomError::ZeroAmount);
+
+    let res = app
+        .registry_try_withdraw_revenue_with_sol(AppUser::Admin, Some(1), None)
+        .unwrap_err();
+    assert_error(res, CustomError::ExceededAvailableAssetAmount);
+
+    Ok(())
+}
+
+#[test]
+fn withdraw_revenue_in_previous_fee_asset_and_sol() -> Result<()> {
+    const MAX_DATA_SIZE: u32 = 1_000;
+    const FEE_AMOUNT_SOL: u64 = 100_000_000;
+
+    let mut app = init_app()?;
+
+    app.registry_try_update_config(
+        AppUser::Admin,
+        UpdateConfigArgs {
+            fee_whitelist: Some(vec![AssetItem {
+                amount: FEE_AMOUNT_SOL,
+                asset: NATIVE_SOL_ASSET,
+            }]),
+            ..Default::default()
+        },
+    )?;
+
+    app.registry_try_create_account(AppUser::Alice, MAX_DATA_SIZE, None)?;
+    app.registry_try_activate_account(AppUser::Alice, None, None, None)?;
+    app.registry_try_create_account(AppUser::Bob, MAX_DATA_SIZE, None)?;
+    app.registry_try_activate_account_with_sol(AppUser::Bob, None, None)?;
+
+    // USDC isn't accepted anymore but its revenue can still be withdrawn
+    app.registry_try_update_config(
+        AppUser::Admin,
+        UpdateConfigArgs {
+            fee_whitelist: Some(vec![]),
+            ..Default::default()
+        },
+    )?;
+    let bob_usdc_before = app.get_balance(AppUser::Bob, AppToken::USDC);
+    app.registry_try_withdraw_revenue(AppUser::Admin, None, Some(AppUser::Bob), None)?;
+    let bob_usdc_after = app.get_balance(AppUser::Bob, AppToken::USDC);
+    assert_eq!(
+        bob_usdc_after - bob_usdc_before,
+        ACCOUNT_REGISTRATION_FEE_AMOUNT
+    );
+
+    // SOL excess above rent exempt can be withdrawn
+    let config_sol_before = app.get_coin_balance(&app.pda.registry_config());
+    let bob_sol_before = app.get_coin_balance(&AppUser::Bob.pubkey());
+    app.registry_try_withdraw_revenue_with_sol(AppUser::Admin, None, Some(AppUser::Bob))?;
+    let config_sol_after = app.get_coin_balance(&app.pda.registry_config());
+    let bob_sol_after = app.get_coin_balance(&AppUser::Bob.pubkey());
+
+    assert_eq!(config_sol_before - config_sol_after, FEE_AMOUNT_SOL);
+    assert_eq!(bob_sol_after - bob_sol_before, FEE_AMOUNT_SOL);
+
+    Ok(())
+}
+
+#[test]
+fn suspend_account_default() -> Result<()> {
+    const MAX_DATA_SIZE: u32 = 1_000;
+    const DATA: &str = "encrypted_secrets";
+
+    let mut app = init_app()?;
+
+    app.registry_try_create_account(AppUser::Alice, MAX_DATA_SIZE, None)?;
+    app.registry_try_activate_account(AppUser::Alice, None, None, None)?;
+
+    // only admin can suspend account
+    let res = app
+        .registry_try_suspend_account(AppUser::Bob, AppUser::Alice)
+        .unwrap_err();
+    assert_error(res, AuthError::Unauthorized);
+
+    let res = app
+        .registry_try_unsuspend_account(AppUser::Admin, AppUser::Alice)
+        .unwrap_err();
+    assert_error(res, CustomError::AccountIsNotSuspended);
+
+    app.registry_try_suspend_account(AppUser::Admin, AppUser::Alice)?;
+    assert_eq!(
+        app.registry_query_user_id(AppUser::Alice)?.is_suspended,
+        true
+    );
+
+    let res = app
+        .registry_try_suspend_account(AppUser::Admin, AppUser::Alice)
+        .unwrap_err();
+    assert_error(res, CustomError::SuspendAccountTwice);
+
+    // suspended account can't write data
+    let res = app
+        .registry_try_write_data(AppUser::Alice, None, DATA, 1, None, None)
+        .unwrap_err();
+    assert_error(res, CustomError::AccountIsSuspended);
+
+    // suspended account can't be rotated
+    let res = app
+        .registry_try_request_account_rotation(AppUser::Alice, AppUser::Bob, false)
+        .unwrap_err();
+    assert_error(res, CustomError::AccountIsSuspended);
+
+    // unsuspended account works as usual
+    app.registry_try_unsuspend_account(AppUser::Admin, AppUser::Alice)?;
+    app.registry_try_write_data(AppUser::Alice, None, DATA, 1, None, None)?;
+
+    // rotation requested before suspension can't be confirmed
+    app.registry_try_request_account_rotation(AppUser::Alice, AppUser::Bob, false)?;
+    app.registry_try_suspend_account(AppUser::Admin, AppUser::Alice)?;
+    let res = app
+        .registry_try_confirm_account_rotation(AppUser::Bob, AppUser::Alice)
+        .unwrap_err();
+    assert_error(res, CustomError::AccountIsSuspended);
+
+    Ok(())
+}
+
+#[test]
+fn force_close_account_default() -> Result<()> {
+    const MAX_DATA_SIZE: u32 = 1_000;
+
+    let mut app = init_app()?;
+
+    app.registry_try_create_account(AppUser::Alice, MAX_DATA_SIZE, None)?;
+
+    // only admin can force close account
+    let res = app
+        .registry_try_force_close_account(AppUser::Bob, AppUser::Alice)
+        .unwrap_err();
+    assert_error(res, AuthError::Unauthorized);
+
+    // rent is refunded to the owner
+    let id = app.registry_query_user_id(AppUser::Alice)?.id;
+    let rent = app.get_coin_balance(&app.pda.registry_user_account(id))
+        + app.get_coin_balance(&app.pda.registry_user_rotation_state(id));
+    let alice_sol_before = app.get_coin_balance(&AppUser::Alice.pubkey());
+    app.registry_try_force_close_account(AppUser::Admin, AppUser::Alice)?;
+    let alice_sol_after = app.get_coin_balance(&AppUser::Alice.pubkey());
+
+    assert_eq!(alice_sol_after - alice_sol_before, rent);
+    assert_eq!(app.registry_query_user_id(AppUser::Alice)?.is_open, false);
+    app.registry_query_user_account(AppUser::Alice).unwrap_err();
+
+    // closed account can be reopened by the owner
+    app.registry_try_reopen_account(AppUser::Alice, MAX_DATA_SIZE)?;
+
+    Ok(())
+}
+
+#[test]
+fn create_account_sponsored() -> Result<()> {
+    const MAX_DATA_SIZE: u32 = 1_000;
+
+    let mut app = init_app()?;
+
+    // sponsor pays rent for the owner
+    let alice_sol_before = app.get_coin_balance(&AppUser::Alice.pubkey());
+    let bob_sol_before = app.get_coin_balance(&AppUser::Bob.pubkey());
+    let tx = app.registry_try_create_account_sponsored(
+        AppUser::Alice,
+        AppUser::Bob,
+        MAX_DATA_SIZE,
+        true,
+    )?;
+    let alice_sol_after = app.get_coin_balance(&AppUser::Alice.pubkey());
+    let bob_sol_after = app.get_coin_balance(&AppUser::Bob.pubkey());
+
+    let [ref event] = get_events::<AccountCreated>(&tx)[..] else {
+        panic!("AccountCreated event wasn't found!");
+    };
+    assert_eq!(event.sponsor, Some(AppUser::Bob.pubkey()));
+
+    let user_id = app.registry_query_user_id(AppUser::Alice)?;
+    assert_eq!(user_id.sponsor, Some(AppUser::Bob.pubkey()));
+    assert_eq!(user_id.is_rent_reclaimable, true);
+    assert_eq!(alice_sol_after, alice_sol_before);
+    assert!(bob_sol_after < bob_sol_before);
+
+    // reclaimable rent can't be resized without the sponsor
+    let id = user_id.id;
+    app.registry_try_resize_account(AppUser::Alice, None, MAX_DATA_SIZE * 2)
+        .unwrap_err();
+
+    // growing is paid by the sponsor
+    let rent_pre = app.get_coin_balance(&app.pda.registry_user_account(id));
+    let alice_sol_before = app.get_coin_balance(&AppUser::Alice.pubkey());
+    app.registry_try_resize_account(AppUser::Alice, Some(AppUser::Bob), MAX_DATA_SIZE * 2)?;
+    let alice_sol_after = app.get_coin_balance(&AppUser::Alice.pubkey());
+    assert!(app.get_coin_balance(&app.pda.registry_user_account(id)) > rent_pre);
+    assert_eq!(alice_sol_after, alice_sol_before);
+
+    // rent redeemed on shrinking is refunded to the sponsor
+    let rent_pre = app.get_coin_balance(&app.pda.registry_user_account(id));
+    let bob_sol_before = app.get_coin_balance(&AppUser::Bob.pubkey());
+    app.registry_try_resize_account(AppUser::Alice, Some(AppUser::Bob), MAX_DATA_SIZE / 2)?;
+    let rent = app.get_coin_balance(&app.pda.registry_user_account(id));
+    let bob_sol_after = app.get_coin_balance(&AppUser::Bob.pubkey());
+    assert!(rent < rent_pre);
+    assert_eq!(bob_sol_after - bob_sol_before, rent_pre - rent);
+
+    // reclaimable rent is refunded to the sponsor
+    let rent = app.get_coin_balance(&app.pda.registry_user_account(id))
+        + app.get_coin_balance(&app.pda.registry_user_rotation_state(id));
+    let bob_sol_before = app.get_coin_balance(&AppUser::Bob.pubkey());
+    app.registry_try_close_account(AppUser::Alice, None)?;
+    let bob_sol_after = app.get_coin_balance(&AppUser::Bob.pubkey());
+    assert_eq!(bob_sol_after - bob_sol_before, rent);
+
+    // reopened account rent is paid by the owner
+    app.registry_try_reopen_account(AppUser::Alice, MAX_DATA_SIZE)?;
+    let user_id = app.registry_query_user_id(AppUser::Alice)?;
+    assert_eq!(user_id.is_rent_reclaimable, false);
+    assert_eq!(
+        user_id.get_rent_recipient(AppUser::Alice.pubkey()),
+        AppUser::Alice.pubkey()
+    );
+
+    // non-reclaimable rent is refunded to the owner
+    app.registry_try_create_account_sponsored(AppUser::Bob, AppUser::Admin, MAX_DATA_SIZE, false)?;
+    let user_id = app.registry_query_user_id(AppUser::Bob)?;
+    assert_eq!(user_id.sponsor, Some(AppUser::Admin.pubkey()));
+    assert_eq!(user_id.is_rent_reclaimable, false);
+
+    let id = user_id.id;
+    let rent = app.get_coin_balance(&app.pda.registry_user_account(id))
+        + app.get_coin_balance(&app.pda.registry_user_rotation_state(id));
+    let bob_sol_before = app.get_coin_balance(&AppUser::Bob.pubkey());
+    app.registry_try_force_close_account(AppUser::Admin, AppUser::Bob)?;
+    let bob_sol_after = app.get_coin_balance(&AppUser::Bob.pubkey());
+    assert_eq!(bob_sol_after - bob_sol_before, rent);
+
+    // self-paid account has no sponsor
+    let tx = app.registry_try_create_account(AppUser::Admin, MAX_DATA_SIZE, None)?;
+    let [ref event] = get_events::<AccountCreated>(&tx)[..] else {
+        panic!("AccountCreated event wasn't found!");
+    };
+    assert_eq!(event.sponsor, None);
+
+    let user_id = app.registry_query_user_id(AppUser::Admin)?;
+    assert_eq!(user_id.sponsor, None);
+    assert_eq!(user_id.is_rent_reclaimable, false);
+
+    Ok(())
+}
+
+#[test]
+fn close_account_guards() -> Result<()> {
+    const MAX_DATA_SIZE_0: u32 = 1_000;
+
+    let mut app = init_app()?;
+
+    // account must be created first
+    app.registry_try_close_account(AppUser::Alice, None)
+        .unwrap_err();
+
+    // close account of other user
+    app.registry_try_create_account(AppUser::Alice, MAX_DATA_SIZE_0, None)?;
+    app.registry_try_close_account(AppUser::Bob, Some(AppUser::Alice))
+        .unwrap_err();
+
+    // close account twice
+    app.registry_try_close_account(AppUser::Alice, None)?;
+    app.registry_try_close_account(AppUser::Alice, None)
+        .unwrap_err();
+
+    Ok(())
+}
+
+#[test]
+fn reopen_account_default() -> Result<()> {
+    const MAX_DATA_SIZE_0: u32 = 1_000;
+    const MAX_DATA_SIZE_1: u32 = 1_000;
+
+    let mut app = init_app()?;
+
+    app.registry_try_create_account(AppUser::Alice, MAX_DATA_SIZE_0, None)?;
+    app.registry_try_activate_account(AppUser::Alice, None, None, None)?;
+    app.registry_try_close_account(AppUser::Alice, None)?;
+
+    let user_id = app.registry_query_user_id(AppUser::Alice)?;
+    assert_eq!(user_id.id, 1);
+    assert_eq!(user_id.is_open, false);
+    assert_eq!(user_id.is_activated, true);
+
+    app.registry_try_reopen_account(AppUser::Alice, MAX_DATA_SIZE_1)?;
+
+    let user_id = app.registry_query_user_id(AppUser::Alice)?;
+    assert_eq!(user_id.id, 1);
+    assert_eq!(user_id.is_open, true);
+    assert_eq!(user_id.is_activated, true);
+
+    assert_eq!(
+        app.registry_query_user_account(AppUser::Alice)?.max_size,
+        MAX_DATA_SIZE_1
+    );
+
+    Ok(())
+}
+
+#[test]
+fn reopen_account_guards() -> Result<()> {
+    const MAX_DATA_SIZE_0: u32 = 1_000;
+
+    let mut app = init_app()?;
+
+    // account must be created first
+    app.registry_try_reopen_account(AppUser::Alice, MAX_DATA_SIZE_0)
+        .unwrap_err();
+
+    // account can't be open twice
+    app.registry_try_create_account(AppUser::Alice, MAX_DATA_SIZE_0, None)?;
+    app.registry_try_reopen_account(AppUser::Alice, MAX_DATA_SIZE_0)
+        .unwrap_err();
+
+    Ok(())
+}
+
+#[test]
+fn resize_account_default() -> Result<()> {
+    const MAX_DATA_SIZE_0: u32 = 1_000;
+    const MAX_DATA_SIZE_1: u32 = 5_000;
+    const MAX_DATA_SIZE_2: u32 = 500;
+    const DATA_0: &str = "encrypted_secrets_0";
+    const NONCE_0: u64 = 1;
+
+    let mut app = init_app()?;
+
+    app.registry_try_create_account(AppUser::Alice, MAX_DATA_SIZE_0, None)?;
+    app.registry_try_activate_account(AppUser::Alice, None, None, None)?;
+    app.registry_try_write_data(AppUser::Alice, None, DATA_0, NONCE_0, None, None)?;
+
+    let user_account = app
+        .pda
+        .registry_user_account(app.registry_query_user_id(AppUser::Alice)?.id);
+
+    for max_data_size in [MAX_DATA_SIZE_1, MAX_DATA_SIZE_2] {
+        app.registry_try_resize_account(AppUser::Alice, None, max_data_size)?;
+
+        // data and nonce are preserved
+        assert_eq!(
+            app.registry_query_user_account(AppUser::Alice)?,
+            UserAccount {
+                data: DATA_0.as_bytes().to_vec(),
+                nonce: NONCE_0,
+                max_size: max_data_size,
+                header: DataHeader::new(0, 0, DATA_0.as_bytes())
+            }
+        );
+
+        // account keeps exactly rent exempt balance
+        assert_eq!(
+            app.get_coin_balance(&user_account),
+            app.litesvm
+                .minimum_balance_for_rent_exemption(UserAccount::get_space(max_data_size))
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn resize_account_guards() -> Result<()> {
+    const MAX_DATA_SIZE: u32 = 1_000;
+    const NONCE_0: u64 = 1;
+
+    let mut app = init_app()?;
+
+    // account must be created first
+    app.registry_try_resize_account(AppUser::Alice, None, MAX_DATA_SIZE)
+        .unwrap_err();
+
+    // new size must be in range
+    app.registry_try_create_account(AppUser::Alice, MAX_DATA_SIZE, None)?;
+    let res = app
+        .registry_try_resize_account(AppUser::Alice, None, ACCOUNT_DATA_SIZE_MAX + 1)
+        .unwrap_err();
+    assert_error(res, CustomError::MaxDataSizeIsOutOfRange);
+
+    // stored data must fit new size
+    app.registry_try_activate_account(AppUser::Alice, None, None, None)?;
+    app.registry_try_write_data(
+        AppUser::Alice,
+        None,
+        &"x".repeat(ACCOUNT_DATA_SIZE_MIN as usize + 1),
+        NONCE_0,
+        None,
+        None,
+    )?;
+    let res = app
+        .registry_try_resize_account(AppUser::Alice, None, ACCOUNT_DATA_SIZE_MIN)
+        .unwrap_err();
+    assert_error(res, CustomError::DataDoesNotFit);
+
+    // suspended account can't be resized
+    app.registry_try_suspend_account(AppUser::Admin, AppUser::Alice)?;
+    let res = app
+        .registry_try_resize_account(AppUser::Alice, None, MAX_DATA_SIZE * 2)
+        .unwrap_err();
+    assert_error(res, CustomError::AccountIsSuspended);
+    app.registry_try_unsuspend_account(AppUser::Admin, AppUser::Alice)?;
+
+    // account can't be resized in paused program
+    app.registry_try_update_config(
+        AppUser::Admin,
+        UpdateConfigArgs {
+            paused_ops: Some(PAUSE_WRITE),
+            ..Default::default()
+        },
+    )?;
+    let res = app
+        .registry_try_resize_account(AppUser::Alice, None, MAX_DATA_SIZE * 2)
+        .unwrap_err();
+    assert_error(res, CustomError::ContractIsPaused);
+    app.registry_try_update_config(
+        AppUser::Admin,
+        UpdateConfigArgs {
+            paused_ops: Some(PAUSE_NONE),
+            ..Default::default()
+        },
+    )?;
+
+    // closed account can't be resized
+    app.registry_try_close_account(AppUser::Alice, None)?;
+    app.registry_try_resize_account(AppUser::Alice, None, MAX_DATA_SIZE)
+        .unwrap_err();
+
+    Ok(())
+}
+
+#[test]
+fn write_data_default() -> Result<()> {
+    const MAX_DATA_SIZE: u32 = 1_000;
+    const DATA_0: &str = "encrypted_secrets_0";
+    const DATA_1: &str = "encrypted_secrets_1";
+    const NONCE_0: u64 = 1;
+    const NONCE_1: u64 = 2;
+
+    let mut app = init_app()?;
+
+    app.registry_try_create_account(AppUser::Alice, MAX_DATA_SIZE, None)?;
+    app.registry_try_activate_account(AppUser::Alice, None, None, None)?;
+
+    for (data, nonce) in [(DATA_0, NONCE_0), (DATA_1, NONCE_1)] {
+        app.registry_try_write_data(AppUser::Alice, None, data, nonce, None, None)?;
+
+        assert_eq!(
+            app.registry_query_user_account(AppUser::Alice)?,
+            UserAccount {
+                data: data.as_bytes().to_vec(),
+                nonce,
+                max_size: MAX_DATA_SIZE,
+                header: DataHeader::new(0, 0, data.as_bytes())
+            }
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn write_data_with_header() -> Result<()> {
+    const MAX_DATA_SIZE: u32 = 1_000;
+    const DATA: &str = "encrypted_secrets";
+    const NONCE: u64 = 1;
+    const SCHEMA_ID: u16 = 3;
+    const ENCRYPTION_SCHEME_ID: u16 = 7;
+
+    let mut app = init_app()?;
+
+    app.registry_try_create_account(AppUser::Alice, MAX_DATA_SIZE, None)?;
+    app.registry_try_activate_account(AppUser::Alice, None, None, None)?;
+
+    let header = DataHeader::new(SCHEMA_ID, ENCRYPTION_SCHEME_ID, DATA.as_bytes());
+
+    // unsupported version
+    let res = app
+        .registry_try_write_data(
+            AppUser::Alice,
+            None,
+            DATA,
+            NONCE,
+            None,
+            Some(DataHeader {
+                version: 0,
+                ..header.clone()
+            }),
+        )
+        .unwrap_err();
+    assert_error(res, CustomError::UnsupportedHeaderVersion);
+
+    // wrong content length
+    let res = app
+        .registry_try_write_data(
+            AppUser::Alice,
+            None,
+            DATA,
+            NONCE,
+            None,
+            Some(DataHeader {
+                content_length: DATA.len() as u32 + 1,
+                ..header.clone()
+            }),
+        )
+        .unwrap_err();
+    assert_error(res, CustomError::ContentLengthMismatch);
+
+    // header of another data
+    let res = app
+        .registry_try_write_data(
+            AppUser::Alice,
+            None,
+            DATA,
+            NONCE,
+            None,
+            Some(DataHeader::new(
+                SCHEMA_ID,
+                ENCRYPTION_SCHEME_ID,
+                "encrypted_secrett".as_bytes(),
+            )),
+        )
+        .unwrap_err();
+    assert_error(res, CustomError::ChecksumMismatch);
+
+    app.registry_try_write_data(
+        AppUser::Alice,
+        None,
+        DATA,
+        NONCE,
+        None,
+        Some(header.clone()),
+    )?;
+    assert_eq!(
+        app.registry_query_user_account(AppUser::Alice)?.header,
+        header
+    );
+
+    Ok(())
+}
+
+#[test]
+fn write_data_multiple_users() -> Result<()> {
+    const MAX_DATA_SIZE: u32 = 1_000;
+    const DATA_0: &str = "encrypted_secrets_0";
+    const DATA_1: &str = "encrypted_secrets_1";
+    const NONCE_0: u64 = 1;
+    const NONCE_1: u64 = 2;
+
+    let mut app = init_app()?;
+
+    for user in [AppUser::Alice, AppUser::Bob] {
+        app.registry_try_create_account(user, MAX_DATA_SIZE, None)?;
+        app.registry_try_activate_account(user, None, None, None)?;
+    }
+
+    app.registry_try_write_data(AppUser::Alice, None, DATA_0, NONCE_0, None, None)?;
+    app.registry_try_write_data(AppUser::Bob, None, DATA_1, NONCE_1, None, None)?;
+
+    assert_eq!(
+        app.registry_query_user_account(AppUser::Alice)?,
+        UserAccount {
+            data: DATA_0.as_bytes().to_vec(),
+            nonce: NONCE_0,
+            max_size: MAX_DATA_SIZE,
+            header: DataHeader::new(0, 0, DATA_0.as_bytes())
         }
     );
     assert_eq!(
         app.registry_query_user_account(AppUser::Bob)?,
         UserAccount {
-            data: DATA_1.to_string(),
+            data: DATA_1.as_bytes().to_vec(),
             nonce: NONCE_1,
+            max_size: MAX_DATA_SIZE,
+            header: DataHeader::new(0, 0, DATA_1.as_bytes())
+        }
+    );
+
+    Ok(())
+}
+
+#[test]
+fn write_data_with_expected_nonce() -> Result<()> {
+    const MAX_DATA_SIZE: u32 = 1_000;
+    const DATA_0: &str = "encrypted_secrets_0";
+    const DATA_1: &str = "encrypted_secrets_1";
+    const DATA_2: &str = "encrypted_secrets_2";
+
+    let mut app = init_app()?;
+
+    app.registry_try_create_account(AppUser::Alice, MAX_DATA_SIZE, None)?;
+    app.registry_try_activate_account(AppUser::Alice, None, None, None)?;
+
+    // both devices read nonce 0, the 1st one writes successfully
+    app.registry_try_write_data(AppUser::Alice, None, DATA_0, 1, Some(0), None)?;
+
+    // the 2nd device can't overwrite data using stale nonce
+    let res = app
+        .registry_try_write_data(AppUser::Alice, None, DATA_1, 2, Some(0), None)
+        .unwrap_err();
+    assert_error(res, CustomError::NonceMismatch);
+    assert_eq!(
+        app.registry_query_user_account(AppUser::Alice)?.data,
+        DATA_0.as_bytes()
+    );
+
+    // the 2nd device writes after refreshing nonce
+    app.registry_try_write_data(AppUser::Alice, None, DATA_2, 2, Some(1), None)?;
+    assert_eq!(
+        app.registry_query_user_account(AppUser::Alice)?,
+        UserAccount {
+            data: DATA_2.as_bytes().to_vec(),
+            nonce: 2,
+            max_size: MAX_DATA_SIZE,
+            header: DataHeader::new(0, 0, DATA_2.as_bytes())
+        }
+    );
+
+    Ok(())
+}
+
+#[test]
+fn write_data_by_delegate() -> Result<()> {
+    const MAX_DATA_SIZE: u32 = 1_000;
+    const DATA_0: &str = "encrypted_secrets_0";
+    const DATA_1: &str = "encrypted_secrets_1";
+    const DELEGATION_PERIOD: u64 = 3_600;
+
+    let mut app = init_app()?;
+
+    app.registry_try_create_account(AppUser::Alice, MAX_DATA_SIZE, None)?;
+    app.registry_try_activate_account(AppUser::Alice, None, None, None)?;
+
+    let expiration_date = app.get_clock_time() + DELEGATION_PERIOD;
+    app.registry_try_grant_delegate(AppUser::Alice, AppUser::Bob, Some(expiration_date))?;
+    assert_eq!(
+        app.registry_query_user_delegate(AppUser::Alice, AppUser::Bob)?,
+        UserDelegate {
+            owner: AppUser::Alice.pubkey(),
+            expiration_date: Some(expiration_date),
+        }
+    );
+
+    app.registry_try_write_data(AppUser::Bob, Some(AppUser::Alice), DATA_0, 1, None, None)?;
+    assert_eq!(
+        app.registry_query_user_account(AppUser::Alice)?.data,
+        DATA_0.as_bytes()
+    );
+
+    // delegate can't write data after expiration
+    app.wait(DELEGATION_PERIOD);
+    let res = app
+        .registry_try_write_data(AppUser::Bob, Some(AppUser::Alice), DATA_1, 2, None, None)
+        .unwrap_err();
+    assert_error(res, CustomError::DelegateIsNotValid);
+
+    // permission can be renewed without expiration
+    app.registry_try_grant_delegate(AppUser::Alice, AppUser::Bob, None)?;
+    app.registry_try_write_data(AppUser::Bob, Some(AppUser::Alice), DATA_1, 2, None, None)?;
+    assert_eq!(
+        app.registry_query_user_account(AppUser::Alice)?.data,
+        DATA_1.as_bytes()
+    );
+
+    // delegate can't write data after revocation
+    app.registry_try_revoke_delegate(AppUser::Alice, AppUser::Bob)?;
+    app.registry_query_user_delegate(AppUser::Alice, AppUser::Bob)
+        .unwrap_err();
+    app.registry_try_write_data(AppUser::Bob, Some(AppUser::Alice), DATA_0, 3, None, None)
+        .unwrap_err();
+
+    Ok(())
+}
+
+#[test]
+fn delegate_guards() -> Result<()> {
+    const MAX_DATA_SIZE: u32 = 1_000;
+    const DATA: &str = "encrypted_secrets";
+
+    let mut app = init_app()?;
+
+    app.registry_try_create_account(AppUser::Alice, MAX_DATA_SIZE, None)?;
+    app.registry_try_activate_account(AppUser::Alice, None, None, None)?;
+
+    // owner can't delegate to itself
+    let res = app
+        .registry_try_grant_delegate(AppUser::Alice, AppUser::Alice, None)
+        .unwrap_err();
+    assert_error(res, CustomError::SelfDelegation);
+
+    // expiration date must be in the future
+    let clock_time = app.get_clock_time();
+    let res = app
+        .registry_try_grant_delegate(AppUser::Alice, AppUser::Bob, Some(clock_time))
+        .unwrap_err();
+    assert_error(res, CustomError::BadDelegateExpirationDate);
+
+    // delegate permission doesn't survive account rotation
+    app.registry_try_grant_delegate(AppUser::Alice, AppUser::Bob, None)?;
+    app.registry_try_request_account_rotation(AppUser::Alice, AppUser::Admin, false)?;
+    app.registry_try_confirm_account_rotation(AppUser::Admin, AppUser::Alice)?;
+    let res = app
+        .registry_try_write_data(AppUser::Bob, Some(AppUser::Admin), DATA, 1, None, None)
+        .unwrap_err();
+    assert_error(res, CustomError::DelegateIsNotValid);
+
+    // new owner can revoke permissions granted by previous one
+    app.registry_try_revoke_delegate(AppUser::Admin, AppUser::Bob)?;
+
+    Ok(())
+}
+
+#[test]
+fn write_bytes_default() -> Result<()> {
+    const MAX_DATA_SIZE: u32 = 1_000;
+    const NONCE: u64 = 1;
+
+    let mut app = init_app()?;
+
+    app.registry_try_create_account(AppUser::Alice, MAX_DATA_SIZE, None)?;
+    app.registry_try_activate_account(AppUser::Alice, None, None, None)?;
+
+    // raw ciphertext isn't required to be valid UTF-8 and can take all allocated space
+    let data: Vec<u8> = (0..MAX_DATA_SIZE).map(|x| (x % 256) as u8).collect();
+    app.registry_try_write_bytes(AppUser::Alice, None, &data, NONCE, None, None)?;
+
+    assert_eq!(
+        app.registry_query_user_account(AppUser::Alice)?,
+        UserAccount {
+            header: DataHeader::new(0, 0, &data),
+            data,
+            nonce: NONCE,
+            max_size: MAX_DATA_SIZE
+        }
+    );
+
+    let res = app
+        .registry_try_write_bytes(
+            AppUser::Alice,
+            None,
+            &[0; MAX_DATA_SIZE as usize + 1],
+            NONCE + 1,
+            None,
+            None,
+        )
+        .unwrap_err();
+    assert_error(res, CustomError::MaxDataSizeIsExceeded);
+
+    Ok(())
+}
+
+#[test]
+fn migrate_user_account_default() -> Result<()> {
+    const MAX_DATA_SIZE: u32 = 1_000;
+    const NONCE: u64 = 1;
+
+    let mut app = init_app()?;
+
+    app.registry_try_create_account(AppUser::Alice, MAX_DATA_SIZE, None)?;
+    app.registry_try_activate_account(AppUser::Alice, None, None, None)?;
+
+    // new accounts have the header
+    let res = app
+        .registry_try_migrate_user_account(AppUser::Alice)
+        .unwrap_err();
+    assert_error(res, CustomError::NothingToMigrate);
+
+    // emulate account created before the header was introduced
+    let id = app.registry_query_user_id(AppUser::Alice)?.id;
+    let user_account = app.pda.registry_user_account(id);
+    let mut account = app.litesvm.get_account(&user_account).unwrap();
+    account
+        .data
+        .truncate(UserAccount::get_space(MAX_DATA_SIZE) - DataHeader::INIT_SPACE);
+    app.litesvm.set_account(user_account, account).unwrap();
+
+    // legacy account can't store max size data
+    let data = vec![1; MAX_DATA_SIZE as usize];
+    app.registry_try_write_bytes(AppUser::Alice, None, &data, NONCE, None, None)
+        .unwrap_err();
+
+    app.registry_try_migrate_user_account(AppUser::Alice)?;
+    assert_eq!(
+        app.litesvm.get_account(&user_account).unwrap().data.len(),
+        UserAccount::get_space(MAX_DATA_SIZE)
+    );
+
+    app.registry_try_write_bytes(AppUser::Alice, None, &data, NONCE, None, None)?;
+    assert_eq!(app.registry_query_user_account(AppUser::Alice)?.data, data);
+
+    Ok(())
+}
+
+#[test]
+fn write_data_chunked() -> Result<()> {
+    const MAX_DATA_SIZE: u32 = ACCOUNT_DATA_SIZE_MAX;
+    const CHUNK_SIZE: usize = 900;
+    const NONCE_0: u64 = 1;
+
+    let mut app = init_app()?;
+
+    app.registry_try_create_account(AppUser::Alice, MAX_DATA_SIZE, None)?;
+    app.registry_try_activate_account(AppUser::Alice, None, None, None)?;
+
+    let data = "x".repeat(MAX_DATA_SIZE as usize);
+    app.registry_try_begin_data_write(AppUser::Alice, MAX_DATA_SIZE)?;
+
+    for (i, chunk) in data.as_bytes().chunks(CHUNK_SIZE).enumerate() {
+        app.registry_try_append_data_chunk(AppUser::Alice, (i * CHUNK_SIZE) as u32, chunk)?;
+
+        // previous data is kept until commit
+        assert_eq!(
+            app.registry_query_user_account(AppUser::Alice)?,
+            UserAccount {
+                data: Vec::default(),
+                nonce: 0,
+                max_size: MAX_DATA_SIZE,
+                header: DataHeader::default()
+            }
+        );
+    }
+
+    app.registry_try_commit_data_write(AppUser::Alice, NONCE_0, None, None)?;
+
+    assert_eq!(
+        app.registry_query_user_account(AppUser::Alice)?,
+        UserAccount {
+            header: DataHeader::new(0, 0, data.as_bytes()),
+            data: data.into_bytes(),
+            nonce: NONCE_0,
             max_size: MAX_DATA_SIZE
         }
     );
 
+    // write buffer is closed after commit
+    app.registry_query_user_write_buffer(AppUser::Alice)
+        .unwrap_err();
+
+    Ok(())
+}
+
+#[test]
+fn write_data_chunked_guards() -> Result<()> {
+    const MAX_DATA_SIZE: u32 = 1_000;
+    const DATA: &str = "encrypted_secrets_0";
+    const NONCE_0: u64 = 1;
+
+    let mut app = init_app()?;
+
+    app.registry_try_create_account(AppUser::Alice, MAX_DATA_SIZE, None)?;
+
+    // account must be activated
+    let res = app
+        .registry_try_begin_data_write(AppUser::Alice, DATA.len() as u32)
+        .unwrap_err();
+    assert_error(res, CustomError::AccountIsNotActivated);
+
+    // data size can't exceed max size
+    app.registry_try_activate_account(AppUser::Alice, None, None, None)?;
+    let res = app
+        .registry_try_begin_data_write(AppUser::Alice, MAX_DATA_SIZE + 1)
+        .unwrap_err();
+    assert_error(res, CustomError::MaxDataSizeIsExceeded);
+
+    app.registry_try_begin_data_write(AppUser::Alice, DATA.len() as u32)?;
+
+    // write can't be started twice
+    app.registry_try_begin_data_write(AppUser::Alice, DATA.len() as u32)
+        .unwrap_err();
+
+    // chunks must be sequential
+    let (chunk_0, chunk_1) = DATA.as_bytes().split_at(DATA.len() / 2);
+    let res = app
+        .registry_try_append_data_chunk(AppUser::Alice, 1, chunk_0)
+        .unwrap_err();
+    assert_error(res, CustomError::BadChunkOffset);
+
+    // incomplete data can't be committed
+    app.registry_try_append_data_chunk(AppUser::Alice, 0, chunk_0)?;
+    let res = app
+        .registry_try_commit_data_write(AppUser::Alice, NONCE_0, None, None)
+        .unwrap_err();
+    assert_error(res, CustomError::IncompleteDataWrite);
+
+    // data can't exceed declared size
+    let res = app
+        .registry_try_append_data_chunk(AppUser::Alice, chunk_0.len() as u32, DATA.as_bytes())
+        .unwrap_err();
+    assert_error(res, CustomError::MaxDataSizeIsExceeded);
+
+    // nonce must be unique
+    app.registry_try_append_data_chunk(AppUser::Alice, chunk_0.len() as u32, chunk_1)?;
+    let res = app
+        .registry_try_commit_data_write(AppUser::Alice, 0, None, None)
+        .unwrap_err();
+    assert_error(res, CustomError::BadNonce);
+
+    // stored nonce must match expected one
+    let res = app
+        .registry_try_commit_data_write(AppUser::Alice, NONCE_0, Some(NONCE_0), None)
+        .unwrap_err();
+    assert_error(res, CustomError::NonceMismatch);
+
+    app.registry_try_commit_data_write(AppUser::Alice, NONCE_0, Some(0), None)?;
+    assert_eq!(
+        app.registry_query_user_account(AppUser::Alice)?.data,
+        DATA.as_bytes()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn abort_data_write_default() -> Result<()> {
+    const MAX_DATA_SIZE: u32 = 1_000;
+    const DATA: &str = "encrypted_secrets_0";
+
+    let mut app = init_app()?;
+
+    app.registry_try_create_account(AppUser::Alice, MAX_DATA_SIZE, None)?;
+    app.registry_try_activate_account(AppUser::Alice, None, None, None)?;
+
+    // nothing to abort
+    app.registry_try_abort_data_write(AppUser::Alice)
+        .unwrap_err();
+
+    // buffer can't be appended or committed in suspended account but it can be aborted
+    app.registry_try_begin_data_write(AppUser::Alice, DATA.len() as u32)?;
+    let (chunk_0, chunk_1) = DATA.as_bytes().split_at(DATA.len() / 2);
+    app.registry_try_append_data_chunk(AppUser::Alice, 0, chunk_0)?;
+    app.registry_try_suspend_account(AppUser::Admin, AppUser::Alice)?;
+    let res = app
+        .registry_try_append_data_chunk(AppUser::Alice, chunk_0.len() as u32, chunk_1)
+        .unwrap_err();
+    assert_error(res, CustomError::AccountIsSuspended);
+
+    let res = app
+        .registry_try_commit_data_write(AppUser::Alice, 1, None, None)
+        .unwrap_err();
+    assert_error(res, CustomError::AccountIsSuspended);
+
+    app.registry_try_abort_data_write(AppUser::Alice)?;
+    app.registry_query_user_write_buffer(AppUser::Alice)
+        .unwrap_err();
+
+    // new write can be started after abort
+    app.registry_try_unsuspend_account(AppUser::Admin, AppUser::Alice)?;
+    app.registry_try_begin_data_write(AppUser::Alice, DATA.len() as u32)?;
+
+    // pending write doesn't block the rotation with data clearing after abort
+    app.registry_try_request_account_rotation(AppUser::Alice, AppUser::Bob, true)?;
+    let res = app
+        .registry_try_confirm_account_rotation(AppUser::Bob, AppUser::Alice)
+        .unwrap_err();
+    assert_error(res, CustomError::IncompleteDataWrite);
+
+    app.registry_try_abort_data_write(AppUser::Alice)?;
+    app.registry_try_confirm_account_rotation(AppUser::Bob, AppUser::Alice)?;
+
+    // write buffer is closed along with the account
+    app.registry_try_begin_data_write(AppUser::Bob, DATA.len() as u32)?;
+    app.registry_try_close_account(AppUser::Bob, None)?;
+    app.registry_query_user_write_buffer(AppUser::Bob)
+        .unwrap_err();
+
     Ok(())
 }
 
@@ -524,8 +1827,8 @@ fn rotate_account() -> Result<()> {
     let mut app = init_app()?;
 
     app.registry_try_create_account(AppUser::Alice, MAX_DATA_SIZE, None)?;
-    app.registry_try_activate_account(AppUser::Alice, None, None)?;
-    app.registry_try_write_data(AppUser::Alice, DATA_0, NONCE_0)?;
+    app.registry_try_activate_account(AppUser::Alice, None, None, None)?;
+    app.registry_try_write_data(AppUser::Alice, None, DATA_0, NONCE_0, None, None)?;
 
     // new owner isn't specified
     let res = app
@@ -535,12 +1838,12 @@ fn rotate_account() -> Result<()> {
 
     // the owner can't be new owner
     let res = app
-        .registry_try_request_account_rotation(AppUser::Alice, AppUser::Alice)
+        .registry_try_request_account_rotation(AppUser::Alice, AppUser::Alice, false)
         .unwrap_err();
     assert_error(res, AuthError::UselessRotation);
 
     // too late to confirm account rotation
-    app.registry_try_request_account_rotation(AppUser::Alice, AppUser::Bob)?;
+    app.registry_try_request_account_rotation(AppUser::Alice, AppUser::Bob, false)?;
     app.wait(ROTATION_TIMEOUT as u64);
     let res = app
         .registry_try_confirm_account_rotation(AppUser::Bob, AppUser::Alice)
@@ -548,7 +1851,7 @@ fn rotate_account() -> Result<()> {
     assert_error(res, AuthError::TransferOwnerDeadline);
 
     // only new owner can confirm account rotation
-    app.registry_try_request_account_rotation(AppUser::Alice, AppUser::Bob)?;
+    app.registry_try_request_account_rotation(AppUser::Alice, AppUser::Bob, false)?;
     let res = app
         .registry_try_confirm_account_rotation(AppUser::Admin, AppUser::Alice)
         .unwrap_err();
@@ -560,9 +1863,10 @@ fn rotate_account() -> Result<()> {
     assert_eq!(
         app.registry_query_user_account(AppUser::Bob)?,
         UserAccount {
-            data: DATA_0.to_string(),
+            data: DATA_0.as_bytes().to_vec(),
             nonce: NONCE_0,
-            max_size: MAX_DATA_SIZE
+            max_size: MAX_DATA_SIZE,
+            header: DataHeader::new(0, 0, DATA_0.as_bytes())
         }
     );
 
@@ -574,3 +1878,1205 @@ fn rotate_account() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn account_rotation_with_data_clearing() -> Result<()> {
+    const MAX_DATA_SIZE: u32 = 1_000;
+    const DATA: &str = "encrypted_secrets";
+    const NONCE: u64 = 5;
+
+    let mut app = init_app()?;
+
+    app.registry_try_create_account(AppUser::Alice, MAX_DATA_SIZE, None)?;
+    app.registry_try_activate_account(AppUser::Alice, None, None, None)?;
+    app.registry_try_write_data(AppUser::Alice, None, DATA, NONCE, None, None)?;
+
+    app.registry_try_request_account_rotation(AppUser::Alice, AppUser::Bob, true)?;
+    assert_eq!(
+        app.registry_query_user_rotation_state(AppUser::Alice)?
+            .clear_data,
+        true
+    );
+
+    // pending data write of the previous owner blocks the handoff
+    app.registry_try_begin_data_write(AppUser::Alice, DATA.len() as u32)?;
+    let res = app
+        .registry_try_confirm_account_rotation(AppUser::Bob, AppUser::Alice)
+        .unwrap_err();
+    assert_error(res, CustomError::IncompleteDataWrite);
+
+    app.registry_try_append_data_chunk(AppUser::Alice, 0, DATA.as_bytes())?;
+    app.registry_try_commit_data_write(AppUser::Alice, NONCE + 1, None, None)?;
+
+    let tx = app.registry_try_confirm_account_rotation(AppUser::Bob, AppUser::Alice)?;
+    let [ref event] = get_events::<AccountRotationConfirmed>(&tx)[..] else {
+        panic!("AccountRotationConfirmed event isn't found!");
+    };
+    assert_eq!(event.clear_data, true);
+
+    // the new owner doesn't get previous owner's data
+    assert_eq!(
+        app.registry_query_user_account(AppUser::Bob)?,
+        UserAccount {
+            data: Vec::default(),
+            nonce: NONCE + 2,
+            max_size: MAX_DATA_SIZE,
+            header: DataHeader::default()
+        }
+    );
+    assert_eq!(
+        app.registry_query_user_rotation_state(AppUser::Bob)?
+            .clear_data,
+        false
+    );
+
+    Ok(())
+}
+
+#[test]
+fn cancel_account_rotation() -> Result<()> {
+    const MAX_DATA_SIZE: u32 = 1_000;
+
+    let mut app = init_app()?;
+
+    app.registry_try_create_account(AppUser::Alice, MAX_DATA_SIZE, None)?;
+
+    // nothing to cancel
+    let res = app
+        .registry_try_cancel_account_rotation(AppUser::Alice)
+        .unwrap_err();
+    assert_error(res, AuthError::NoRotationToCancel);
+
+    // expired rotation can be cancelled
+    app.registry_try_request_account_rotation(AppUser::Alice, AppUser::Bob, false)?;
+    app.wait(ROTATION_TIMEOUT as u64);
+    let tx = app.registry_try_cancel_account_rotation(AppUser::Alice)?;
+    let [ref event] = get_events::<AccountRotationCancelled>(&tx)[..] else {
+        panic!("AccountRotationCancelled event isn't found!");
+    };
+    assert_eq!(
+        (event.id, event.owner, event.new_owner),
+        (1, AppUser::Alice.pubkey(), AppUser::Bob.pubkey())
+    );
+
+    // pending rotation can be cancelled
+    app.registry_try_request_account_rotation(AppUser::Alice, AppUser::Bob, false)?;
+    app.registry_try_cancel_account_rotation(AppUser::Alice)?;
+    assert_eq!(
+        app.registry_query_user_rotation_state(AppUser::Alice)?
+            .new_owner,
+        None
+    );
+
+    // cancelled rotation can't be confirmed
+    let res = app
+        .registry_try_confirm_account_rotation(AppUser::Bob, AppUser::Alice)
+        .unwrap_err();
+    assert_error(res, AuthError::NoNewOwner);
+
+    Ok(())
+}
+
+#[test]
+fn registry_events() -> Result<()> {
+    const MAX_DATA_SIZE: u32 = 1_000;
+    const DATA: &str = "encrypted_secrets";
+    const NONCE: u64 = 1;
+
+    let mut app = init_app()?;
+
+    let tx = app.registry_try_create_account(AppUser::Alice, MAX_DATA_SIZE, None)?;
+    let [ref event] = get_events::<AccountCreated>(&tx)[..] else {
+        panic!("AccountCreated event isn't found!");
+    };
+    assert_eq!(
+        (event.id, event.owner, event.max_data_size),
+        (1, AppUser::Alice.pubkey(), MAX_DATA_SIZE)
+    );
+
+    let tx = app.registry_try_activate_account(AppUser::Bob, Some(AppUser::Alice), None, None)?;
+    let [ref event] = get_events::<AccountActivated>(&tx)[..] else {
+        panic!("AccountActivated event isn't found!");
+    };
+    assert_eq!(
+        (event.owner, event.payer, event.fee.amount, event.is_renewal),
+        (
+            AppUser::Alice.pubkey(),
+            AppUser::Bob.pubkey(),
+            ACCOUNT_REGISTRATION_FEE_AMOUNT,
+            false
+        )
+    );
+
+    let tx = app.registry_try_write_data(AppUser::Alice, None, DATA, NONCE, None, None)?;
+    let [ref event] = get_events::<DataWritten>(&tx)[..] else {
+        panic!("DataWritten event isn't found!");
+    };
+    assert_eq!(
+        (event.id, event.writer, event.nonce, event.length),
+        (1, AppUser::Alice.pubkey(), NONCE, DATA.len() as u32)
+    );
+
+    app.registry_try_request_account_rotation(AppUser::Alice, AppUser::Admin, false)?;
+    let tx = app.registry_try_confirm_account_rotation(AppUser::Admin, AppUser::Alice)?;
+    let [ref event] = get_events::<AccountRotationConfirmed>(&tx)[..] else {
+        panic!("AccountRotationConfirmed event isn't found!");
+    };
+    assert_eq!(
+        (event.id, event.prev_owner, event.new_owner),
+        (1, AppUser::Alice.pubkey(), AppUser::Admin.pubkey())
+    );
+
+    let tx = app.registry_try_update_config(
+        AppUser::Admin,
+        UpdateConfigArgs {
+            paused_ops: Some(PAUSE_CREATE),
+            ..Default::default()
+        },
+    )?;
+    let [ref event] = get_events::<ConfigUpdated>(&tx)[..] else {
+        panic!("ConfigUpdated event isn't found!");
+    };
+    assert_eq!(event.config, app.registry_query_config()?);
+
+    Ok(())
+}
+
+#[test]
+fn pause_operations_default() -> Result<()> {
+    const MAX_DATA_SIZE: u32 = 1_000;
+    const DATA: &str = "encrypted_secrets";
+
+    let mut app = init_app()?;
+
+    app.registry_try_create_account(AppUser::Alice, MAX_DATA_SIZE, None)?;
+    app.registry_try_activate_account(AppUser::Alice, None, None, None)?;
+
+    // pause everything except account creation
+    app.registry_try_update_config(
+        AppUser::Admin,
+        UpdateConfigArgs {
+            paused_ops: Some(PAUSE_ALL & !PAUSE_CREATE),
+            ..Default::default()
+        },
+    )?;
+
+    app.registry_try_create_account(AppUser::Bob, MAX_DATA_SIZE, None)?;
+
+    let res = app
+        .registry_try_activate_account(AppUser::Bob, None, None, None)
+        .unwrap_err();
+    assert_error(res, CustomError::ContractIsPaused);
+
+    let res = app
+        .registry_try_write_data(AppUser::Alice, None, DATA, 1, None, None)
+        .unwrap_err();
+    assert_error(res, CustomError::ContractIsPaused);
+
+    let res = app
+        .registry_try_request_account_rotation(AppUser::Alice, AppUser::Admin, false)
+        .unwrap_err();
+    assert_error(res, CustomError::ContractIsPaused);
+
+    // resume data writing only
+    app.registry_try_update_config(
+        AppUser::Admin,
+        UpdateConfigArgs {
+            paused_ops: Some(PAUSE_ALL & !PAUSE_WRITE),
+            ..Default::default()
+        },
+    )?;
+
+    app.registry_try_write_data(AppUser::Alice, None, DATA, 1, None, None)?;
+
+    let res = app
+        .registry_try_create_account(AppUser::Admin, MAX_DATA_SIZE, None)
+        .unwrap_err();
+    assert_error(res, CustomError::ContractIsPaused);
+
+    Ok(())
+}
+
+#[test]
+fn migrate_default() -> Result<()> {
+    const MAX_DATA_SIZE: u32 = 1_000;
+    // baseline layouts: discriminator, admin, is_paused, rotation_timeout, registration_fee, data_size_range
+    const LEGACY_CONFIG_SPACE: usize =
+        DISCRIMINATOR_SPACE + 32 + 1 + 4 + AssetItem::INIT_SPACE + Range::INIT_SPACE;
+    // discriminator, id, is_open, is_activated, account_bump, rotation_state_bump
+    const LEGACY_USER_ID_SPACE: usize = DISCRIMINATOR_SPACE + 4 + 1 + 1 + 1 + 1;
+    // discriminator, owner, new_owner (pending), expiration_date
+    const LEGACY_ROTATION_STATE_SPACE: usize = DISCRIMINATOR_SPACE + 32 + 33 + 8;
+
+    let mut app = init_app()?;
+
+    app.registry_try_create_account(AppUser::Alice, MAX_DATA_SIZE, None)?;
+    app.registry_try_activate_account(AppUser::Alice, None, None, None)?;
+    app.registry_try_create_account(AppUser::Bob, MAX_DATA_SIZE, None)?;
+    let alice_id = app.registry_query_user_id(AppUser::Alice)?.id;
+
+    // pending rotations
+    app.registry_try_update_config(
+        AppUser::Admin,
+        UpdateConfigArgs {
+            admin: Some(AppUser::Bob.pubkey()),
+            ..Default::default()
+        },
+    )?;
+    app.registry_try_request_account_rotation(AppUser::Alice, AppUser::Admin, false)?;
+
+    // accounts are up to date
+    let tx = app.registry_try_migrate(AppUser::Admin, &[AppUser::Alice], &[alice_id])?;
+    assert_eq!(get_events::<AccountMigrated>(&tx).len(), 0);
+
+    // emulate accounts created before versioning
+    let config = app.pda.registry_config();
+    let admin_rotation_state = app.pda.registry_admin_rotation_state();
+    let alice_user_id = app.pda.registry_user_id(AppUser::Alice.pubkey());
+    let alice_rotation_state = app.pda.registry_user_rotation_state(alice_id);
+    let bob_user_id = app.pda.registry_user_id(AppUser::Bob.pubkey());
+    for (address, legacy_space) in [
+        (config, LEGACY_CONFIG_SPACE),
+        (admin_rotation_state, LEGACY_ROTATION_STATE_SPACE),
+        (alice_user_id, LEGACY_USER_ID_SPACE),
+        (alice_rotation_state, LEGACY_ROTATION_STATE_SPACE),
+        (bob_user_id, LEGACY_USER_ID_SPACE),
+    ] {
+        let mut account = app.litesvm.get_account(&address).unwrap();
+        account.data.truncate(legacy_space);
+        app.litesvm.set_account(address, account).unwrap();
+    }
+    // the whitelist didn't exist
+    let fee_whitelist = app.pda.registry_fee_whitelist();
+    app.litesvm
+        .set_account(fee_whitelist, Default::default())
+        .unwrap();
+    app.registry_query_fee_whitelist().unwrap_err();
+
+    // legacy accounts can't be used
+    app.registry_try_activate_account(AppUser::Bob, None, None, None)
+        .unwrap_err();
+    app.registry_try_confirm_account_rotation(AppUser::Admin, AppUser::Alice)
+        .unwrap_err();
+
+    // only admin can migrate
+    let res = app
+        .registry_try_migrate(AppUser::Alice, &[AppUser::Alice], &[alice_id])
+        .unwrap_err();
+    assert_error(res, AuthError::Unauthorized);
+
+    let tx =
+        app.registry_try_migrate(AppUser::Admin, &[AppUser::Alice, AppUser::Bob], &[alice_id])?;
+    let events = get_events::<AccountMigrated>(&tx);
+    assert_eq!(
+        events
+            .iter()
+            .map(|x| (x.address, x.version))
+            .collect::<Vec<_>>(),
+        vec![
+            (config, Config::VERSION),
+            (admin_rotation_state, RotationState::VERSION),
+            (alice_user_id, UserId::VERSION),
+            (bob_user_id, UserId::VERSION),
+            (alice_rotation_state, RotationState::VERSION)
+        ]
+    );
+
+    assert_eq!(app.registry_query_fee_whitelist()?, FeeWhitelist::default());
+
+    // new fields get defaults instead of zeros
+    let config = app.registry_query_config()?;
+    assert_eq!(
+        (config.activation_period, config.version),
+        (ACTIVATION_PERIOD, Config::VERSION)
+    );
+
+    // activated account stays activated
+    let user_id = app.registry_query_user_id(AppUser::Alice)?;
+    assert_eq!(
+        (
+            user_id.is_activated,
+            user_id.activated_until,
+            user_id.is_suspended,
+            user_id.version
+        ),
+        (true, u64::MAX, false, UserId::VERSION)
+    );
+    app.registry_try_write_data(AppUser::Alice, None, "data", 1, None, None)?;
+    app.registry_try_renew_activation(AppUser::Alice, None, None)?;
+    assert_eq!(
+        app.registry_query_user_id(AppUser::Alice)?.activated_until,
+        u64::MAX
+    );
+
+    let user_id = app.registry_query_user_id(AppUser::Bob)?;
+    assert_eq!((user_id.is_activated, user_id.activated_until), (false, 0));
+
+    app.registry_try_activate_account(AppUser::Bob, None, None, None)?;
+    assert_eq!(
+        app.registry_query_user_id(AppUser::Bob)?.activated_until,
+        app.get_clock_time() + ACTIVATION_PERIOD as u64
+    );
+
+    // pending rotations are kept
+    let rotation_state = app.registry_query_admin_rotation_state()?;
+    assert_eq!(
+        (rotation_state.new_owner, rotation_state.version),
+        (Some(AppUser::Bob.pubkey()), RotationState::VERSION)
+    );
+    let rotation_state = app.registry_query_user_rotation_state(AppUser::Alice)?;
+    assert_eq!(
+        (
+            rotation_state.new_owner,
+            rotation_state.clear_data,
+            rotation_state.version
+        ),
+        (Some(AppUser::Admin.pubkey()), false, RotationState::VERSION)
+    );
+
+    app.registry_try_confirm_admin_rotation(AppUser::Bob)?;
+    app.registry_try_confirm_account_rotation(AppUser::Admin, AppUser::Alice)?;
+    assert_eq!(app.registry_query_config()?.admin, AppUser::Bob.pubkey());
+    assert_eq!(
+        app.registry_query_user_account(AppUser::Admin)?.data,
+        b"data".to_vec()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn batch_update_accounts_default() -> Result<()> {
+    const MAX_DATA_SIZE: u32 = 1_000;
+    const USERS: [AppUser; 2] = [AppUser::Alice, AppUser::Bob];
+
+    let mut app = init_app()?;
+
+    for user in USERS {
+        app.registry_try_create_account(user, MAX_DATA_SIZE, None)?;
+    }
+
+    // only admin can run batch operations
+    let res = app
+        .registry_try_batch_activate_accounts(AppUser::Alice, &USERS)
+        .unwrap_err();
+    assert_error(res, AuthError::Unauthorized);
+
+    let res = app
+        .registry_try_batch_activate_accounts(AppUser::Admin, &[])
+        .unwrap_err();
+    assert_error(res, CustomError::BatchSizeIsOutOfRange);
+
+    // activation is free
+    let admin_usdc_before = app.get_balance(AppUser::Admin, AppToken::USDC);
+    let tx = app.registry_try_batch_activate_accounts(AppUser::Admin, &USERS)?;
+    assert_eq!(
+        app.get_balance(AppUser::Admin, AppToken::USDC),
+        admin_usdc_before
+    );
+
+    let events = get_events::<AccountActivated>(&tx);
+    assert_eq!(
+        events
+            .iter()
+            .map(|x| (x.id, x.owner, x.fee.amount, x.is_renewal))
+            .collect::<Vec<_>>(),
+        vec![
+            (1, AppUser::Alice.pubkey(), 0, false),
+            (2, AppUser::Bob.pubkey(), 0, false)
+        ]
+    );
+
+    for user in USERS {
+        assert_eq!(app.registry_query_user_id(user)?.is_activated, true);
+    }
+
+    // already suspended account is skipped
+    app.registry_try_suspend_account(AppUser::Admin, AppUser::Alice)?;
+    let tx = app.registry_try_batch_suspend_accounts(AppUser::Admin, &USERS)?;
+    assert_eq!(
+        get_events::<AccountSuspended>(&tx)
+            .iter()
+            .map(|x| x.owner)
+            .collect::<Vec<_>>(),
+        vec![AppUser::Bob.pubkey()]
+    );
+    for user in USERS {
+        assert_eq!(app.registry_query_user_id(user)?.is_suspended, true);
+    }
+
+    // rent is refunded to the owners
+    let alice_sol_before = app.get_coin_balance(&AppUser::Alice.pubkey());
+    app.registry_try_batch_close_accounts(AppUser::Admin, &USERS)?;
+    assert!(app.get_coin_balance(&AppUser::Alice.pubkey()) > alice_sol_before);
+
+    for user in USERS {
+        assert_eq!(app.registry_query_user_id(user)?.is_open, false);
+        app.registry_query_user_account(user).unwrap_err();
+    }
+
+    // already closed account is skipped
+    let tx = app.registry_try_batch_close_accounts(AppUser::Admin, &[AppUser::Alice])?;
+    assert_eq!(get_events::<AccountForceClosed>(&tx).len(), 0);
+
+    Ok(())
+}
+
+#[test]
+fn user_index_default() -> Result<()> {
+    const MAX_DATA_SIZE: u32 = 1_000;
+
+    let mut app = init_app()?;
+
+    app.registry_try_create_account(AppUser::Alice, MAX_DATA_SIZE, None)?;
+    app.registry_try_create_account(AppUser::Bob, MAX_DATA_SIZE, None)?;
+
+    let page = UserIndexPage::get_page(app.registry_query_user_counter()?.last_user_id);
+    assert_eq!(page, 0);
+    assert_eq!(
+        get_user_index_page_address(page),
+        app.pda.registry_user_index_page(page)
+    );
+
+    let user_index_page = app.registry_query_user_index_page(page)?;
+    assert_eq!(
+        user_index_page.get_users(page),
+        vec![(1, AppUser::Alice.pubkey()), (2, AppUser::Bob.pubkey())]
+    );
+
+    // index is updated on account rotation
+    app.registry_try_request_account_rotation(AppUser::Alice, AppUser::Admin, false)?;
+    app.registry_try_confirm_account_rotation(AppUser::Admin, AppUser::Alice)?;
+
+    let user_index_page = app.registry_query_user_index_page(page)?;
+    assert_eq!(user_index_page.get_owner(1), Some(AppUser::Admin.pubkey()));
+    assert_eq!(user_index_page.get_owner(2), Some(AppUser::Bob.pubkey()));
+    assert_eq!(user_index_page.get_owner(3), None);
+
+    // raw account data can be decoded by indexers
+    let data = app
+        .litesvm
+        .get_account(&get_user_index_page_address(page))
+        .unwrap()
+        .data;
+    assert_eq!(decode_user_index_page(&data)?, user_index_page);
+
+    Ok(())
+}
+
+#[test]
+fn user_index_pages() {
+    assert_eq!(UserIndexPage::get_page(1), 0);
+    assert_eq!(UserIndexPage::get_position(1), 0);
+    assert_eq!(UserIndexPage::get_page(USER_INDEX_PAGE_SIZE as u32), 0);
+    assert_eq!(UserIndexPage::get_page(USER_INDEX_PAGE_SIZE as u32 + 1), 1);
+    assert_eq!(
+        UserIndexPage::get_position(USER_INDEX_PAGE_SIZE as u32 + 1),
+        0
+    );
+
+    // ids registered before the index was introduced are skipped
+    let mut user_index_page = UserIndexPage::default();
+    user_index_page.set_owner(USER_INDEX_PAGE_SIZE as u32 + 3, AppUser::Alice.pubkey());
+    assert_eq!(user_index_page.owners.len(), 3);
+    assert_eq!(
+        user_index_page.get_users(1),
+        vec![(USER_INDEX_PAGE_SIZE as u32 + 3, AppUser::Alice.pubkey())]
+    );
+}
+
+#[test]
+fn client_instructions_default() -> Result<()> {
+    const ID: u32 = 70;
+    const CODE: &str = "promo";
+
+    let admin = AppUser::Admin.pubkey();
+    let sender = AppUser::Alice.pubkey();
+    let sponsor = AppUser::Bob.pubkey();
+    let system_program = system_program::ID;
+    let token_program = anchor_spl::token::ID;
+    let associated_token_program = associated_token::ID;
+    let revenue_mint = AppToken::USDC.pubkey();
+
+    let bump = pda::get_bump_address();
+    let config = pda::get_config_address();
+    let user_counter = pda::get_user_counter_address();
+    let admin_rotation_state = pda::get_admin_rotation_state_address();
+    let fee_whitelist = pda::get_fee_whitelist_address();
+    let promo_code = pda::get_promo_code_address(CODE)?;
+    let user_id = pda::get_user_id_address(&sender);
+    let user_account = pda::get_user_account_address(ID);
+    let user_rotation_state = pda::get_user_rotation_state_address(ID);
+    let user_write_buffer = pda::get_user_write_buffer_address(ID);
+    let user_index_page = pda::get_user_index_page_address(UserIndexPage::get_page(ID));
+    let user_delegate = pda::get_user_delegate_address(ID, &sponsor);
+    let revenue_app_ata = App::get_ata(&config, &revenue_mint);
+
+    let registration_fee = AssetItem {
+        amount: 1,
+        asset: revenue_mint,
+    };
+    let data_size_range = Range { min: 100, max: 200 };
+    let config_args = UpdateConfigArgs {
+        paused_ops: Some(PAUSE_WRITE),
+        ..Default::default()
+    };
+    let header = DataHeader::new(1, 0, b"data");
+
+    let user_id_metas = |users: &[Pubkey]| {
+        users
+            .iter()
+            .flat_map(|x| {
+                [
+                    AccountMeta::new_readonly(*x, false),
+                    AccountMeta::new(pda::get_user_id_address(x), false),
+                ]
+            })
+            .collect::<Vec<_>>()
+    };
+
+    // instructions built by the client match the program interface
+    let cases = [
+        (
+            instruction::init(
+                admin,
+                token_program,
+                revenue_mint,
+                Some(1),
+                Some(registration_fee.clone()),
+                Some(data_size_range.clone()),
+                Some(2),
+            )?,
+            get_registry_instruction(
+                registry::accounts::Init {
+                    system_program,
+                    token_program,
+                    associated_token_program,
+                    sender: admin,
+                    bump,
+                    config,
+                    user_counter,
+                    admin_rotation_state,
+                    fee_whitelist,
+                    revenue_mint,
+                    revenue_app_ata,
+                },
+                registry::instruction::Init {
+                    rotation_timeout: Some(1),
+                    account_registration_fee: Some(registration_fee),
+                    account_data_size_range: Some(data_size_range),
+                    activation_period: Some(2),
+                },
+            ),
+        ),
+        (
+            instruction::update_config(admin, config_args.clone())?,
+            get_registry_instruction(
+                registry::accounts::UpdateConfig {
+                    system_program,
+                    sender: admin,
+                    bump,
+                    config,
+                    admin_rotation_state,
+                    fee_whitelist,
+                },
+                registry::instruction::UpdateConfig { args: config_args },
+            ),
+        ),
+        (
+            instruction::confirm_admin_rotation(sender)?,
+            get_registry_instruction(
+                registry::accounts::ConfirmAdminRotation {
+                    sender,
+                    bump,
+                    config,
+                    admin_rotation_state,
+                },
+                registry::instruction::ConfirmAdminRotation {},
+            ),
+        ),
+        (
+            instruction::cancel_admin_rotation(admin)?,
+            get_registry_instruction(
+                registry::accounts::CancelAdminRotation {
+                    sender: admin,
+                    bump,
+                    config,
+                    admin_rotation_state,
+                },
+                registry::instruction::CancelAdminRotation {},
+            ),
+        ),
+        (
+            instruction::withdraw_revenue(admin, sponsor, token_program, revenue_mint, Some(1))?,
+            get_registry_instruction(
+                registry::accounts::WithdrawRevenue {
+                    system_program,
+                    token_program,
+                    associated_token_program,
+                    sender: admin,
+                    recipient: sponsor,
+                    bump,
+                    config,
+                    revenue_mint,
+                    revenue_recipient_ata: App::get_ata(&sponsor, &revenue_mint),
+                    revenue_app_ata,
+                },
+                registry::instruction::WithdrawRevenue { amount: Some(1) },
+            ),
+        ),
+        (
+            instruction::withdraw_revenue_with_sol(admin, sponsor, None)?,
+            get_registry_instruction(
+                registry::accounts::WithdrawRevenueWithSol {
+                    system_program,
+                    sender: admin,
+                    recipient: sponsor,
+                    bump,
+                    config,
+                },
+                registry::instruction::WithdrawRevenueWithSol { amount: None },
+            ),
+        ),
+        (
+            instruction::suspend_account(admin, sender)?,
+            get_registry_instruction(
+                registry::accounts::SuspendAccount {
+                    sender: admin,
+                    bump,
+                    config,
+                    user_id,
+                },
+                registry::instruction::SuspendAccount { user: sender },
+            ),
+        ),
+        (
+            instruction::unsuspend_account(admin, sender)?,
+            get_registry_instruction(
+                registry::accounts::SuspendAccount {
+                    sender: admin,
+                    bump,
+                    config,
+                    user_id,
+                },
+                registry::instruction::UnsuspendAccount { user: sender },
+            ),
+        ),
+        (
+            instruction::force_close_account(admin, sender, ID, sponsor)?,
+            get_registry_instruction(
+                registry::accounts::ForceCloseAccount {
+                    system_program,
+                    sender: admin,
+                    rent_recipient: sponsor,
+                    owner: sender,
+                    bump,
+                    config,
+                    user_id,
+                    user_account,
+                    user_rotation_state,
+                    user_write_buffer,
+                },
+                registry::instruction::ForceCloseAccount { user: sender },
+            ),
+        ),
+        (
+            instruction::create_promo_code(admin, CODE, 1, Some(sponsor), 2, Some(3), Some(4))?,
+            get_registry_instruction(
+                registry::accounts::CreatePromoCode {
+                    system_program,
+                    sender: admin,
+                    bump,
+                    config,
+                    promo_code,
+                },
+                registry::instruction::CreatePromoCode {
+                    code: CODE.to_string(),
+                    discount_bps: 1,
+                    referrer: Some(sponsor),
+                    referrer_share_bps: 2,
+                    usage_limit: Some(3),
+                    expiration_date: Some(4),
+                },
+            ),
+        ),
+        (
+            instruction::close_promo_code(admin, CODE)?,
+            get_registry_instruction(
+                registry::accounts::ClosePromoCode {
+                    system_program,
+                    sender: admin,
+                    bump,
+                    config,
+                    promo_code,
+                },
+                registry::instruction::ClosePromoCode {
+                    code: CODE.to_string(),
+                },
+            ),
+        ),
+        (
+            instruction::batch_activate_accounts(admin, &[sender, sponsor])?,
+            extend_accounts(
+                get_registry_instruction(
+                    registry::accounts::BatchUpdateAccounts {
+                        sender: admin,
+                        bump,
+                        config,
+                    },
+                    registry::instruction::BatchActivateAccounts {},
+                ),
+                user_id_metas(&[sender, sponsor]),
+            ),
+        ),
+        (
+            instruction::batch_suspend_accounts(admin, &[sender])?,
+            extend_accounts(
+                get_registry_instruction(
+                    registry::accounts::BatchUpdateAccounts {
+                        sender: admin,
+                        bump,
+                        config,
+                    },
+                    registry::instruction::BatchSuspendAccounts {},
+                ),
+                user_id_metas(&[sender]),
+            ),
+        ),
+        (
+            instruction::batch_close_accounts(admin, &[(sender, ID, sponsor)])?,
+            extend_accounts(
+                get_registry_instruction(
+                    registry::accounts::BatchUpdateAccounts {
+                        sender: admin,
+                        bump,
+                        config,
+                    },
+                    registry::instruction::BatchCloseAccounts {},
+                ),
+                [
+                    AccountMeta::new(sender, false),
+                    AccountMeta::new(user_id, false),
+                    AccountMeta::new(user_account, false),
+                    AccountMeta::new(user_rotation_state, false),
+                    AccountMeta::new(user_write_buffer, false),
+                    AccountMeta::new(sponsor, false),
+                ],
+            ),
+        ),
+        (
+            instruction::migrate(admin, &[sender], &[ID])?,
+            extend_accounts(
+                get_registry_instruction(
+                    registry::accounts::Migrate {
+                        system_program,
+                        sender: admin,
+                        bump,
+                        config,
+                        admin_rotation_state,
+                        fee_whitelist,
+                    },
+                    registry::instruction::Migrate {},
+                ),
+                [
+                    AccountMeta::new(user_id, false),
+                    AccountMeta::new(user_rotation_state, false),
+                ],
+            ),
+        ),
+        (
+            instruction::create_account(sender, sponsor, ID, 1_000, true)?,
+            get_registry_instruction(
+                registry::accounts::CreateAccount {
+                    system_program,
+                    sender,
+                    payer: sponsor,
+                    bump,
+                    config,
+                    user_counter,
+                    user_id,
+                    user_account,
+                    user_rotation_state,
+                    user_index_page,
+                },
+                registry::instruction::CreateAccount {
+                    max_data_size: 1_000,
+                    is_rent_reclaimable: true,
+                },
+            ),
+        ),
+        (
+            instruction::close_account(sender, ID, sponsor)?,
+            get_registry_instruction(
+                registry::accounts::CloseAccount {
+                    system_program,
+                    sender,
+                    rent_recipient: sponsor,
+                    user_id,
+                    user_account,
+                    user_rotation_state,
+                    user_write_buffer,
+                },
+                registry::instruction::CloseAccount {},
+            ),
+        ),
+        (
+            instruction::reopen_account(sender, ID, 1_000)?,
+            get_registry_instruction(
+                registry::accounts::ReopenAccount {
+                    system_program,
+                    sender,
+                    bump,
+                    config,
+                    user_id,
+                    user_account,
+                    user_rotation_state,
+                },
+                registry::instruction::ReopenAccount {
+                    max_data_size: 1_000,
+                },
+            ),
+        ),
+        (
+            instruction::resize_account(sender, ID, 1_000, sponsor)?,
+            get_registry_instruction(
+                registry::accounts::ResizeAccount {
+                    system_program,
+                    sender,
+                    rent_payer: sponsor,
+                    bump,
+                    config,
+                    user_id,
+                    user_account,
+                },
+                registry::instruction::ResizeAccount {
+                    max_data_size: 1_000,
+                },
+            ),
+        ),
+        (
+            instruction::migrate_user_account(sender, ID)?,
+            get_registry_instruction(
+                registry::accounts::MigrateUserAccount {
+                    system_program,
+                    sender,
+                    user_id,
+                    user_account,
+                },
+                registry::instruction::MigrateUserAccount {},
+            ),
+        ),
+        (
+            instruction::activate_account(
+                sender,
+                sender,
+                token_program,
+                revenue_mint,
+                Some(CODE),
+                Some(sponsor),
+            )?,
+            get_registry_instruction(
+                registry::accounts::ActivateAccount {
+                    system_program,
+                    token_program,
+                    associated_token_program,
+                    sender,
+                    bump,
+                    config,
+                    fee_whitelist,
+                    user_id,
+                    promo_code: Some(promo_code),
+                    revenue_mint,
+                    revenue_sender_ata: App::get_ata(&sender, &revenue_mint),
+                    revenue_app_ata,
+                    referrer_ata: Some(App::get_ata(&sponsor, &revenue_mint)),
+                },
+                registry::instruction::ActivateAccount { user: sender },
+            ),
+        ),
+        (
+            // optional accounts are omitted
+            instruction::renew_activation(
+                sponsor,
+                sender,
+                token_program,
+                revenue_mint,
+                None,
+                None,
+            )?,
+            get_registry_instruction(
+                registry::accounts::ActivateAccount {
+                    system_program,
+                    token_program,
+                    associated_token_program,
+                    sender: sponsor,
+                    bump,
+                    config,
+                    fee_whitelist,
+                    user_id,
+                    promo_code: None,
+                    revenue_mint,
+                    revenue_sender_ata: App::get_ata(&sponsor, &revenue_mint),
+                    revenue_app_ata,
+                    referrer_ata: None,
+                },
+                registry::instruction::RenewActivation { user: sender },
+            ),
+        ),
+        (
+            instruction::activate_account_with_sol(sender, sender, Some(CODE), Some(sponsor))?,
+            get_registry_instruction(
+                registry::accounts::ActivateAccountWithSol {
+                    system_program,
+                    sender,
+                    bump,
+                    config,
+                    fee_whitelist,
+                    user_id,
+                    promo_code: Some(promo_code),
+                    referrer: Some(sponsor),
+                },
+                registry::instruction::ActivateAccountWithSol { user: sender },
+            ),
+        ),
+        (
+            instruction::renew_activation_with_sol(sender, sender, None, None)?,
+            get_registry_instruction(
+                registry::accounts::ActivateAccountWithSol {
+                    system_program,
+                    sender,
+                    bump,
+                    config,
+                    fee_whitelist,
+                    user_id,
+                    promo_code: None,
+                    referrer: None,
+                },
+                registry::instruction::RenewActivationWithSol { user: sender },
+            ),
+        ),
+        (
+            // optional delegate account is omitted for the owner
+            instruction::write_data(sender, sender, ID, "data", 1, None, header.clone())?,
+            get_registry_instruction(
+                registry::accounts::WriteData {
+                    sender,
+                    bump,
+                    config,
+                    user_id,
+                    user_account,
+                    user_delegate: None,
+                },
+                registry::instruction::WriteData {
+                    user: sender,
+                    data: "data".to_string(),
+                    nonce: 1,
+                    expected_nonce: None,
+                    header: header.clone(),
+                },
+            ),
+        ),
+        (
+            instruction::write_bytes(sponsor, sender, ID, b"data", 1, Some(0), header.clone())?,
+            get_registry_instruction(
+                registry::accounts::WriteData {
+                    sender: sponsor,
+                    bump,
+                    config,
+                    user_id,
+                    user_account,
+                    user_delegate: Some(user_delegate),
+                },
+                registry::instruction::WriteBytes {
+                    user: sender,
+                    data: b"data".to_vec(),
+                    nonce: 1,
+                    expected_nonce: Some(0),
+                    header: header.clone(),
+                },
+            ),
+        ),
+        (
+            instruction::grant_delegate(sender, ID, sponsor, Some(1))?,
+            get_registry_instruction(
+                registry::accounts::GrantDelegate {
+                    system_program,
+                    sender,
+                    user_id,
+                    user_delegate,
+                },
+                registry::instruction::GrantDelegate {
+                    delegate: sponsor,
+                    expiration_date: Some(1),
+                },
+            ),
+        ),
+        (
+            instruction::revoke_delegate(sender, ID, sponsor)?,
+            get_registry_instruction(
+                registry::accounts::RevokeDelegate {
+                    system_program,
+                    sender,
+                    user_id,
+                    user_delegate,
+                },
+                registry::instruction::RevokeDelegate { delegate: sponsor },
+            ),
+        ),
+        (
+            instruction::begin_data_write(sender, ID, 1_000)?,
+            get_registry_instruction(
+                registry::accounts::BeginDataWrite {
+                    system_program,
+                    sender,
+                    bump,
+                    config,
+                    user_id,
+                    user_account,
+                    user_write_buffer,
+                },
+                registry::instruction::BeginDataWrite { data_size: 1_000 },
+            ),
+        ),
+        (
+            instruction::append_data_chunk(sender, ID, 4, b"data")?,
+            get_registry_instruction(
+                registry::accounts::AppendDataChunk {
+                    sender,
+                    bump,
+                    config,
+                    user_id,
+                    user_write_buffer,
+                },
+                registry::instruction::AppendDataChunk {
+                    offset: 4,
+                    chunk: b"data".to_vec(),
+                },
+            ),
+        ),
+        (
+            instruction::commit_data_write(sender, ID, 1, Some(0), header.clone())?,
+            get_registry_instruction(
+                registry::accounts::CommitDataWrite {
+                    sender,
+                    bump,
+                    config,
+                    user_id,
+                    user_account,
+                    user_write_buffer,
+                },
+                registry::instruction::CommitDataWrite {
+                    nonce: 1,
+                    expected_nonce: Some(0),
+                    header,
+                },
+            ),
+        ),
+        (
+            instruction::abort_data_write(sender, ID)?,
+            get_registry_instruction(
+                registry::accounts::AbortDataWrite {
+                    sender,
+                    user_id,
+                    user_write_buffer,
+                },
+                registry::instruction::AbortDataWrite {},
+            ),
+        ),
+        (
+            instruction::request_account_rotation(sender, ID, sponsor, true)?,
+            get_registry_instruction(
+                registry::accounts::RequestAccountRotation {
+                    sender,
+                    bump,
+                    config,
+                    user_id,
+                    user_rotation_state,
+                },
+                registry::instruction::RequestAccountRotation {
+                    new_owner: sponsor,
+                    clear_data: true,
+                },
+            ),
+        ),
+        (
+            instruction::confirm_account_rotation(sponsor, sender, ID)?,
+            get_registry_instruction(
+                registry::accounts::ConfirmAccountRotation {
+                    system_program,
+                    sender: sponsor,
+                    bump,
+                    config,
+                    user_id_pre: user_id,
+                    user_id: pda::get_user_id_address(&sponsor),
+                    user_rotation_state,
+                    user_account,
+                    user_write_buffer,
+                    user_index_page,
+                },
+                registry::instruction::ConfirmAccountRotation {},
+            ),
+        ),
+        (
+            instruction::cancel_account_rotation(sender, ID)?,
+            get_registry_instruction(
+                registry::accounts::CancelAccountRotation {
+                    sender,
+                    user_id,
+                    user_rotation_state,
+                },
+                registry::instruction::CancelAccountRotation {},
+            ),
+        ),
+    ];
+
+    for (actual, expected) in cases {
+        assert_eq!(actual, expected);
+    }
+
+    // address of too long promo code can't be derived
+    let code = "x".repeat(PROMO_CODE_MAX_LEN + 1);
+    pda::get_promo_code_address(&code).unwrap_err();
+    instruction::close_promo_code(admin, &code).unwrap_err();
+
+    // accounts are decoded from raw data
+    let user_id_value = UserId {
+        id: ID,
+        is_open: true,
+        is_activated: false,
+        account_bump: 1,
+        rotation_state_bump: 2,
+        activated_until: 0,
+        is_suspended: false,
+        version: UserId::VERSION,
+        sponsor: Some(sponsor),
+        is_rent_reclaimable: true,
+    };
+    let mut data = vec![];
+    user_id_value.try_serialize(&mut data)?;
+
+    assert_eq!(decode::decode_user_id(&data)?.sponsor, Some(sponsor));
+    decode::decode_config(&data).unwrap_err();
+
+    Ok(())
+}
+
+fn get_registry_instruction(
+    accounts: impl ToAccountMetas,
+    instruction_data: impl InstructionData,
+) -> Instruction {
+    Instruction {
+        program_id: registry::ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction_data.data(),
+    }
+}
+
+/// appends remaining accounts
+fn extend_accounts(
+    mut instruction: Instruction,
+    accounts: impl IntoIterator<Item = AccountMeta>,
+) -> Instruction {
+    instruction.accounts.extend(accounts);
+    instruction
+}